@@ -414,7 +414,13 @@ fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
 
 
 // Public interface members begin here.
-
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
@@ -598,31 +604,264 @@ fileprivate struct FfiConverterData: FfiConverterRustBuffer {
 
 
 
+/**
+ * Defines a named analyzer, usable as `TantivyTokenizer::Custom` once added to
+ * the schema builder.
+ */
+public protocol TantivyAnalyzerBuilderProtocol: AnyObject, Sendable {
+    
+    func addFilter(filter: TantivyTokenFilter) 
+    
+}
+/**
+ * Defines a named analyzer, usable as `TantivyTokenizer::Custom` once added to
+ * the schema builder.
+ */
+open class TantivyAnalyzerBuilder: TantivyAnalyzerBuilderProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_tantivy_fn_clone_tantivyanalyzerbuilder(self.handle, $0) }
+    }
+public convenience init(name: String, tokenizer: TantivyBaseTokenizer) {
+    let handle =
+        try! rustCall() {
+    uniffi_tantivy_fn_constructor_tantivyanalyzerbuilder_new(
+        FfiConverterString.lower(name),
+        FfiConverterTypeTantivyBaseTokenizer_lower(tokenizer),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_tantivy_fn_free_tantivyanalyzerbuilder(handle, $0) }
+    }
+
+    
+
+    
+open func addFilter(filter: TantivyTokenFilter)  {try! rustCall() {
+    uniffi_tantivy_fn_method_tantivyanalyzerbuilder_add_filter(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeTantivyTokenFilter_lower(filter),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyAnalyzerBuilder: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = TantivyAnalyzerBuilder
+
+    public static func lift(_ handle: UInt64) throws -> TantivyAnalyzerBuilder {
+        return TantivyAnalyzerBuilder(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: TantivyAnalyzerBuilder) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyAnalyzerBuilder {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: TantivyAnalyzerBuilder, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyAnalyzerBuilder_lift(_ handle: UInt64) throws -> TantivyAnalyzerBuilder {
+    return try FfiConverterTypeTantivyAnalyzerBuilder.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyAnalyzerBuilder_lower(_ value: TantivyAnalyzerBuilder) -> UInt64 {
+    return FfiConverterTypeTantivyAnalyzerBuilder.lower(value)
+}
+
+
+
+
+
+
 public protocol TantivyIndexProtocol: AnyObject, Sendable {
     
+    /**
+     * Runs Elasticsearch-style aggregations over the documents matching the query DSL.
+     *
+     * Aggregated fields must be fast fields.
+     */
+    func aggregate(queryJson: String, aggregationsJson: String) throws  -> TantivyAggregationResults
+    
+    /**
+     * Runs `text` through the analyzer of a field, or through a registered
+     * tokenizer such as `unicode` when no field has that name.
+     */
+    func analyze(fieldOrTokenizer: String, text: String) throws  -> [TantivyToken]
+    
     func clearIndex() throws 
     
     func commit() throws 
     
+    /**
+     * Stages the deletion of every document matching the query DSL without committing.
+     *
+     * Returns how many documents currently visible to the reader matched.
+     */
+    func deleteByQuery(queryJson: String) throws  -> UInt64
+    
     func deleteDoc(id: DocumentField) throws 
     
+    /**
+     * Stages the deletion of every document matching one of `ids` without committing.
+     *
+     * Returns how many documents currently visible to the reader matched.
+     */
+    func deleteDocs(ids: [DocumentField]) throws  -> UInt64
+    
     func docExists(id: DocumentField) throws  -> Bool
     
     func docsCount()  -> UInt64
     
-    func getDoc(id: DocumentField) throws  -> TantivyDocumentFields
+    /**
+     * Explains how the query DSL scores the document identified by `id`.
+     *
+     * Fails if the document does not match the query.
+     */
+    func explain(queryJson: String, id: DocumentField) throws  -> TantivyExplanation
+    
+    /**
+     * Counts the direct children of each facet in `root_paths` across the documents
+     * matching the query DSL, sorted by descending count.
+     */
+    func facetCounts(queryJson: String, field: String, rootPaths: [String], topK: UInt32?) throws  -> [TantivyFacetCounts]
+    
+    func getDoc(id: DocumentField, fields: TantivyFieldProjection?) throws  -> TantivyDocumentFields
     
-    func getDocsByIds(ids: [DocumentField]) throws  -> [TantivyDocumentFields]
+    func getDocsByIds(ids: [DocumentField], fields: TantivyFieldProjection?) throws  -> [TantivyDocumentFields]
     
     func indexDoc(doc: TantivyDocumentFields) throws 
     
     func indexDocs(docs: [TantivyDocumentFields]) throws 
     
+    /**
+     * Lists the indexed terms of a text field in lexicographic order, `limit` at a
+     * time, optionally only those starting with `prefix`.
+     *
+     * Terms are listed as indexed, `prefix` is not analyzed.
+     */
+    func listTerms(field: String, limit: UInt32, prefix: String?, cursor: String?) throws  -> TantivyTermPage
+    
+    /**
+     * The fields of the index, in schema order.
+     */
+    func schema()  -> [TantivyFieldDescriptor]
+    
     func search(query: TantivySearchQuery) throws  -> TantivySearchResults
     
+    /**
+     * Returns the u64 IDs of the hits, read from the fast column of `id_field`.
+     *
+     * Hits start after `cursor` when set. Only IDs are returned, so `fields` and
+     * `snippet_fields` fail with `QueryError` when set.
+     */
     func searchDocIds(query: TantivySearchQuery, idField: String) throws  -> [TantivySearchHit]
     
-    func searchDsl(queryJson: String, topDocLimit: UInt32, topDocOffset: UInt32) throws  -> TantivySearchResults
+    func searchDsl(queryJson: String, topDocLimit: UInt32, topDocOffset: UInt32, sort: [TantivySortField], snippetFields: [TantivySnippetField], cursor: String?, fields: TantivyFieldProjection?) throws  -> TantivySearchResults
+    
+    /**
+     * Returns the IDs of the documents matching the query DSL, read from the fast
+     * column of `id_field` without loading stored documents. A hit without an ID
+     * fails the search with `MissingIdField`.
+     */
+    func searchIdsDsl(queryJson: String, idField: String, topDocLimit: UInt32, topDocOffset: UInt32, sort: [TantivySortField], cursor: String?) throws  -> TantivyIdSearchResults
+    
+    /**
+     * Suggests corrections for each analyzed token of `text` from the indexed terms
+     * of a text field within `max_distance` edits (0 to 2, a transposition counts
+     * as one).
+     */
+    func spellSuggest(field: String, text: String, maxDistance: UInt8) throws  -> TantivySpellSuggestion
+    
+    /**
+     * Completes the last word of `prefix` from the indexed terms of a text field,
+     * most frequent first.
+     *
+     * The prefix goes through the field's analyzer, so suggestions are terms as
+     * indexed, e.g. lowercased.
+     */
+    func suggest(field: String, prefix: String, limit: UInt32) throws  -> [TantivyTermSuggestion]
+    
+    /**
+     * Counts of an indexed term of a text field, overall and per segment.
+     *
+     * `term` is looked up as indexed, it is not analyzed.
+     */
+    func termStats(field: String, term: String) throws  -> TantivyTermStats
+    
+    /**
+     * Replaces any document sharing `id_field`'s value with `doc`.
+     *
+     * The delete and the add are staged under the same writer lock, so they land
+     * in the same commit and readers never observe the document as missing.
+     */
+    func upsertDoc(idField: String, doc: TantivyDocumentFields) throws 
+    
+    func upsertDocs(idField: String, docs: [TantivyDocumentFields]) throws 
+    
+    /**
+     * Reports the syntax errors, unknown fields and invalid values of a query string
+     * that `search` would otherwise skip over.
+     */
+    func validateQuery(queryStr: String, defaultFields: [String], fuzzyFields: [TantivyFuzzyField]) throws  -> TantivyQueryValidation
     
 }
 open class TantivyIndex: TantivyIndexProtocol, @unchecked Sendable {
@@ -671,6 +910,48 @@ open class TantivyIndex: TantivyIndexProtocol, @unchecked Sendable {
     }
 
     
+    /**
+     * Reindexes the index at `path` into the schema of `schema_builder` and opens it.
+     *
+     * The stored documents are copied through `field_mappings` into a new index
+     * next to the old one, which replaces the old directory once complete. The old
+     * index is left as it was when anything fails. Handles with a writer must be
+     * dropped first, and read-only handles reopened afterwards.
+     *
+     * The swap uses `renamex_np` on Apple platforms and `renameat2` on Linux with
+     * glibc. Other platforms, and file systems without an atomic swap, fail with
+     * `MigrationError` before any document is reindexed.
+     *
+     * A `<path>.migrating` directory left by an interrupted build is replaced, any
+     * other directory there fails the migration with `MigrationError`.
+     *
+     * `options` size the writer that builds the new index and are used to open it.
+     */
+public static func migrate(path: String, schemaBuilder: TantivySchemaBuilder, fieldMappings: [TantivyFieldMapping], options: TantivyIndexOptions, progress: TantivyMigrationProgress?)throws  -> TantivyIndex  {
+    return try  FfiConverterTypeTantivyIndex_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_constructor_tantivyindex_migrate(
+        FfiConverterString.lower(path),
+        FfiConverterTypeTantivySchemaBuilder_lower(schemaBuilder),
+        FfiConverterSequenceTypeTantivyFieldMapping.lower(fieldMappings),
+        FfiConverterTypeTantivyIndexOptions_lower(options),
+        FfiConverterOptionTypeTantivyMigrationProgress.lower(progress),$0
+    )
+})
+}
+    
+    /**
+     * Like `new_with_schema`, sizing the writer or leaving it out.
+     */
+public static func newWithOptions(path: String, schemaBuilder: TantivySchemaBuilder, options: TantivyIndexOptions)throws  -> TantivyIndex  {
+    return try  FfiConverterTypeTantivyIndex_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_constructor_tantivyindex_new_with_options(
+        FfiConverterString.lower(path),
+        FfiConverterTypeTantivySchemaBuilder_lower(schemaBuilder),
+        FfiConverterTypeTantivyIndexOptions_lower(options),$0
+    )
+})
+}
+    
 public static func newWithSchema(path: String, schemaBuilder: TantivySchemaBuilder)throws  -> TantivyIndex  {
     return try  FfiConverterTypeTantivyIndex_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
     uniffi_tantivy_fn_constructor_tantivyindex_new_with_schema(
@@ -680,8 +961,63 @@ public static func newWithSchema(path: String, schemaBuilder: TantivySchemaBuild
 })
 }
     
+    /**
+     * Opens an existing index with the schema saved in it.
+     */
+public static func `open`(path: String, options: TantivyIndexOptions)throws  -> TantivyIndex  {
+    return try  FfiConverterTypeTantivyIndex_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_constructor_tantivyindex_open(
+        FfiConverterString.lower(path),
+        FfiConverterTypeTantivyIndexOptions_lower(options),$0
+    )
+})
+}
+    
+    /**
+     * Opens an existing index for searching only, with the schema it was created with.
+     *
+     * No writer is created, so no directory lock is taken and the index can be
+     * searched while another process writes to it. Its commits show up shortly after.
+     */
+public static func openReadOnly(path: String)throws  -> TantivyIndex  {
+    return try  FfiConverterTypeTantivyIndex_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_constructor_tantivyindex_open_read_only(
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
 
     
+    /**
+     * Runs Elasticsearch-style aggregations over the documents matching the query DSL.
+     *
+     * Aggregated fields must be fast fields.
+     */
+open func aggregate(queryJson: String, aggregationsJson: String)throws  -> TantivyAggregationResults  {
+    return try  FfiConverterTypeTantivyAggregationResults_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_aggregate(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(queryJson),
+        FfiConverterString.lower(aggregationsJson),$0
+    )
+})
+}
+    
+    /**
+     * Runs `text` through the analyzer of a field, or through a registered
+     * tokenizer such as `unicode` when no field has that name.
+     */
+open func analyze(fieldOrTokenizer: String, text: String)throws  -> [TantivyToken]  {
+    return try  FfiConverterSequenceTypeTantivyToken.lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_analyze(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(fieldOrTokenizer),
+        FfiConverterString.lower(text),$0
+    )
+})
+}
+    
 open func clearIndex()throws   {try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
     uniffi_tantivy_fn_method_tantivyindex_clear_index(
             self.uniffiCloneHandle(),$0
@@ -694,6 +1030,20 @@ open func commit()throws   {try rustCallWithError(FfiConverterTypeTantivyIndexEr
             self.uniffiCloneHandle(),$0
     )
 }
+}
+    
+    /**
+     * Stages the deletion of every document matching the query DSL without committing.
+     *
+     * Returns how many documents currently visible to the reader matched.
+     */
+open func deleteByQuery(queryJson: String)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_delete_by_query(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(queryJson),$0
+    )
+})
 }
     
 open func deleteDoc(id: DocumentField)throws   {try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
@@ -702,6 +1052,20 @@ open func deleteDoc(id: DocumentField)throws   {try rustCallWithError(FfiConvert
         FfiConverterTypeDocumentField_lower(id),$0
     )
 }
+}
+    
+    /**
+     * Stages the deletion of every document matching one of `ids` without committing.
+     *
+     * Returns how many documents currently visible to the reader matched.
+     */
+open func deleteDocs(ids: [DocumentField])throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_delete_docs(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceTypeDocumentField.lower(ids),$0
+    )
+})
 }
     
 open func docExists(id: DocumentField)throws  -> Bool  {
@@ -721,20 +1085,53 @@ open func docsCount() -> UInt64  {
 })
 }
     
-open func getDoc(id: DocumentField)throws  -> TantivyDocumentFields  {
+    /**
+     * Explains how the query DSL scores the document identified by `id`.
+     *
+     * Fails if the document does not match the query.
+     */
+open func explain(queryJson: String, id: DocumentField)throws  -> TantivyExplanation  {
+    return try  FfiConverterTypeTantivyExplanation_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_explain(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(queryJson),
+        FfiConverterTypeDocumentField_lower(id),$0
+    )
+})
+}
+    
+    /**
+     * Counts the direct children of each facet in `root_paths` across the documents
+     * matching the query DSL, sorted by descending count.
+     */
+open func facetCounts(queryJson: String, field: String, rootPaths: [String], topK: UInt32?)throws  -> [TantivyFacetCounts]  {
+    return try  FfiConverterSequenceTypeTantivyFacetCounts.lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_facet_counts(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(queryJson),
+        FfiConverterString.lower(field),
+        FfiConverterSequenceString.lower(rootPaths),
+        FfiConverterOptionUInt32.lower(topK),$0
+    )
+})
+}
+    
+open func getDoc(id: DocumentField, fields: TantivyFieldProjection? = nil)throws  -> TantivyDocumentFields  {
     return try  FfiConverterTypeTantivyDocumentFields_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
     uniffi_tantivy_fn_method_tantivyindex_get_doc(
             self.uniffiCloneHandle(),
-        FfiConverterTypeDocumentField_lower(id),$0
+        FfiConverterTypeDocumentField_lower(id),
+        FfiConverterOptionTypeTantivyFieldProjection.lower(fields),$0
     )
 })
 }
     
-open func getDocsByIds(ids: [DocumentField])throws  -> [TantivyDocumentFields]  {
+open func getDocsByIds(ids: [DocumentField], fields: TantivyFieldProjection? = nil)throws  -> [TantivyDocumentFields]  {
     return try  FfiConverterSequenceTypeTantivyDocumentFields.lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
     uniffi_tantivy_fn_method_tantivyindex_get_docs_by_ids(
             self.uniffiCloneHandle(),
-        FfiConverterSequenceTypeDocumentField.lower(ids),$0
+        FfiConverterSequenceTypeDocumentField.lower(ids),
+        FfiConverterOptionTypeTantivyFieldProjection.lower(fields),$0
     )
 })
 }
@@ -753,6 +1150,35 @@ open func indexDocs(docs: [TantivyDocumentFields])throws   {try rustCallWithErro
         FfiConverterSequenceTypeTantivyDocumentFields.lower(docs),$0
     )
 }
+}
+    
+    /**
+     * Lists the indexed terms of a text field in lexicographic order, `limit` at a
+     * time, optionally only those starting with `prefix`.
+     *
+     * Terms are listed as indexed, `prefix` is not analyzed.
+     */
+open func listTerms(field: String, limit: UInt32, prefix: String? = nil, cursor: String? = nil)throws  -> TantivyTermPage  {
+    return try  FfiConverterTypeTantivyTermPage_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_list_terms(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(field),
+        FfiConverterUInt32.lower(limit),
+        FfiConverterOptionString.lower(prefix),
+        FfiConverterOptionString.lower(cursor),$0
+    )
+})
+}
+    
+    /**
+     * The fields of the index, in schema order.
+     */
+open func schema() -> [TantivyFieldDescriptor]  {
+    return try!  FfiConverterSequenceTypeTantivyFieldDescriptor.lift(try! rustCall() {
+    uniffi_tantivy_fn_method_tantivyindex_schema(
+            self.uniffiCloneHandle(),$0
+    )
+})
 }
     
 open func search(query: TantivySearchQuery)throws  -> TantivySearchResults  {
@@ -764,6 +1190,12 @@ open func search(query: TantivySearchQuery)throws  -> TantivySearchResults  {
 })
 }
     
+    /**
+     * Returns the u64 IDs of the hits, read from the fast column of `id_field`.
+     *
+     * Hits start after `cursor` when set. Only IDs are returned, so `fields` and
+     * `snippet_fields` fail with `QueryError` when set.
+     */
 open func searchDocIds(query: TantivySearchQuery, idField: String)throws  -> [TantivySearchHit]  {
     return try  FfiConverterSequenceTypeTantivySearchHit.lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
     uniffi_tantivy_fn_method_tantivyindex_search_doc_ids(
@@ -774,23 +1206,134 @@ open func searchDocIds(query: TantivySearchQuery, idField: String)throws  -> [Ta
 })
 }
     
-open func searchDsl(queryJson: String, topDocLimit: UInt32, topDocOffset: UInt32)throws  -> TantivySearchResults  {
+open func searchDsl(queryJson: String, topDocLimit: UInt32, topDocOffset: UInt32, sort: [TantivySortField] = [], snippetFields: [TantivySnippetField] = [], cursor: String? = nil, fields: TantivyFieldProjection? = nil)throws  -> TantivySearchResults  {
     return try  FfiConverterTypeTantivySearchResults_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
     uniffi_tantivy_fn_method_tantivyindex_search_dsl(
             self.uniffiCloneHandle(),
         FfiConverterString.lower(queryJson),
         FfiConverterUInt32.lower(topDocLimit),
-        FfiConverterUInt32.lower(topDocOffset),$0
+        FfiConverterUInt32.lower(topDocOffset),
+        FfiConverterSequenceTypeTantivySortField.lower(sort),
+        FfiConverterSequenceTypeTantivySnippetField.lower(snippetFields),
+        FfiConverterOptionString.lower(cursor),
+        FfiConverterOptionTypeTantivyFieldProjection.lower(fields),$0
     )
 })
 }
     
-
-    
+    /**
+     * Returns the IDs of the documents matching the query DSL, read from the fast
+     * column of `id_field` without loading stored documents. A hit without an ID
+     * fails the search with `MissingIdField`.
+     */
+open func searchIdsDsl(queryJson: String, idField: String, topDocLimit: UInt32, topDocOffset: UInt32, sort: [TantivySortField] = [], cursor: String? = nil)throws  -> TantivyIdSearchResults  {
+    return try  FfiConverterTypeTantivyIdSearchResults_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_search_ids_dsl(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(queryJson),
+        FfiConverterString.lower(idField),
+        FfiConverterUInt32.lower(topDocLimit),
+        FfiConverterUInt32.lower(topDocOffset),
+        FfiConverterSequenceTypeTantivySortField.lower(sort),
+        FfiConverterOptionString.lower(cursor),$0
+    )
+})
 }
-
-
-#if swift(>=5.8)
+    
+    /**
+     * Suggests corrections for each analyzed token of `text` from the indexed terms
+     * of a text field within `max_distance` edits (0 to 2, a transposition counts
+     * as one).
+     */
+open func spellSuggest(field: String, text: String, maxDistance: UInt8)throws  -> TantivySpellSuggestion  {
+    return try  FfiConverterTypeTantivySpellSuggestion_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_spell_suggest(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(field),
+        FfiConverterString.lower(text),
+        FfiConverterUInt8.lower(maxDistance),$0
+    )
+})
+}
+    
+    /**
+     * Completes the last word of `prefix` from the indexed terms of a text field,
+     * most frequent first.
+     *
+     * The prefix goes through the field's analyzer, so suggestions are terms as
+     * indexed, e.g. lowercased.
+     */
+open func suggest(field: String, prefix: String, limit: UInt32)throws  -> [TantivyTermSuggestion]  {
+    return try  FfiConverterSequenceTypeTantivyTermSuggestion.lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_suggest(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(field),
+        FfiConverterString.lower(prefix),
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+    
+    /**
+     * Counts of an indexed term of a text field, overall and per segment.
+     *
+     * `term` is looked up as indexed, it is not analyzed.
+     */
+open func termStats(field: String, term: String)throws  -> TantivyTermStats  {
+    return try  FfiConverterTypeTantivyTermStats_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_term_stats(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(field),
+        FfiConverterString.lower(term),$0
+    )
+})
+}
+    
+    /**
+     * Replaces any document sharing `id_field`'s value with `doc`.
+     *
+     * The delete and the add are staged under the same writer lock, so they land
+     * in the same commit and readers never observe the document as missing.
+     */
+open func upsertDoc(idField: String, doc: TantivyDocumentFields)throws   {try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_upsert_doc(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(idField),
+        FfiConverterTypeTantivyDocumentFields_lower(doc),$0
+    )
+}
+}
+    
+open func upsertDocs(idField: String, docs: [TantivyDocumentFields])throws   {try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_upsert_docs(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(idField),
+        FfiConverterSequenceTypeTantivyDocumentFields.lower(docs),$0
+    )
+}
+}
+    
+    /**
+     * Reports the syntax errors, unknown fields and invalid values of a query string
+     * that `search` would otherwise skip over.
+     */
+open func validateQuery(queryStr: String, defaultFields: [String], fuzzyFields: [TantivyFuzzyField])throws  -> TantivyQueryValidation  {
+    return try  FfiConverterTypeTantivyQueryValidation_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyindex_validate_query(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(queryStr),
+        FfiConverterSequenceString.lower(defaultFields),
+        FfiConverterSequenceTypeTantivyFuzzyField.lower(fuzzyFields),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
 public struct FfiConverterTypeTantivyIndex: FfiConverter {
@@ -835,8 +1378,203 @@ public func FfiConverterTypeTantivyIndex_lower(_ value: TantivyIndex) -> UInt64
 
 
 
+public protocol TantivyMigrationProgress: AnyObject, Sendable {
+    
+    /**
+     * Called every thousand documents and once all `total` are migrated.
+     */
+    func onProgress(migrated: UInt64, total: UInt64) 
+    
+}
+open class TantivyMigrationProgressImpl: TantivyMigrationProgress, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_tantivy_fn_clone_tantivymigrationprogress(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        try! rustCall { uniffi_tantivy_fn_free_tantivymigrationprogress(handle, $0) }
+    }
+
+    
+
+    
+    /**
+     * Called every thousand documents and once all `total` are migrated.
+     */
+open func onProgress(migrated: UInt64, total: UInt64)  {try! rustCall() {
+    uniffi_tantivy_fn_method_tantivymigrationprogress_on_progress(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(migrated),
+        FfiConverterUInt64.lower(total),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceTantivyMigrationProgress {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceTantivyMigrationProgress] = [UniffiVTableCallbackInterfaceTantivyMigrationProgress(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeTantivyMigrationProgress.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface TantivyMigrationProgress: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeTantivyMigrationProgress.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface TantivyMigrationProgress: handle missing in uniffiClone")
+            }
+        },
+        onProgress: { (
+            uniffiHandle: UInt64,
+            migrated: UInt64,
+            total: UInt64,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeTantivyMigrationProgress.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onProgress(
+                     migrated: try FfiConverterUInt64.lift(migrated),
+                     total: try FfiConverterUInt64.lift(total)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitTantivyMigrationProgress() {
+    uniffi_tantivy_fn_init_callback_vtable_tantivymigrationprogress(UniffiCallbackInterfaceTantivyMigrationProgress.vtable)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyMigrationProgress: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<TantivyMigrationProgress>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = TantivyMigrationProgress
+
+    public static func lift(_ handle: UInt64) throws -> TantivyMigrationProgress {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return TantivyMigrationProgressImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
+    }
+
+    public static func lower(_ value: TantivyMigrationProgress) -> UInt64 {
+         if let rustImpl = value as? TantivyMigrationProgressImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyMigrationProgress {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: TantivyMigrationProgress, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyMigrationProgress_lift(_ handle: UInt64) throws -> TantivyMigrationProgress {
+    return try FfiConverterTypeTantivyMigrationProgress.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyMigrationProgress_lower(_ value: TantivyMigrationProgress) -> UInt64 {
+    return FfiConverterTypeTantivyMigrationProgress.lower(value)
+}
+
+
+
+
+
+
 public protocol TantivySchemaBuilderProtocol: AnyObject, Sendable {
     
+    /**
+     * Registers a named analyzer on indexes built from this schema.
+     */
+    func addAnalyzer(analyzer: TantivyAnalyzerBuilder) 
+    
     func addBoolField(name: String, options: NumericFieldOptions) 
     
     func addBytesField(name: String, stored: Bool, fast: Bool, indexed: Bool) 
@@ -849,12 +1587,31 @@ public protocol TantivySchemaBuilderProtocol: AnyObject, Sendable {
     
     func addI64Field(name: String, options: NumericFieldOptions) 
     
+    func addIpAddrField(name: String, options: NumericFieldOptions) 
+    
     func addJsonField(name: String, options: JsonFieldOptions) 
     
     func addTextField(name: String, options: TextFieldOptions) 
     
     func addU64Field(name: String, options: NumericFieldOptions) 
     
+    /**
+     * Compares the schema being built with the index at `path`, empty when there is
+     * no index there yet. The builder can still be used afterwards.
+     */
+    func diffWithIndex(path: String) throws  -> TantivySchemaDiff
+    
+    /**
+     * The fields added so far.
+     */
+    func fields()  -> [TantivyFieldDescriptor]
+    
+    /**
+     * The fields added so far, serialized the way tantivy stores its schema.
+     * Analyzers are not included.
+     */
+    func toJson() throws  -> String
+    
 }
 open class TantivySchemaBuilder: TantivySchemaBuilderProtocol, @unchecked Sendable {
     fileprivate let handle: UInt64
@@ -909,8 +1666,31 @@ public convenience init() {
     }
 
     
+    /**
+     * A builder holding the fields of a schema serialized by tantivy, e.g. the
+     * `schema.json` written by `schema-gen` or the `schema` of an index's meta.json.
+     */
+public static func fromJson(json: String)throws  -> TantivySchemaBuilder  {
+    return try  FfiConverterTypeTantivySchemaBuilder_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_constructor_tantivyschemabuilder_from_json(
+        FfiConverterString.lower(json),$0
+    )
+})
+}
+    
 
     
+    /**
+     * Registers a named analyzer on indexes built from this schema.
+     */
+open func addAnalyzer(analyzer: TantivyAnalyzerBuilder)  {try! rustCall() {
+    uniffi_tantivy_fn_method_tantivyschemabuilder_add_analyzer(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeTantivyAnalyzerBuilder_lower(analyzer),$0
+    )
+}
+}
+    
 open func addBoolField(name: String, options: NumericFieldOptions)  {try! rustCall() {
     uniffi_tantivy_fn_method_tantivyschemabuilder_add_bool_field(
             self.uniffiCloneHandle(),
@@ -967,6 +1747,15 @@ open func addI64Field(name: String, options: NumericFieldOptions)  {try! rustCal
 }
 }
     
+open func addIpAddrField(name: String, options: NumericFieldOptions)  {try! rustCall() {
+    uniffi_tantivy_fn_method_tantivyschemabuilder_add_ip_addr_field(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(name),
+        FfiConverterTypeNumericFieldOptions_lower(options),$0
+    )
+}
+}
+    
 open func addJsonField(name: String, options: JsonFieldOptions)  {try! rustCall() {
     uniffi_tantivy_fn_method_tantivyschemabuilder_add_json_field(
             self.uniffiCloneHandle(),
@@ -994,6 +1783,42 @@ open func addU64Field(name: String, options: NumericFieldOptions)  {try! rustCal
 }
 }
     
+    /**
+     * Compares the schema being built with the index at `path`, empty when there is
+     * no index there yet. The builder can still be used afterwards.
+     */
+open func diffWithIndex(path: String)throws  -> TantivySchemaDiff  {
+    return try  FfiConverterTypeTantivySchemaDiff_lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyschemabuilder_diff_with_index(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * The fields added so far.
+     */
+open func fields() -> [TantivyFieldDescriptor]  {
+    return try!  FfiConverterSequenceTypeTantivyFieldDescriptor.lift(try! rustCall() {
+    uniffi_tantivy_fn_method_tantivyschemabuilder_fields(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * The fields added so far, serialized the way tantivy stores its schema.
+     * Analyzers are not included.
+     */
+open func toJson()throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeTantivyIndexError_lift) {
+    uniffi_tantivy_fn_method_tantivyschemabuilder_to_json(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
 
     
 }
@@ -1345,38 +2170,73 @@ public func FfiConverterTypeNumericFieldOptions_lower(_ value: NumericFieldOptio
 }
 
 
-/**
- * A complete document (scalar fields only)
- */
-public struct TantivyDocumentFields: Equatable, Hashable {
-    public var fields: [DocumentField]
+public struct TantivyAggregationBucket: Equatable, Hashable {
+    public var key: TantivyAggregationKey
+    /**
+     * Formatted key, e.g. RFC 3339 for date histograms.
+     */
+    public var keyAsString: String?
+    public var docCount: UInt64
+    /**
+     * Lower bound of a range bucket.
+     */
+    public var from: Double?
+    /**
+     * Upper bound of a range bucket.
+     */
+    public var to: Double?
+    public var subAggregations: [TantivyAggregationResult]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(fields: [DocumentField]) {
-        self.fields = fields
+    public init(key: TantivyAggregationKey, 
+        /**
+         * Formatted key, e.g. RFC 3339 for date histograms.
+         */keyAsString: String?, docCount: UInt64, 
+        /**
+         * Lower bound of a range bucket.
+         */from: Double?, 
+        /**
+         * Upper bound of a range bucket.
+         */to: Double?, subAggregations: [TantivyAggregationResult]) {
+        self.key = key
+        self.keyAsString = keyAsString
+        self.docCount = docCount
+        self.from = from
+        self.to = to
+        self.subAggregations = subAggregations
     }
 
     
 }
 
 #if compiler(>=6)
-extension TantivyDocumentFields: Sendable {}
+extension TantivyAggregationBucket: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivyDocumentFields: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyDocumentFields {
+public struct FfiConverterTypeTantivyAggregationBucket: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyAggregationBucket {
         return
-            try TantivyDocumentFields(
-                fields: FfiConverterSequenceTypeDocumentField.read(from: &buf)
+            try TantivyAggregationBucket(
+                key: FfiConverterTypeTantivyAggregationKey.read(from: &buf), 
+                keyAsString: FfiConverterOptionString.read(from: &buf), 
+                docCount: FfiConverterUInt64.read(from: &buf), 
+                from: FfiConverterOptionDouble.read(from: &buf), 
+                to: FfiConverterOptionDouble.read(from: &buf), 
+                subAggregations: FfiConverterSequenceTypeTantivyAggregationResult.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TantivyDocumentFields, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypeDocumentField.write(value.fields, into: &buf)
+    public static func write(_ value: TantivyAggregationBucket, into buf: inout [UInt8]) {
+        FfiConverterTypeTantivyAggregationKey.write(value.key, into: &buf)
+        FfiConverterOptionString.write(value.keyAsString, into: &buf)
+        FfiConverterUInt64.write(value.docCount, into: &buf)
+        FfiConverterOptionDouble.write(value.from, into: &buf)
+        FfiConverterOptionDouble.write(value.to, into: &buf)
+        FfiConverterSequenceTypeTantivyAggregationResult.write(value.subAggregations, into: &buf)
     }
 }
 
@@ -1384,59 +2244,51 @@ public struct FfiConverterTypeTantivyDocumentFields: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyDocumentFields_lift(_ buf: RustBuffer) throws -> TantivyDocumentFields {
-    return try FfiConverterTypeTantivyDocumentFields.lift(buf)
+public func FfiConverterTypeTantivyAggregationBucket_lift(_ buf: RustBuffer) throws -> TantivyAggregationBucket {
+    return try FfiConverterTypeTantivyAggregationBucket.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyDocumentFields_lower(_ value: TantivyDocumentFields) -> RustBuffer {
-    return FfiConverterTypeTantivyDocumentFields.lower(value)
+public func FfiConverterTypeTantivyAggregationBucket_lower(_ value: TantivyAggregationBucket) -> RustBuffer {
+    return FfiConverterTypeTantivyAggregationBucket.lower(value)
 }
 
 
-public struct TantivyFuzzyField: Equatable, Hashable {
-    public var fieldName: String
-    public var prefix: Bool
-    public var distance: UInt8
-    public var transposeCostOne: Bool
+public struct TantivyAggregationResult: Equatable, Hashable {
+    public var name: String
+    public var value: TantivyAggregationValue
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(fieldName: String, prefix: Bool, distance: UInt8, transposeCostOne: Bool) {
-        self.fieldName = fieldName
-        self.prefix = prefix
-        self.distance = distance
-        self.transposeCostOne = transposeCostOne
+    public init(name: String, value: TantivyAggregationValue) {
+        self.name = name
+        self.value = value
     }
 
     
 }
 
 #if compiler(>=6)
-extension TantivyFuzzyField: Sendable {}
+extension TantivyAggregationResult: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivyFuzzyField: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyFuzzyField {
+public struct FfiConverterTypeTantivyAggregationResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyAggregationResult {
         return
-            try TantivyFuzzyField(
-                fieldName: FfiConverterString.read(from: &buf), 
-                prefix: FfiConverterBool.read(from: &buf), 
-                distance: FfiConverterUInt8.read(from: &buf), 
-                transposeCostOne: FfiConverterBool.read(from: &buf)
+            try TantivyAggregationResult(
+                name: FfiConverterString.read(from: &buf), 
+                value: FfiConverterTypeTantivyAggregationValue.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TantivyFuzzyField, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.fieldName, into: &buf)
-        FfiConverterBool.write(value.prefix, into: &buf)
-        FfiConverterUInt8.write(value.distance, into: &buf)
-        FfiConverterBool.write(value.transposeCostOne, into: &buf)
+    public static func write(_ value: TantivyAggregationResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeTantivyAggregationValue.write(value.value, into: &buf)
     }
 }
 
@@ -1444,51 +2296,51 @@ public struct FfiConverterTypeTantivyFuzzyField: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyFuzzyField_lift(_ buf: RustBuffer) throws -> TantivyFuzzyField {
-    return try FfiConverterTypeTantivyFuzzyField.lift(buf)
+public func FfiConverterTypeTantivyAggregationResult_lift(_ buf: RustBuffer) throws -> TantivyAggregationResult {
+    return try FfiConverterTypeTantivyAggregationResult.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyFuzzyField_lower(_ value: TantivyFuzzyField) -> RustBuffer {
-    return FfiConverterTypeTantivyFuzzyField.lower(value)
+public func FfiConverterTypeTantivyAggregationResult_lower(_ value: TantivyAggregationResult) -> RustBuffer {
+    return FfiConverterTypeTantivyAggregationResult.lower(value)
 }
 
 
-public struct TantivySearchHit: Equatable, Hashable {
-    public var docId: UInt64
-    public var score: Float
+public struct TantivyAggregationResults: Equatable, Hashable {
+    public var count: UInt64
+    public var aggregations: [TantivyAggregationResult]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(docId: UInt64, score: Float) {
-        self.docId = docId
-        self.score = score
+    public init(count: UInt64, aggregations: [TantivyAggregationResult]) {
+        self.count = count
+        self.aggregations = aggregations
     }
 
     
 }
 
 #if compiler(>=6)
-extension TantivySearchHit: Sendable {}
+extension TantivyAggregationResults: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivySearchHit: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySearchHit {
+public struct FfiConverterTypeTantivyAggregationResults: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyAggregationResults {
         return
-            try TantivySearchHit(
-                docId: FfiConverterUInt64.read(from: &buf), 
-                score: FfiConverterFloat.read(from: &buf)
+            try TantivyAggregationResults(
+                count: FfiConverterUInt64.read(from: &buf), 
+                aggregations: FfiConverterSequenceTypeTantivyAggregationResult.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TantivySearchHit, into buf: inout [UInt8]) {
-        FfiConverterUInt64.write(value.docId, into: &buf)
-        FfiConverterFloat.write(value.score, into: &buf)
+    public static func write(_ value: TantivyAggregationResults, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.count, into: &buf)
+        FfiConverterSequenceTypeTantivyAggregationResult.write(value.aggregations, into: &buf)
     }
 }
 
@@ -1496,63 +2348,50 @@ public struct FfiConverterTypeTantivySearchHit: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivySearchHit_lift(_ buf: RustBuffer) throws -> TantivySearchHit {
-    return try FfiConverterTypeTantivySearchHit.lift(buf)
+public func FfiConverterTypeTantivyAggregationResults_lift(_ buf: RustBuffer) throws -> TantivyAggregationResults {
+    return try FfiConverterTypeTantivyAggregationResults.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivySearchHit_lower(_ value: TantivySearchHit) -> RustBuffer {
-    return FfiConverterTypeTantivySearchHit.lower(value)
+public func FfiConverterTypeTantivyAggregationResults_lower(_ value: TantivyAggregationResults) -> RustBuffer {
+    return FfiConverterTypeTantivyAggregationResults.lower(value)
 }
 
 
-public struct TantivySearchQuery: Equatable, Hashable {
-    public var queryStr: String
-    public var defaultFields: [String]
-    public var fuzzyFields: [TantivyFuzzyField]
-    public var topDocLimit: UInt32
-    public var topDocOffset: UInt32
+/**
+ * A complete document (scalar fields only)
+ */
+public struct TantivyDocumentFields: Equatable, Hashable {
+    public var fields: [DocumentField]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(queryStr: String, defaultFields: [String], fuzzyFields: [TantivyFuzzyField], topDocLimit: UInt32, topDocOffset: UInt32) {
-        self.queryStr = queryStr
-        self.defaultFields = defaultFields
-        self.fuzzyFields = fuzzyFields
-        self.topDocLimit = topDocLimit
-        self.topDocOffset = topDocOffset
+    public init(fields: [DocumentField]) {
+        self.fields = fields
     }
 
     
 }
 
 #if compiler(>=6)
-extension TantivySearchQuery: Sendable {}
+extension TantivyDocumentFields: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivySearchQuery: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySearchQuery {
+public struct FfiConverterTypeTantivyDocumentFields: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyDocumentFields {
         return
-            try TantivySearchQuery(
-                queryStr: FfiConverterString.read(from: &buf), 
-                defaultFields: FfiConverterSequenceString.read(from: &buf), 
-                fuzzyFields: FfiConverterSequenceTypeTantivyFuzzyField.read(from: &buf), 
-                topDocLimit: FfiConverterUInt32.read(from: &buf), 
-                topDocOffset: FfiConverterUInt32.read(from: &buf)
+            try TantivyDocumentFields(
+                fields: FfiConverterSequenceTypeDocumentField.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TantivySearchQuery, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.queryStr, into: &buf)
-        FfiConverterSequenceString.write(value.defaultFields, into: &buf)
-        FfiConverterSequenceTypeTantivyFuzzyField.write(value.fuzzyFields, into: &buf)
-        FfiConverterUInt32.write(value.topDocLimit, into: &buf)
-        FfiConverterUInt32.write(value.topDocOffset, into: &buf)
+    public static func write(_ value: TantivyDocumentFields, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeDocumentField.write(value.fields, into: &buf)
     }
 }
 
@@ -1560,51 +2399,68 @@ public struct FfiConverterTypeTantivySearchQuery: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivySearchQuery_lift(_ buf: RustBuffer) throws -> TantivySearchQuery {
-    return try FfiConverterTypeTantivySearchQuery.lift(buf)
+public func FfiConverterTypeTantivyDocumentFields_lift(_ buf: RustBuffer) throws -> TantivyDocumentFields {
+    return try FfiConverterTypeTantivyDocumentFields.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivySearchQuery_lower(_ value: TantivySearchQuery) -> RustBuffer {
-    return FfiConverterTypeTantivySearchQuery.lower(value)
+public func FfiConverterTypeTantivyDocumentFields_lower(_ value: TantivyDocumentFields) -> RustBuffer {
+    return FfiConverterTypeTantivyDocumentFields.lower(value)
 }
 
 
-public struct TantivySearchResult: Equatable, Hashable {
-    public var score: Float
-    public var doc: TantivyDocumentFields
+/**
+ * One node of a score explanation: how `value` was computed from its children.
+ */
+public struct TantivyExplanation: Equatable, Hashable {
+    public var value: Float
+    public var description: String
+    /**
+     * Extra notes attached by the query, e.g. the term being scored.
+     */
+    public var context: [String]
+    public var children: [TantivyExplanation]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(score: Float, doc: TantivyDocumentFields) {
-        self.score = score
-        self.doc = doc
+    public init(value: Float, description: String, 
+        /**
+         * Extra notes attached by the query, e.g. the term being scored.
+         */context: [String], children: [TantivyExplanation]) {
+        self.value = value
+        self.description = description
+        self.context = context
+        self.children = children
     }
 
     
 }
 
 #if compiler(>=6)
-extension TantivySearchResult: Sendable {}
+extension TantivyExplanation: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivySearchResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySearchResult {
+public struct FfiConverterTypeTantivyExplanation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyExplanation {
         return
-            try TantivySearchResult(
-                score: FfiConverterFloat.read(from: &buf), 
-                doc: FfiConverterTypeTantivyDocumentFields.read(from: &buf)
+            try TantivyExplanation(
+                value: FfiConverterFloat.read(from: &buf), 
+                description: FfiConverterString.read(from: &buf), 
+                context: FfiConverterSequenceString.read(from: &buf), 
+                children: FfiConverterSequenceTypeTantivyExplanation.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TantivySearchResult, into buf: inout [UInt8]) {
-        FfiConverterFloat.write(value.score, into: &buf)
-        FfiConverterTypeTantivyDocumentFields.write(value.doc, into: &buf)
+    public static func write(_ value: TantivyExplanation, into buf: inout [UInt8]) {
+        FfiConverterFloat.write(value.value, into: &buf)
+        FfiConverterString.write(value.description, into: &buf)
+        FfiConverterSequenceString.write(value.context, into: &buf)
+        FfiConverterSequenceTypeTantivyExplanation.write(value.children, into: &buf)
     }
 }
 
@@ -1612,51 +2468,51 @@ public struct FfiConverterTypeTantivySearchResult: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivySearchResult_lift(_ buf: RustBuffer) throws -> TantivySearchResult {
-    return try FfiConverterTypeTantivySearchResult.lift(buf)
+public func FfiConverterTypeTantivyExplanation_lift(_ buf: RustBuffer) throws -> TantivyExplanation {
+    return try FfiConverterTypeTantivyExplanation.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivySearchResult_lower(_ value: TantivySearchResult) -> RustBuffer {
-    return FfiConverterTypeTantivySearchResult.lower(value)
+public func FfiConverterTypeTantivyExplanation_lower(_ value: TantivyExplanation) -> RustBuffer {
+    return FfiConverterTypeTantivyExplanation.lower(value)
 }
 
 
-public struct TantivySearchResults: Equatable, Hashable {
+public struct TantivyFacetCount: Equatable, Hashable {
+    public var path: String
     public var count: UInt64
-    public var docs: [TantivySearchResult]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(count: UInt64, docs: [TantivySearchResult]) {
+    public init(path: String, count: UInt64) {
+        self.path = path
         self.count = count
-        self.docs = docs
     }
 
     
 }
 
 #if compiler(>=6)
-extension TantivySearchResults: Sendable {}
+extension TantivyFacetCount: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivySearchResults: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySearchResults {
+public struct FfiConverterTypeTantivyFacetCount: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyFacetCount {
         return
-            try TantivySearchResults(
-                count: FfiConverterUInt64.read(from: &buf), 
-                docs: FfiConverterSequenceTypeTantivySearchResult.read(from: &buf)
+            try TantivyFacetCount(
+                path: FfiConverterString.read(from: &buf), 
+                count: FfiConverterUInt64.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TantivySearchResults, into buf: inout [UInt8]) {
+    public static func write(_ value: TantivyFacetCount, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
         FfiConverterUInt64.write(value.count, into: &buf)
-        FfiConverterSequenceTypeTantivySearchResult.write(value.docs, into: &buf)
     }
 }
 
@@ -1664,63 +2520,51 @@ public struct FfiConverterTypeTantivySearchResults: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivySearchResults_lift(_ buf: RustBuffer) throws -> TantivySearchResults {
-    return try FfiConverterTypeTantivySearchResults.lift(buf)
+public func FfiConverterTypeTantivyFacetCount_lift(_ buf: RustBuffer) throws -> TantivyFacetCount {
+    return try FfiConverterTypeTantivyFacetCount.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivySearchResults_lower(_ value: TantivySearchResults) -> RustBuffer {
-    return FfiConverterTypeTantivySearchResults.lower(value)
+public func FfiConverterTypeTantivyFacetCount_lower(_ value: TantivyFacetCount) -> RustBuffer {
+    return FfiConverterTypeTantivyFacetCount.lower(value)
 }
 
 
-public struct TextFieldOptions: Equatable, Hashable {
-    public var tokenizer: TantivyTokenizer
-    public var record: TantivyIndexRecordOption
-    public var stored: Bool
-    public var fast: Bool
-    public var fieldnorms: Bool
+public struct TantivyFacetCounts: Equatable, Hashable {
+    public var root: String
+    public var children: [TantivyFacetCount]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(tokenizer: TantivyTokenizer, record: TantivyIndexRecordOption, stored: Bool, fast: Bool, fieldnorms: Bool) {
-        self.tokenizer = tokenizer
-        self.record = record
-        self.stored = stored
-        self.fast = fast
-        self.fieldnorms = fieldnorms
+    public init(root: String, children: [TantivyFacetCount]) {
+        self.root = root
+        self.children = children
     }
 
     
 }
 
 #if compiler(>=6)
-extension TextFieldOptions: Sendable {}
+extension TantivyFacetCounts: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTextFieldOptions: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextFieldOptions {
+public struct FfiConverterTypeTantivyFacetCounts: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyFacetCounts {
         return
-            try TextFieldOptions(
-                tokenizer: FfiConverterTypeTantivyTokenizer.read(from: &buf), 
-                record: FfiConverterTypeTantivyIndexRecordOption.read(from: &buf), 
-                stored: FfiConverterBool.read(from: &buf), 
-                fast: FfiConverterBool.read(from: &buf), 
-                fieldnorms: FfiConverterBool.read(from: &buf)
+            try TantivyFacetCounts(
+                root: FfiConverterString.read(from: &buf), 
+                children: FfiConverterSequenceTypeTantivyFacetCount.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TextFieldOptions, into buf: inout [UInt8]) {
-        FfiConverterTypeTantivyTokenizer.write(value.tokenizer, into: &buf)
-        FfiConverterTypeTantivyIndexRecordOption.write(value.record, into: &buf)
-        FfiConverterBool.write(value.stored, into: &buf)
-        FfiConverterBool.write(value.fast, into: &buf)
-        FfiConverterBool.write(value.fieldnorms, into: &buf)
+    public static func write(_ value: TantivyFacetCounts, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.root, into: &buf)
+        FfiConverterSequenceTypeTantivyFacetCount.write(value.children, into: &buf)
     }
 }
 
@@ -1728,145 +2572,167 @@ public struct FfiConverterTypeTextFieldOptions: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTextFieldOptions_lift(_ buf: RustBuffer) throws -> TextFieldOptions {
-    return try FfiConverterTypeTextFieldOptions.lift(buf)
+public func FfiConverterTypeTantivyFacetCounts_lift(_ buf: RustBuffer) throws -> TantivyFacetCounts {
+    return try FfiConverterTypeTantivyFacetCounts.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTextFieldOptions_lower(_ value: TextFieldOptions) -> RustBuffer {
-    return FfiConverterTypeTextFieldOptions.lower(value)
+public func FfiConverterTypeTantivyFacetCounts_lower(_ value: TantivyFacetCounts) -> RustBuffer {
+    return FfiConverterTypeTantivyFacetCounts.lower(value)
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
 /**
- * A field value passed from Swift - no JSON serialization needed
+ * One option of a field that differs between the index on disk and the schema
+ * builder, e.g. `options.indexing.tokenizer`. `None` where the option is not set.
  */
+public struct TantivyFieldChange: Equatable, Hashable {
+    public var field: String
+    public var option: String
+    public var onDisk: String?
+    public var requested: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(field: String, option: String, onDisk: String?, requested: String?) {
+        self.field = field
+        self.option = option
+        self.onDisk = onDisk
+        self.requested = requested
+    }
 
-public enum FieldValue: Equatable, Hashable {
     
-    case text(String
-    )
-    case u64(UInt64
-    )
-    case i64(Int64
-    )
-    case f64(Double
-    )
-    case bool(Bool
-    )
+}
+
+#if compiler(>=6)
+extension TantivyFieldChange: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyFieldChange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyFieldChange {
+        return
+            try TantivyFieldChange(
+                field: FfiConverterString.read(from: &buf), 
+                option: FfiConverterString.read(from: &buf), 
+                onDisk: FfiConverterOptionString.read(from: &buf), 
+                requested: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivyFieldChange, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.field, into: &buf)
+        FfiConverterString.write(value.option, into: &buf)
+        FfiConverterOptionString.write(value.onDisk, into: &buf)
+        FfiConverterOptionString.write(value.requested, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyFieldChange_lift(_ buf: RustBuffer) throws -> TantivyFieldChange {
+    return try FfiConverterTypeTantivyFieldChange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyFieldChange_lower(_ value: TantivyFieldChange) -> RustBuffer {
+    return FfiConverterTypeTantivyFieldChange.lower(value)
+}
+
+
+/**
+ * A field of a schema, with the options of the `add_*_field` method that adds it.
+ * Options that do not apply to the field type are `None`.
+ */
+public struct TantivyFieldDescriptor: Equatable, Hashable {
+    public var name: String
+    public var fieldType: TantivyFieldType
+    public var indexed: Bool
+    public var stored: Bool
+    public var fast: Bool
+    public var fieldnorms: Bool
     /**
-     * Unix timestamp in microseconds
+     * Tokenizer of indexed text and JSON fields.
      */
-    case date(Int64
-    )
-    case bytes(Data
-    )
-    case facet(String
-    )
-    case json(String
-    )
-
+    public var tokenizer: TantivyTokenizer?
+    public var record: TantivyIndexRecordOption?
+    /**
+     * Tokenizer of fast text and JSON fields, `None` keeps the text as is.
+     */
+    public var fastTokenizer: TantivyTokenizer?
+    public var precision: TantivyDatePrecision?
+    public var expandDots: Bool?
 
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, fieldType: TantivyFieldType, indexed: Bool, stored: Bool, fast: Bool, fieldnorms: Bool, 
+        /**
+         * Tokenizer of indexed text and JSON fields.
+         */tokenizer: TantivyTokenizer?, record: TantivyIndexRecordOption?, 
+        /**
+         * Tokenizer of fast text and JSON fields, `None` keeps the text as is.
+         */fastTokenizer: TantivyTokenizer?, precision: TantivyDatePrecision?, expandDots: Bool?) {
+        self.name = name
+        self.fieldType = fieldType
+        self.indexed = indexed
+        self.stored = stored
+        self.fast = fast
+        self.fieldnorms = fieldnorms
+        self.tokenizer = tokenizer
+        self.record = record
+        self.fastTokenizer = fastTokenizer
+        self.precision = precision
+        self.expandDots = expandDots
+    }
 
+    
 }
 
 #if compiler(>=6)
-extension FieldValue: Sendable {}
+extension TantivyFieldDescriptor: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFieldValue: FfiConverterRustBuffer {
-    typealias SwiftType = FieldValue
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FieldValue {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .text(try FfiConverterString.read(from: &buf)
-        )
-        
-        case 2: return .u64(try FfiConverterUInt64.read(from: &buf)
-        )
-        
-        case 3: return .i64(try FfiConverterInt64.read(from: &buf)
-        )
-        
-        case 4: return .f64(try FfiConverterDouble.read(from: &buf)
+public struct FfiConverterTypeTantivyFieldDescriptor: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyFieldDescriptor {
+        return
+            try TantivyFieldDescriptor(
+                name: FfiConverterString.read(from: &buf), 
+                fieldType: FfiConverterTypeTantivyFieldType.read(from: &buf), 
+                indexed: FfiConverterBool.read(from: &buf), 
+                stored: FfiConverterBool.read(from: &buf), 
+                fast: FfiConverterBool.read(from: &buf), 
+                fieldnorms: FfiConverterBool.read(from: &buf), 
+                tokenizer: FfiConverterOptionTypeTantivyTokenizer.read(from: &buf), 
+                record: FfiConverterOptionTypeTantivyIndexRecordOption.read(from: &buf), 
+                fastTokenizer: FfiConverterOptionTypeTantivyTokenizer.read(from: &buf), 
+                precision: FfiConverterOptionTypeTantivyDatePrecision.read(from: &buf), 
+                expandDots: FfiConverterOptionBool.read(from: &buf)
         )
-        
-        case 5: return .bool(try FfiConverterBool.read(from: &buf)
-        )
-        
-        case 6: return .date(try FfiConverterInt64.read(from: &buf)
-        )
-        
-        case 7: return .bytes(try FfiConverterData.read(from: &buf)
-        )
-        
-        case 8: return .facet(try FfiConverterString.read(from: &buf)
-        )
-        
-        case 9: return .json(try FfiConverterString.read(from: &buf)
-        )
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
     }
 
-    public static func write(_ value: FieldValue, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case let .text(v1):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .u64(v1):
-            writeInt(&buf, Int32(2))
-            FfiConverterUInt64.write(v1, into: &buf)
-            
-        
-        case let .i64(v1):
-            writeInt(&buf, Int32(3))
-            FfiConverterInt64.write(v1, into: &buf)
-            
-        
-        case let .f64(v1):
-            writeInt(&buf, Int32(4))
-            FfiConverterDouble.write(v1, into: &buf)
-            
-        
-        case let .bool(v1):
-            writeInt(&buf, Int32(5))
-            FfiConverterBool.write(v1, into: &buf)
-            
-        
-        case let .date(v1):
-            writeInt(&buf, Int32(6))
-            FfiConverterInt64.write(v1, into: &buf)
-            
-        
-        case let .bytes(v1):
-            writeInt(&buf, Int32(7))
-            FfiConverterData.write(v1, into: &buf)
-            
-        
-        case let .facet(v1):
-            writeInt(&buf, Int32(8))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .json(v1):
-            writeInt(&buf, Int32(9))
-            FfiConverterString.write(v1, into: &buf)
-            
-        }
+    public static func write(_ value: TantivyFieldDescriptor, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeTantivyFieldType.write(value.fieldType, into: &buf)
+        FfiConverterBool.write(value.indexed, into: &buf)
+        FfiConverterBool.write(value.stored, into: &buf)
+        FfiConverterBool.write(value.fast, into: &buf)
+        FfiConverterBool.write(value.fieldnorms, into: &buf)
+        FfiConverterOptionTypeTantivyTokenizer.write(value.tokenizer, into: &buf)
+        FfiConverterOptionTypeTantivyIndexRecordOption.write(value.record, into: &buf)
+        FfiConverterOptionTypeTantivyTokenizer.write(value.fastTokenizer, into: &buf)
+        FfiConverterOptionTypeTantivyDatePrecision.write(value.precision, into: &buf)
+        FfiConverterOptionBool.write(value.expandDots, into: &buf)
     }
 }
 
@@ -1874,71 +2740,114 @@ public struct FfiConverterTypeFieldValue: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFieldValue_lift(_ buf: RustBuffer) throws -> FieldValue {
-    return try FfiConverterTypeFieldValue.lift(buf)
+public func FfiConverterTypeTantivyFieldDescriptor_lift(_ buf: RustBuffer) throws -> TantivyFieldDescriptor {
+    return try FfiConverterTypeTantivyFieldDescriptor.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFieldValue_lower(_ value: FieldValue) -> RustBuffer {
-    return FfiConverterTypeFieldValue.lower(value)
+public func FfiConverterTypeTantivyFieldDescriptor_lower(_ value: TantivyFieldDescriptor) -> RustBuffer {
+    return FfiConverterTypeTantivyFieldDescriptor.lower(value)
 }
 
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+public struct TantivyFuzzyField: Equatable, Hashable {
+    public var fieldName: String
+    public var prefix: Bool
+    public var distance: UInt8
+    public var transposeCostOne: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(fieldName: String, prefix: Bool, distance: UInt8, transposeCostOne: Bool) {
+        self.fieldName = fieldName
+        self.prefix = prefix
+        self.distance = distance
+        self.transposeCostOne = transposeCostOne
+    }
 
-public enum TantivyDatePrecision: Equatable, Hashable {
     
-    case seconds
-    case milliseconds
-    case microseconds
+}
 
+#if compiler(>=6)
+extension TantivyFuzzyField: Sendable {}
+#endif
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyFuzzyField: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyFuzzyField {
+        return
+            try TantivyFuzzyField(
+                fieldName: FfiConverterString.read(from: &buf), 
+                prefix: FfiConverterBool.read(from: &buf), 
+                distance: FfiConverterUInt8.read(from: &buf), 
+                transposeCostOne: FfiConverterBool.read(from: &buf)
+        )
+    }
 
+    public static func write(_ value: TantivyFuzzyField, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.fieldName, into: &buf)
+        FfiConverterBool.write(value.prefix, into: &buf)
+        FfiConverterUInt8.write(value.distance, into: &buf)
+        FfiConverterBool.write(value.transposeCostOne, into: &buf)
+    }
 }
 
-#if compiler(>=6)
-extension TantivyDatePrecision: Sendable {}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+public func FfiConverterTypeTantivyFuzzyField_lift(_ buf: RustBuffer) throws -> TantivyFuzzyField {
+    return try FfiConverterTypeTantivyFuzzyField.lift(buf)
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivyDatePrecision: FfiConverterRustBuffer {
-    typealias SwiftType = TantivyDatePrecision
+public func FfiConverterTypeTantivyFuzzyField_lower(_ value: TantivyFuzzyField) -> RustBuffer {
+    return FfiConverterTypeTantivyFuzzyField.lower(value)
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyDatePrecision {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .seconds
-        
-        case 2: return .milliseconds
-        
-        case 3: return .microseconds
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+
+/**
+ * A highlighted byte range, relative to the snippet fragment.
+ */
+public struct TantivyHighlightRange: Equatable, Hashable {
+    public var start: UInt64
+    public var end: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(start: UInt64, end: UInt64) {
+        self.start = start
+        self.end = end
     }
 
-    public static func write(_ value: TantivyDatePrecision, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .seconds:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .milliseconds:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .microseconds:
-            writeInt(&buf, Int32(3))
-        
-        }
+    
+}
+
+#if compiler(>=6)
+extension TantivyHighlightRange: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyHighlightRange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyHighlightRange {
+        return
+            try TantivyHighlightRange(
+                start: FfiConverterUInt64.read(from: &buf), 
+                end: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivyHighlightRange, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.start, into: &buf)
+        FfiConverterUInt64.write(value.end, into: &buf)
     }
 }
 
@@ -1946,372 +2855,4046 @@ public struct FfiConverterTypeTantivyDatePrecision: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyDatePrecision_lift(_ buf: RustBuffer) throws -> TantivyDatePrecision {
-    return try FfiConverterTypeTantivyDatePrecision.lift(buf)
+public func FfiConverterTypeTantivyHighlightRange_lift(_ buf: RustBuffer) throws -> TantivyHighlightRange {
+    return try FfiConverterTypeTantivyHighlightRange.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyDatePrecision_lower(_ value: TantivyDatePrecision) -> RustBuffer {
-    return FfiConverterTypeTantivyDatePrecision.lower(value)
+public func FfiConverterTypeTantivyHighlightRange_lower(_ value: TantivyHighlightRange) -> RustBuffer {
+    return FfiConverterTypeTantivyHighlightRange.lower(value)
 }
 
 
+public struct TantivyIdHit: Equatable, Hashable {
+    /**
+     * Text, u64 or i64 value of the ID field.
+     */
+    public var id: FieldValue
+    public var score: Float
+    public var sortValues: [FieldValue?]
 
-public enum TantivyIndexError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Text, u64 or i64 value of the ID field.
+         */id: FieldValue, score: Float, sortValues: [FieldValue?]) {
+        self.id = id
+        self.score = score
+        self.sortValues = sortValues
+    }
 
     
-    
-    case IoError(message: String)
-    
-    case OpenDirectoryError(message: String)
-    
-    case TantivyError(message: String)
-    
-    case SerializationError(message: String)
-    
-    case DocParsingError(message: String)
-    
-    case FacetParseError(message: String)
-    
-    case TryFromIntError(message: String)
-    
-    case WriterAcquisitionError(message: String)
-    
-    case DocRetrievalError(message: String)
-    
-    case SchemaBuilderError(message: String)
-    
-    case QueryError(message: String)
-    
+}
 
-    
+#if compiler(>=6)
+extension TantivyIdHit: Sendable {}
+#endif
 
-    
-    public var errorDescription: String? {
-        String(reflecting: self)
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyIdHit: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyIdHit {
+        return
+            try TantivyIdHit(
+                id: FfiConverterTypeFieldValue.read(from: &buf), 
+                score: FfiConverterFloat.read(from: &buf), 
+                sortValues: FfiConverterSequenceOptionTypeFieldValue.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivyIdHit, into buf: inout [UInt8]) {
+        FfiConverterTypeFieldValue.write(value.id, into: &buf)
+        FfiConverterFloat.write(value.score, into: &buf)
+        FfiConverterSequenceOptionTypeFieldValue.write(value.sortValues, into: &buf)
     }
-    
 }
 
-#if compiler(>=6)
-extension TantivyIndexError: Sendable {}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+public func FfiConverterTypeTantivyIdHit_lift(_ buf: RustBuffer) throws -> TantivyIdHit {
+    return try FfiConverterTypeTantivyIdHit.lift(buf)
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivyIndexError: FfiConverterRustBuffer {
-    typealias SwiftType = TantivyIndexError
+public func FfiConverterTypeTantivyIdHit_lower(_ value: TantivyIdHit) -> RustBuffer {
+    return FfiConverterTypeTantivyIdHit.lower(value)
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyIndexError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
 
-        
+public struct TantivyIdSearchResults: Equatable, Hashable {
+    public var count: UInt64
+    public var hits: [TantivyIdHit]
+    /**
+     * Opaque cursor to fetch the page after this one, `None` on the last page.
+     */
+    public var nextCursor: String?
 
-        
-        case 1: return .IoError(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 2: return .OpenDirectoryError(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 3: return .TantivyError(
-            message: try FfiConverterString.read(from: &buf)
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(count: UInt64, hits: [TantivyIdHit], 
+        /**
+         * Opaque cursor to fetch the page after this one, `None` on the last page.
+         */nextCursor: String?) {
+        self.count = count
+        self.hits = hits
+        self.nextCursor = nextCursor
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyIdSearchResults: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyIdSearchResults: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyIdSearchResults {
+        return
+            try TantivyIdSearchResults(
+                count: FfiConverterUInt64.read(from: &buf), 
+                hits: FfiConverterSequenceTypeTantivyIdHit.read(from: &buf), 
+                nextCursor: FfiConverterOptionString.read(from: &buf)
         )
-        
-        case 4: return .SerializationError(
-            message: try FfiConverterString.read(from: &buf)
+    }
+
+    public static func write(_ value: TantivyIdSearchResults, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.count, into: &buf)
+        FfiConverterSequenceTypeTantivyIdHit.write(value.hits, into: &buf)
+        FfiConverterOptionString.write(value.nextCursor, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyIdSearchResults_lift(_ buf: RustBuffer) throws -> TantivyIdSearchResults {
+    return try FfiConverterTypeTantivyIdSearchResults.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyIdSearchResults_lower(_ value: TantivyIdSearchResults) -> RustBuffer {
+    return FfiConverterTypeTantivyIdSearchResults.lower(value)
+}
+
+
+public struct TantivyIndexOptions: Equatable, Hashable {
+    /**
+     * Memory budget of the writer in bytes, shared by its indexing threads. Each
+     * thread needs at least 15 MB.
+     */
+    public var writerHeapBytes: UInt64
+    /**
+     * Indexing threads, picked from the number of cores when `None`. Zero threads
+     * fail with `InvalidIndexOptions`.
+     */
+    public var numThreads: UInt32?
+    /**
+     * Without a writer the index can only be searched, write methods fail with
+     * `ReadOnlyIndex`.
+     */
+    public var writable: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Memory budget of the writer in bytes, shared by its indexing threads. Each
+         * thread needs at least 15 MB.
+         */writerHeapBytes: UInt64 = UInt64(100000000), 
+        /**
+         * Indexing threads, picked from the number of cores when `None`. Zero threads
+         * fail with `InvalidIndexOptions`.
+         */numThreads: UInt32? = nil, 
+        /**
+         * Without a writer the index can only be searched, write methods fail with
+         * `ReadOnlyIndex`.
+         */writable: Bool = true) {
+        self.writerHeapBytes = writerHeapBytes
+        self.numThreads = numThreads
+        self.writable = writable
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyIndexOptions: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyIndexOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyIndexOptions {
+        return
+            try TantivyIndexOptions(
+                writerHeapBytes: FfiConverterUInt64.read(from: &buf), 
+                numThreads: FfiConverterOptionUInt32.read(from: &buf), 
+                writable: FfiConverterBool.read(from: &buf)
         )
-        
-        case 5: return .DocParsingError(
-            message: try FfiConverterString.read(from: &buf)
+    }
+
+    public static func write(_ value: TantivyIndexOptions, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.writerHeapBytes, into: &buf)
+        FfiConverterOptionUInt32.write(value.numThreads, into: &buf)
+        FfiConverterBool.write(value.writable, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyIndexOptions_lift(_ buf: RustBuffer) throws -> TantivyIndexOptions {
+    return try FfiConverterTypeTantivyIndexOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyIndexOptions_lower(_ value: TantivyIndexOptions) -> RustBuffer {
+    return FfiConverterTypeTantivyIndexOptions.lower(value)
+}
+
+
+public struct TantivyPercentile: Equatable, Hashable {
+    public var percent: Double
+    public var value: Double?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(percent: Double, value: Double?) {
+        self.percent = percent
+        self.value = value
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyPercentile: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyPercentile: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyPercentile {
+        return
+            try TantivyPercentile(
+                percent: FfiConverterDouble.read(from: &buf), 
+                value: FfiConverterOptionDouble.read(from: &buf)
         )
-        
-        case 6: return .FacetParseError(
-            message: try FfiConverterString.read(from: &buf)
+    }
+
+    public static func write(_ value: TantivyPercentile, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.percent, into: &buf)
+        FfiConverterOptionDouble.write(value.value, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyPercentile_lift(_ buf: RustBuffer) throws -> TantivyPercentile {
+    return try FfiConverterTypeTantivyPercentile.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyPercentile_lower(_ value: TantivyPercentile) -> RustBuffer {
+    return FfiConverterTypeTantivyPercentile.lower(value)
+}
+
+
+public struct TantivyQueryParseError: Equatable, Hashable {
+    public var kind: TantivyQueryErrorKind
+    public var message: String
+    /**
+     * Byte offset in the query string, only known for syntax errors.
+     */
+    public var position: UInt64?
+    public var field: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: TantivyQueryErrorKind, message: String, 
+        /**
+         * Byte offset in the query string, only known for syntax errors.
+         */position: UInt64?, field: String?) {
+        self.kind = kind
+        self.message = message
+        self.position = position
+        self.field = field
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyQueryParseError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyQueryParseError: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyQueryParseError {
+        return
+            try TantivyQueryParseError(
+                kind: FfiConverterTypeTantivyQueryErrorKind.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf), 
+                position: FfiConverterOptionUInt64.read(from: &buf), 
+                field: FfiConverterOptionString.read(from: &buf)
         )
-        
-        case 7: return .TryFromIntError(
-            message: try FfiConverterString.read(from: &buf)
+    }
+
+    public static func write(_ value: TantivyQueryParseError, into buf: inout [UInt8]) {
+        FfiConverterTypeTantivyQueryErrorKind.write(value.kind, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+        FfiConverterOptionUInt64.write(value.position, into: &buf)
+        FfiConverterOptionString.write(value.field, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyQueryParseError_lift(_ buf: RustBuffer) throws -> TantivyQueryParseError {
+    return try FfiConverterTypeTantivyQueryParseError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyQueryParseError_lower(_ value: TantivyQueryParseError) -> RustBuffer {
+    return FfiConverterTypeTantivyQueryParseError.lower(value)
+}
+
+
+public struct TantivyQueryValidation: Equatable, Hashable {
+    public var errors: [TantivyQueryParseError]
+    /**
+     * Debug representation of what the lenient parser makes of the query.
+     */
+    public var lenientQuery: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(errors: [TantivyQueryParseError], 
+        /**
+         * Debug representation of what the lenient parser makes of the query.
+         */lenientQuery: String) {
+        self.errors = errors
+        self.lenientQuery = lenientQuery
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyQueryValidation: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyQueryValidation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyQueryValidation {
+        return
+            try TantivyQueryValidation(
+                errors: FfiConverterSequenceTypeTantivyQueryParseError.read(from: &buf), 
+                lenientQuery: FfiConverterString.read(from: &buf)
         )
-        
-        case 8: return .WriterAcquisitionError(
-            message: try FfiConverterString.read(from: &buf)
+    }
+
+    public static func write(_ value: TantivyQueryValidation, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeTantivyQueryParseError.write(value.errors, into: &buf)
+        FfiConverterString.write(value.lenientQuery, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyQueryValidation_lift(_ buf: RustBuffer) throws -> TantivyQueryValidation {
+    return try FfiConverterTypeTantivyQueryValidation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyQueryValidation_lower(_ value: TantivyQueryValidation) -> RustBuffer {
+    return FfiConverterTypeTantivyQueryValidation.lower(value)
+}
+
+
+/**
+ * How a schema builder differs from the schema of an existing index.
+ */
+public struct TantivySchemaDiff: Equatable, Hashable {
+    /**
+     * Fields only in the schema builder.
+     */
+    public var added: [String]
+    /**
+     * Fields only in the index on disk.
+     */
+    public var removed: [String]
+    /**
+     * Fields in both with different options, or in a different order (`position`).
+     */
+    public var changed: [TantivyFieldChange]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Fields only in the schema builder.
+         */added: [String], 
+        /**
+         * Fields only in the index on disk.
+         */removed: [String], 
+        /**
+         * Fields in both with different options, or in a different order (`position`).
+         */changed: [TantivyFieldChange]) {
+        self.added = added
+        self.removed = removed
+        self.changed = changed
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySchemaDiff: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySchemaDiff: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySchemaDiff {
+        return
+            try TantivySchemaDiff(
+                added: FfiConverterSequenceString.read(from: &buf), 
+                removed: FfiConverterSequenceString.read(from: &buf), 
+                changed: FfiConverterSequenceTypeTantivyFieldChange.read(from: &buf)
         )
-        
-        case 9: return .DocRetrievalError(
-            message: try FfiConverterString.read(from: &buf)
+    }
+
+    public static func write(_ value: TantivySchemaDiff, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.added, into: &buf)
+        FfiConverterSequenceString.write(value.removed, into: &buf)
+        FfiConverterSequenceTypeTantivyFieldChange.write(value.changed, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySchemaDiff_lift(_ buf: RustBuffer) throws -> TantivySchemaDiff {
+    return try FfiConverterTypeTantivySchemaDiff.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySchemaDiff_lower(_ value: TantivySchemaDiff) -> RustBuffer {
+    return FfiConverterTypeTantivySchemaDiff.lower(value)
+}
+
+
+public struct TantivySearchHit: Equatable, Hashable {
+    public var docId: UInt64
+    public var score: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(docId: UInt64, score: Float) {
+        self.docId = docId
+        self.score = score
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySearchHit: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySearchHit: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySearchHit {
+        return
+            try TantivySearchHit(
+                docId: FfiConverterUInt64.read(from: &buf), 
+                score: FfiConverterFloat.read(from: &buf)
         )
-        
-        case 10: return .SchemaBuilderError(
-            message: try FfiConverterString.read(from: &buf)
+    }
+
+    public static func write(_ value: TantivySearchHit, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.docId, into: &buf)
+        FfiConverterFloat.write(value.score, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySearchHit_lift(_ buf: RustBuffer) throws -> TantivySearchHit {
+    return try FfiConverterTypeTantivySearchHit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySearchHit_lower(_ value: TantivySearchHit) -> RustBuffer {
+    return FfiConverterTypeTantivySearchHit.lower(value)
+}
+
+
+public struct TantivySearchQuery: Equatable, Hashable {
+    public var queryStr: String
+    public var defaultFields: [String]
+    public var fuzzyFields: [TantivyFuzzyField]
+    public var topDocLimit: UInt32
+    public var topDocOffset: UInt32
+    public var sort: [TantivySortField]
+    public var snippetFields: [TantivySnippetField]
+    /**
+     * `next_cursor` of a previous page, hits are returned from right after it.
+     */
+    public var cursor: String?
+    /**
+     * Fail with the parse errors instead of skipping the invalid parts of the query.
+     */
+    public var strict: Bool
+    /**
+     * Stored fields to return with each hit, all of them when `None`.
+     */
+    public var fields: TantivyFieldProjection?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(queryStr: String, defaultFields: [String], fuzzyFields: [TantivyFuzzyField], topDocLimit: UInt32, topDocOffset: UInt32, sort: [TantivySortField] = [], snippetFields: [TantivySnippetField] = [], 
+        /**
+         * `next_cursor` of a previous page, hits are returned from right after it.
+         */cursor: String? = nil, 
+        /**
+         * Fail with the parse errors instead of skipping the invalid parts of the query.
+         */strict: Bool = false, 
+        /**
+         * Stored fields to return with each hit, all of them when `None`.
+         */fields: TantivyFieldProjection? = nil) {
+        self.queryStr = queryStr
+        self.defaultFields = defaultFields
+        self.fuzzyFields = fuzzyFields
+        self.topDocLimit = topDocLimit
+        self.topDocOffset = topDocOffset
+        self.sort = sort
+        self.snippetFields = snippetFields
+        self.cursor = cursor
+        self.strict = strict
+        self.fields = fields
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySearchQuery: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySearchQuery: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySearchQuery {
+        return
+            try TantivySearchQuery(
+                queryStr: FfiConverterString.read(from: &buf), 
+                defaultFields: FfiConverterSequenceString.read(from: &buf), 
+                fuzzyFields: FfiConverterSequenceTypeTantivyFuzzyField.read(from: &buf), 
+                topDocLimit: FfiConverterUInt32.read(from: &buf), 
+                topDocOffset: FfiConverterUInt32.read(from: &buf), 
+                sort: FfiConverterSequenceTypeTantivySortField.read(from: &buf), 
+                snippetFields: FfiConverterSequenceTypeTantivySnippetField.read(from: &buf), 
+                cursor: FfiConverterOptionString.read(from: &buf), 
+                strict: FfiConverterBool.read(from: &buf), 
+                fields: FfiConverterOptionTypeTantivyFieldProjection.read(from: &buf)
         )
-        
-        case 11: return .QueryError(
-            message: try FfiConverterString.read(from: &buf)
+    }
+
+    public static func write(_ value: TantivySearchQuery, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.queryStr, into: &buf)
+        FfiConverterSequenceString.write(value.defaultFields, into: &buf)
+        FfiConverterSequenceTypeTantivyFuzzyField.write(value.fuzzyFields, into: &buf)
+        FfiConverterUInt32.write(value.topDocLimit, into: &buf)
+        FfiConverterUInt32.write(value.topDocOffset, into: &buf)
+        FfiConverterSequenceTypeTantivySortField.write(value.sort, into: &buf)
+        FfiConverterSequenceTypeTantivySnippetField.write(value.snippetFields, into: &buf)
+        FfiConverterOptionString.write(value.cursor, into: &buf)
+        FfiConverterBool.write(value.strict, into: &buf)
+        FfiConverterOptionTypeTantivyFieldProjection.write(value.fields, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySearchQuery_lift(_ buf: RustBuffer) throws -> TantivySearchQuery {
+    return try FfiConverterTypeTantivySearchQuery.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySearchQuery_lower(_ value: TantivySearchQuery) -> RustBuffer {
+    return FfiConverterTypeTantivySearchQuery.lower(value)
+}
+
+
+public struct TantivySearchResult: Equatable, Hashable {
+    public var score: Float
+    public var doc: TantivyDocumentFields
+    /**
+     * Values of the requested sort fields, `None` when the document has no value.
+     */
+    public var sortValues: [FieldValue?]
+    /**
+     * Highlighted snippets of the requested snippet fields that matched the query.
+     */
+    public var snippets: [TantivySnippet]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(score: Float, doc: TantivyDocumentFields, 
+        /**
+         * Values of the requested sort fields, `None` when the document has no value.
+         */sortValues: [FieldValue?], 
+        /**
+         * Highlighted snippets of the requested snippet fields that matched the query.
+         */snippets: [TantivySnippet]) {
+        self.score = score
+        self.doc = doc
+        self.sortValues = sortValues
+        self.snippets = snippets
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySearchResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySearchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySearchResult {
+        return
+            try TantivySearchResult(
+                score: FfiConverterFloat.read(from: &buf), 
+                doc: FfiConverterTypeTantivyDocumentFields.read(from: &buf), 
+                sortValues: FfiConverterSequenceOptionTypeFieldValue.read(from: &buf), 
+                snippets: FfiConverterSequenceTypeTantivySnippet.read(from: &buf)
         )
-        
+    }
+
+    public static func write(_ value: TantivySearchResult, into buf: inout [UInt8]) {
+        FfiConverterFloat.write(value.score, into: &buf)
+        FfiConverterTypeTantivyDocumentFields.write(value.doc, into: &buf)
+        FfiConverterSequenceOptionTypeFieldValue.write(value.sortValues, into: &buf)
+        FfiConverterSequenceTypeTantivySnippet.write(value.snippets, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySearchResult_lift(_ buf: RustBuffer) throws -> TantivySearchResult {
+    return try FfiConverterTypeTantivySearchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySearchResult_lower(_ value: TantivySearchResult) -> RustBuffer {
+    return FfiConverterTypeTantivySearchResult.lower(value)
+}
+
+
+public struct TantivySearchResults: Equatable, Hashable {
+    public var count: UInt64
+    public var docs: [TantivySearchResult]
+    /**
+     * Opaque cursor to fetch the page after this one, `None` on the last page.
+     */
+    public var nextCursor: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(count: UInt64, docs: [TantivySearchResult], 
+        /**
+         * Opaque cursor to fetch the page after this one, `None` on the last page.
+         */nextCursor: String?) {
+        self.count = count
+        self.docs = docs
+        self.nextCursor = nextCursor
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySearchResults: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySearchResults: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySearchResults {
+        return
+            try TantivySearchResults(
+                count: FfiConverterUInt64.read(from: &buf), 
+                docs: FfiConverterSequenceTypeTantivySearchResult.read(from: &buf), 
+                nextCursor: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivySearchResults, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.count, into: &buf)
+        FfiConverterSequenceTypeTantivySearchResult.write(value.docs, into: &buf)
+        FfiConverterOptionString.write(value.nextCursor, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySearchResults_lift(_ buf: RustBuffer) throws -> TantivySearchResults {
+    return try FfiConverterTypeTantivySearchResults.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySearchResults_lower(_ value: TantivySearchResults) -> RustBuffer {
+    return FfiConverterTypeTantivySearchResults.lower(value)
+}
+
+
+public struct TantivySegmentTermStats: Equatable, Hashable {
+    public var segmentId: String
+    public var docFreq: UInt64
+    public var totalTermFreq: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(segmentId: String, docFreq: UInt64, totalTermFreq: UInt64?) {
+        self.segmentId = segmentId
+        self.docFreq = docFreq
+        self.totalTermFreq = totalTermFreq
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySegmentTermStats: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySegmentTermStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySegmentTermStats {
+        return
+            try TantivySegmentTermStats(
+                segmentId: FfiConverterString.read(from: &buf), 
+                docFreq: FfiConverterUInt64.read(from: &buf), 
+                totalTermFreq: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivySegmentTermStats, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.segmentId, into: &buf)
+        FfiConverterUInt64.write(value.docFreq, into: &buf)
+        FfiConverterOptionUInt64.write(value.totalTermFreq, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySegmentTermStats_lift(_ buf: RustBuffer) throws -> TantivySegmentTermStats {
+    return try FfiConverterTypeTantivySegmentTermStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySegmentTermStats_lower(_ value: TantivySegmentTermStats) -> RustBuffer {
+    return FfiConverterTypeTantivySegmentTermStats.lower(value)
+}
+
+
+public struct TantivySnippet: Equatable, Hashable {
+    public var field: String
+    public var fragment: String
+    /**
+     * The fragment with HTML escaped and highlighted terms wrapped in `<b>` tags.
+     */
+    public var html: String
+    /**
+     * Sorted, non-overlapping byte ranges of the highlighted terms in `fragment`.
+     */
+    public var highlighted: [TantivyHighlightRange]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(field: String, fragment: String, 
+        /**
+         * The fragment with HTML escaped and highlighted terms wrapped in `<b>` tags.
+         */html: String, 
+        /**
+         * Sorted, non-overlapping byte ranges of the highlighted terms in `fragment`.
+         */highlighted: [TantivyHighlightRange]) {
+        self.field = field
+        self.fragment = fragment
+        self.html = html
+        self.highlighted = highlighted
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySnippet: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySnippet: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySnippet {
+        return
+            try TantivySnippet(
+                field: FfiConverterString.read(from: &buf), 
+                fragment: FfiConverterString.read(from: &buf), 
+                html: FfiConverterString.read(from: &buf), 
+                highlighted: FfiConverterSequenceTypeTantivyHighlightRange.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivySnippet, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.field, into: &buf)
+        FfiConverterString.write(value.fragment, into: &buf)
+        FfiConverterString.write(value.html, into: &buf)
+        FfiConverterSequenceTypeTantivyHighlightRange.write(value.highlighted, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySnippet_lift(_ buf: RustBuffer) throws -> TantivySnippet {
+    return try FfiConverterTypeTantivySnippet.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySnippet_lower(_ value: TantivySnippet) -> RustBuffer {
+    return FfiConverterTypeTantivySnippet.lower(value)
+}
+
+
+/**
+ * A text field to build a highlighted snippet for.
+ */
+public struct TantivySnippetField: Equatable, Hashable {
+    public var field: String
+    /**
+     * Maximum fragment length, defaults to 150.
+     */
+    public var maxNumChars: UInt32?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(field: String, 
+        /**
+         * Maximum fragment length, defaults to 150.
+         */maxNumChars: UInt32? = nil) {
+        self.field = field
+        self.maxNumChars = maxNumChars
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySnippetField: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySnippetField: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySnippetField {
+        return
+            try TantivySnippetField(
+                field: FfiConverterString.read(from: &buf), 
+                maxNumChars: FfiConverterOptionUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivySnippetField, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.field, into: &buf)
+        FfiConverterOptionUInt32.write(value.maxNumChars, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySnippetField_lift(_ buf: RustBuffer) throws -> TantivySnippetField {
+    return try FfiConverterTypeTantivySnippetField.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySnippetField_lower(_ value: TantivySnippetField) -> RustBuffer {
+    return FfiConverterTypeTantivySnippetField.lower(value)
+}
+
+
+/**
+ * One key of a multi-key sort. Keys are applied in order and the relevance score
+ * always breaks the remaining ties.
+ */
+public struct TantivySortField: Equatable, Hashable {
+    public var field: String
+    public var order: TantivySortOrder
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(field: String, order: TantivySortOrder) {
+        self.field = field
+        self.order = order
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySortField: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySortField: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySortField {
+        return
+            try TantivySortField(
+                field: FfiConverterString.read(from: &buf), 
+                order: FfiConverterTypeTantivySortOrder.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivySortField, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.field, into: &buf)
+        FfiConverterTypeTantivySortOrder.write(value.order, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySortField_lift(_ buf: RustBuffer) throws -> TantivySortField {
+    return try FfiConverterTypeTantivySortField.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySortField_lower(_ value: TantivySortField) -> RustBuffer {
+    return FfiConverterTypeTantivySortField.lower(value)
+}
+
+
+public struct TantivySpellSuggestion: Equatable, Hashable {
+    /**
+     * The analyzed tokens joined by spaces, each token that is not indexed
+     * replaced by its most frequent alternative.
+     */
+    public var corrected: String
+    public var tokens: [TantivySpellToken]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The analyzed tokens joined by spaces, each token that is not indexed
+         * replaced by its most frequent alternative.
+         */corrected: String, tokens: [TantivySpellToken]) {
+        self.corrected = corrected
+        self.tokens = tokens
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySpellSuggestion: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySpellSuggestion: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySpellSuggestion {
+        return
+            try TantivySpellSuggestion(
+                corrected: FfiConverterString.read(from: &buf), 
+                tokens: FfiConverterSequenceTypeTantivySpellToken.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivySpellSuggestion, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.corrected, into: &buf)
+        FfiConverterSequenceTypeTantivySpellToken.write(value.tokens, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySpellSuggestion_lift(_ buf: RustBuffer) throws -> TantivySpellSuggestion {
+    return try FfiConverterTypeTantivySpellSuggestion.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySpellSuggestion_lower(_ value: TantivySpellSuggestion) -> RustBuffer {
+    return FfiConverterTypeTantivySpellSuggestion.lower(value)
+}
+
+
+public struct TantivySpellToken: Equatable, Hashable {
+    /**
+     * The token as analyzed, e.g. lowercased.
+     */
+    public var token: String
+    /**
+     * Whether the token itself is an indexed term.
+     */
+    public var found: Bool
+    /**
+     * Other indexed terms within the edit distance, most frequent first.
+     */
+    public var alternatives: [TantivyTermSuggestion]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The token as analyzed, e.g. lowercased.
+         */token: String, 
+        /**
+         * Whether the token itself is an indexed term.
+         */found: Bool, 
+        /**
+         * Other indexed terms within the edit distance, most frequent first.
+         */alternatives: [TantivyTermSuggestion]) {
+        self.token = token
+        self.found = found
+        self.alternatives = alternatives
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivySpellToken: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySpellToken: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySpellToken {
+        return
+            try TantivySpellToken(
+                token: FfiConverterString.read(from: &buf), 
+                found: FfiConverterBool.read(from: &buf), 
+                alternatives: FfiConverterSequenceTypeTantivyTermSuggestion.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivySpellToken, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.token, into: &buf)
+        FfiConverterBool.write(value.found, into: &buf)
+        FfiConverterSequenceTypeTantivyTermSuggestion.write(value.alternatives, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySpellToken_lift(_ buf: RustBuffer) throws -> TantivySpellToken {
+    return try FfiConverterTypeTantivySpellToken.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySpellToken_lower(_ value: TantivySpellToken) -> RustBuffer {
+    return FfiConverterTypeTantivySpellToken.lower(value)
+}
+
+
+/**
+ * Counts of an indexed term. Deleted documents still count until their segment
+ * is merged.
+ */
+public struct TantivyTermFrequency: Equatable, Hashable {
+    public var term: String
+    public var docFreq: UInt64
+    /**
+     * Occurrences over all documents, `None` when the field is indexed without
+     * frequencies.
+     */
+    public var totalTermFreq: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(term: String, docFreq: UInt64, 
+        /**
+         * Occurrences over all documents, `None` when the field is indexed without
+         * frequencies.
+         */totalTermFreq: UInt64?) {
+        self.term = term
+        self.docFreq = docFreq
+        self.totalTermFreq = totalTermFreq
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyTermFrequency: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyTermFrequency: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyTermFrequency {
+        return
+            try TantivyTermFrequency(
+                term: FfiConverterString.read(from: &buf), 
+                docFreq: FfiConverterUInt64.read(from: &buf), 
+                totalTermFreq: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivyTermFrequency, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.term, into: &buf)
+        FfiConverterUInt64.write(value.docFreq, into: &buf)
+        FfiConverterOptionUInt64.write(value.totalTermFreq, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTermFrequency_lift(_ buf: RustBuffer) throws -> TantivyTermFrequency {
+    return try FfiConverterTypeTantivyTermFrequency.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTermFrequency_lower(_ value: TantivyTermFrequency) -> RustBuffer {
+    return FfiConverterTypeTantivyTermFrequency.lower(value)
+}
+
+
+public struct TantivyTermPage: Equatable, Hashable {
+    public var terms: [TantivyTermFrequency]
+    /**
+     * Pass back as `cursor` to list the following terms, `nil` on the last page.
+     */
+    public var nextCursor: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(terms: [TantivyTermFrequency], 
+        /**
+         * Pass back as `cursor` to list the following terms, `nil` on the last page.
+         */nextCursor: String?) {
+        self.terms = terms
+        self.nextCursor = nextCursor
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyTermPage: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyTermPage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyTermPage {
+        return
+            try TantivyTermPage(
+                terms: FfiConverterSequenceTypeTantivyTermFrequency.read(from: &buf), 
+                nextCursor: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivyTermPage, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeTantivyTermFrequency.write(value.terms, into: &buf)
+        FfiConverterOptionString.write(value.nextCursor, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTermPage_lift(_ buf: RustBuffer) throws -> TantivyTermPage {
+    return try FfiConverterTypeTantivyTermPage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTermPage_lower(_ value: TantivyTermPage) -> RustBuffer {
+    return FfiConverterTypeTantivyTermPage.lower(value)
+}
+
+
+public struct TantivyTermStats: Equatable, Hashable {
+    public var term: String
+    public var docFreq: UInt64
+    public var totalTermFreq: UInt64?
+    /**
+     * Only the segments containing the term.
+     */
+    public var segments: [TantivySegmentTermStats]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(term: String, docFreq: UInt64, totalTermFreq: UInt64?, 
+        /**
+         * Only the segments containing the term.
+         */segments: [TantivySegmentTermStats]) {
+        self.term = term
+        self.docFreq = docFreq
+        self.totalTermFreq = totalTermFreq
+        self.segments = segments
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyTermStats: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyTermStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyTermStats {
+        return
+            try TantivyTermStats(
+                term: FfiConverterString.read(from: &buf), 
+                docFreq: FfiConverterUInt64.read(from: &buf), 
+                totalTermFreq: FfiConverterOptionUInt64.read(from: &buf), 
+                segments: FfiConverterSequenceTypeTantivySegmentTermStats.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivyTermStats, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.term, into: &buf)
+        FfiConverterUInt64.write(value.docFreq, into: &buf)
+        FfiConverterOptionUInt64.write(value.totalTermFreq, into: &buf)
+        FfiConverterSequenceTypeTantivySegmentTermStats.write(value.segments, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTermStats_lift(_ buf: RustBuffer) throws -> TantivyTermStats {
+    return try FfiConverterTypeTantivyTermStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTermStats_lower(_ value: TantivyTermStats) -> RustBuffer {
+    return FfiConverterTypeTantivyTermStats.lower(value)
+}
+
+
+public struct TantivyTermSuggestion: Equatable, Hashable {
+    public var term: String
+    /**
+     * Documents containing the term, summed over segments. Deleted documents
+     * still count until their segment is merged.
+     */
+    public var docFreq: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(term: String, 
+        /**
+         * Documents containing the term, summed over segments. Deleted documents
+         * still count until their segment is merged.
+         */docFreq: UInt64) {
+        self.term = term
+        self.docFreq = docFreq
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyTermSuggestion: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyTermSuggestion: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyTermSuggestion {
+        return
+            try TantivyTermSuggestion(
+                term: FfiConverterString.read(from: &buf), 
+                docFreq: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivyTermSuggestion, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.term, into: &buf)
+        FfiConverterUInt64.write(value.docFreq, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTermSuggestion_lift(_ buf: RustBuffer) throws -> TantivyTermSuggestion {
+    return try FfiConverterTypeTantivyTermSuggestion.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTermSuggestion_lower(_ value: TantivyTermSuggestion) -> RustBuffer {
+    return FfiConverterTypeTantivyTermSuggestion.lower(value)
+}
+
+
+/**
+ * A token as produced by an analyzer. Offsets point into the analyzed text, in
+ * UTF-8 bytes for Rust and UTF-16 code units for `NSString`/`String.UTF16View`.
+ */
+public struct TantivyToken: Equatable, Hashable {
+    public var text: String
+    public var position: UInt64
+    public var byteStart: UInt64
+    public var byteEnd: UInt64
+    public var utf16Start: UInt64
+    public var utf16End: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(text: String, position: UInt64, byteStart: UInt64, byteEnd: UInt64, utf16Start: UInt64, utf16End: UInt64) {
+        self.text = text
+        self.position = position
+        self.byteStart = byteStart
+        self.byteEnd = byteEnd
+        self.utf16Start = utf16Start
+        self.utf16End = utf16End
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TantivyToken: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyToken: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyToken {
+        return
+            try TantivyToken(
+                text: FfiConverterString.read(from: &buf), 
+                position: FfiConverterUInt64.read(from: &buf), 
+                byteStart: FfiConverterUInt64.read(from: &buf), 
+                byteEnd: FfiConverterUInt64.read(from: &buf), 
+                utf16Start: FfiConverterUInt64.read(from: &buf), 
+                utf16End: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TantivyToken, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterUInt64.write(value.position, into: &buf)
+        FfiConverterUInt64.write(value.byteStart, into: &buf)
+        FfiConverterUInt64.write(value.byteEnd, into: &buf)
+        FfiConverterUInt64.write(value.utf16Start, into: &buf)
+        FfiConverterUInt64.write(value.utf16End, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyToken_lift(_ buf: RustBuffer) throws -> TantivyToken {
+    return try FfiConverterTypeTantivyToken.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyToken_lower(_ value: TantivyToken) -> RustBuffer {
+    return FfiConverterTypeTantivyToken.lower(value)
+}
+
+
+public struct TextFieldOptions: Equatable, Hashable {
+    public var tokenizer: TantivyTokenizer
+    public var record: TantivyIndexRecordOption
+    public var stored: Bool
+    public var fast: Bool
+    public var fieldnorms: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(tokenizer: TantivyTokenizer, record: TantivyIndexRecordOption, stored: Bool, fast: Bool, fieldnorms: Bool) {
+        self.tokenizer = tokenizer
+        self.record = record
+        self.stored = stored
+        self.fast = fast
+        self.fieldnorms = fieldnorms
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension TextFieldOptions: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTextFieldOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextFieldOptions {
+        return
+            try TextFieldOptions(
+                tokenizer: FfiConverterTypeTantivyTokenizer.read(from: &buf), 
+                record: FfiConverterTypeTantivyIndexRecordOption.read(from: &buf), 
+                stored: FfiConverterBool.read(from: &buf), 
+                fast: FfiConverterBool.read(from: &buf), 
+                fieldnorms: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TextFieldOptions, into buf: inout [UInt8]) {
+        FfiConverterTypeTantivyTokenizer.write(value.tokenizer, into: &buf)
+        FfiConverterTypeTantivyIndexRecordOption.write(value.record, into: &buf)
+        FfiConverterBool.write(value.stored, into: &buf)
+        FfiConverterBool.write(value.fast, into: &buf)
+        FfiConverterBool.write(value.fieldnorms, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextFieldOptions_lift(_ buf: RustBuffer) throws -> TextFieldOptions {
+    return try FfiConverterTypeTextFieldOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextFieldOptions_lower(_ value: TextFieldOptions) -> RustBuffer {
+    return FfiConverterTypeTextFieldOptions.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A field value passed from Swift - no JSON serialization needed
+ */
+
+public enum FieldValue: Equatable, Hashable {
+    
+    case text(String
+    )
+    case u64(UInt64
+    )
+    case i64(Int64
+    )
+    case f64(Double
+    )
+    case bool(Bool
+    )
+    /**
+     * Unix timestamp in microseconds
+     */
+    case date(Int64
+    )
+    case bytes(Data
+    )
+    case facet(String
+    )
+    case json(String
+    )
+    /**
+     * IPv4 or IPv6 address in its textual form
+     */
+    case ipAddr(String
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension FieldValue: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFieldValue: FfiConverterRustBuffer {
+    typealias SwiftType = FieldValue
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FieldValue {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .text(try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .u64(try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        case 3: return .i64(try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 4: return .f64(try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 5: return .bool(try FfiConverterBool.read(from: &buf)
+        )
+        
+        case 6: return .date(try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 7: return .bytes(try FfiConverterData.read(from: &buf)
+        )
+        
+        case 8: return .facet(try FfiConverterString.read(from: &buf)
+        )
+        
+        case 9: return .json(try FfiConverterString.read(from: &buf)
+        )
+        
+        case 10: return .ipAddr(try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FieldValue, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .text(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .u64(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterUInt64.write(v1, into: &buf)
+            
+        
+        case let .i64(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterInt64.write(v1, into: &buf)
+            
+        
+        case let .f64(v1):
+            writeInt(&buf, Int32(4))
+            FfiConverterDouble.write(v1, into: &buf)
+            
+        
+        case let .bool(v1):
+            writeInt(&buf, Int32(5))
+            FfiConverterBool.write(v1, into: &buf)
+            
+        
+        case let .date(v1):
+            writeInt(&buf, Int32(6))
+            FfiConverterInt64.write(v1, into: &buf)
+            
+        
+        case let .bytes(v1):
+            writeInt(&buf, Int32(7))
+            FfiConverterData.write(v1, into: &buf)
+            
+        
+        case let .facet(v1):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .json(v1):
+            writeInt(&buf, Int32(9))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .ipAddr(v1):
+            writeInt(&buf, Int32(10))
+            FfiConverterString.write(v1, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldValue_lift(_ buf: RustBuffer) throws -> FieldValue {
+    return try FfiConverterTypeFieldValue.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldValue_lower(_ value: FieldValue) -> RustBuffer {
+    return FfiConverterTypeFieldValue.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivyAggregationKey: Equatable, Hashable {
+    
+    case str(String
+    )
+    case i64(Int64
+    )
+    case u64(UInt64
+    )
+    case f64(Double
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyAggregationKey: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyAggregationKey: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyAggregationKey
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyAggregationKey {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .str(try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .i64(try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 3: return .u64(try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        case 4: return .f64(try FfiConverterDouble.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyAggregationKey, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .str(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .i64(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterInt64.write(v1, into: &buf)
+            
+        
+        case let .u64(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterUInt64.write(v1, into: &buf)
+            
+        
+        case let .f64(v1):
+            writeInt(&buf, Int32(4))
+            FfiConverterDouble.write(v1, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyAggregationKey_lift(_ buf: RustBuffer) throws -> TantivyAggregationKey {
+    return try FfiConverterTypeTantivyAggregationKey.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyAggregationKey_lower(_ value: TantivyAggregationKey) -> RustBuffer {
+    return FfiConverterTypeTantivyAggregationKey.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivyAggregationValue: Equatable, Hashable {
+    
+    case buckets(buckets: [TantivyAggregationBucket], 
+        /**
+         * Only set for terms aggregations.
+         */sumOtherDocCount: UInt64?, docCountErrorUpperBound: UInt64?
+    )
+    /**
+     * Result of avg, min, max, sum, count and cardinality aggregations.
+     */
+    case single(value: Double?
+    )
+    case stats(count: UInt64, sum: Double, min: Double?, max: Double?, avg: Double?
+    )
+    case percentiles(values: [TantivyPercentile]
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyAggregationValue: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyAggregationValue: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyAggregationValue
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyAggregationValue {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .buckets(buckets: try FfiConverterSequenceTypeTantivyAggregationBucket.read(from: &buf), sumOtherDocCount: try FfiConverterOptionUInt64.read(from: &buf), docCountErrorUpperBound: try FfiConverterOptionUInt64.read(from: &buf)
+        )
+        
+        case 2: return .single(value: try FfiConverterOptionDouble.read(from: &buf)
+        )
+        
+        case 3: return .stats(count: try FfiConverterUInt64.read(from: &buf), sum: try FfiConverterDouble.read(from: &buf), min: try FfiConverterOptionDouble.read(from: &buf), max: try FfiConverterOptionDouble.read(from: &buf), avg: try FfiConverterOptionDouble.read(from: &buf)
+        )
+        
+        case 4: return .percentiles(values: try FfiConverterSequenceTypeTantivyPercentile.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyAggregationValue, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .buckets(buckets,sumOtherDocCount,docCountErrorUpperBound):
+            writeInt(&buf, Int32(1))
+            FfiConverterSequenceTypeTantivyAggregationBucket.write(buckets, into: &buf)
+            FfiConverterOptionUInt64.write(sumOtherDocCount, into: &buf)
+            FfiConverterOptionUInt64.write(docCountErrorUpperBound, into: &buf)
+            
+        
+        case let .single(value):
+            writeInt(&buf, Int32(2))
+            FfiConverterOptionDouble.write(value, into: &buf)
+            
+        
+        case let .stats(count,sum,min,max,avg):
+            writeInt(&buf, Int32(3))
+            FfiConverterUInt64.write(count, into: &buf)
+            FfiConverterDouble.write(sum, into: &buf)
+            FfiConverterOptionDouble.write(min, into: &buf)
+            FfiConverterOptionDouble.write(max, into: &buf)
+            FfiConverterOptionDouble.write(avg, into: &buf)
+            
+        
+        case let .percentiles(values):
+            writeInt(&buf, Int32(4))
+            FfiConverterSequenceTypeTantivyPercentile.write(values, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyAggregationValue_lift(_ buf: RustBuffer) throws -> TantivyAggregationValue {
+    return try FfiConverterTypeTantivyAggregationValue.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyAggregationValue_lower(_ value: TantivyAggregationValue) -> RustBuffer {
+    return FfiConverterTypeTantivyAggregationValue.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivyBaseTokenizer: Equatable, Hashable {
+    
+    /**
+     * Unicode word boundaries, the tokenizer behind the `unicode` analyzer.
+     */
+    case unicode
+    /**
+     * Splits on anything that is not alphanumeric.
+     */
+    case simple
+    case whitespace
+    /**
+     * The whole text as a single token.
+     */
+    case raw
+    /**
+     * Every n-gram of `min_gram..=max_gram` characters, or only the prefixes.
+     */
+    case ngram(minGram: UInt32, maxGram: UInt32, prefixOnly: Bool
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyBaseTokenizer: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyBaseTokenizer: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyBaseTokenizer
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyBaseTokenizer {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .unicode
+        
+        case 2: return .simple
+        
+        case 3: return .whitespace
+        
+        case 4: return .raw
+        
+        case 5: return .ngram(minGram: try FfiConverterUInt32.read(from: &buf), maxGram: try FfiConverterUInt32.read(from: &buf), prefixOnly: try FfiConverterBool.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyBaseTokenizer, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .unicode:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .simple:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .whitespace:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .raw:
+            writeInt(&buf, Int32(4))
+        
+        
+        case let .ngram(minGram,maxGram,prefixOnly):
+            writeInt(&buf, Int32(5))
+            FfiConverterUInt32.write(minGram, into: &buf)
+            FfiConverterUInt32.write(maxGram, into: &buf)
+            FfiConverterBool.write(prefixOnly, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyBaseTokenizer_lift(_ buf: RustBuffer) throws -> TantivyBaseTokenizer {
+    return try FfiConverterTypeTantivyBaseTokenizer.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyBaseTokenizer_lower(_ value: TantivyBaseTokenizer) -> RustBuffer {
+    return FfiConverterTypeTantivyBaseTokenizer.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivyDatePrecision: Equatable, Hashable {
+    
+    case seconds
+    case milliseconds
+    case microseconds
+    case nanoseconds
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyDatePrecision: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyDatePrecision: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyDatePrecision
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyDatePrecision {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .seconds
+        
+        case 2: return .milliseconds
+        
+        case 3: return .microseconds
+        
+        case 4: return .nanoseconds
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyDatePrecision, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .seconds:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .milliseconds:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .microseconds:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .nanoseconds:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyDatePrecision_lift(_ buf: RustBuffer) throws -> TantivyDatePrecision {
+    return try FfiConverterTypeTantivyDatePrecision.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyDatePrecision_lower(_ value: TantivyDatePrecision) -> RustBuffer {
+    return FfiConverterTypeTantivyDatePrecision.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How a field of the old schema carries over to the new one. Fields without a
+ * mapping keep their name.
+ */
+
+public enum TantivyFieldMapping: Equatable, Hashable {
+    
+    /**
+     * Moves the values of `from` to the field `to` of the new schema.
+     */
+    case rename(from: String, to: String
+    )
+    /**
+     * Leaves the values of `field` out of the new index.
+     */
+    case drop(field: String
+    )
+    /**
+     * Sets `field` of the new schema on documents that have no value for it.
+     */
+    case `default`(field: String, value: FieldValue
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyFieldMapping: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyFieldMapping: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyFieldMapping
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyFieldMapping {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .rename(from: try FfiConverterString.read(from: &buf), to: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .drop(field: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .`default`(field: try FfiConverterString.read(from: &buf), value: try FfiConverterTypeFieldValue.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyFieldMapping, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .rename(from,to):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(from, into: &buf)
+            FfiConverterString.write(to, into: &buf)
+            
+        
+        case let .drop(field):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(field, into: &buf)
+            
+        
+        case let .`default`(field,value):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(field, into: &buf)
+            FfiConverterTypeFieldValue.write(value, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyFieldMapping_lift(_ buf: RustBuffer) throws -> TantivyFieldMapping {
+    return try FfiConverterTypeTantivyFieldMapping.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyFieldMapping_lower(_ value: TantivyFieldMapping) -> RustBuffer {
+    return FfiConverterTypeTantivyFieldMapping.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Which stored fields to return with each document.
+ */
+
+public enum TantivyFieldProjection: Equatable, Hashable {
+    
+    case all
+    /**
+     * Only these stored fields; an empty list returns no stored fields at all.
+     */
+    case include(fields: [String]
+    )
+    case exclude(fields: [String]
+    )
+    /**
+     * Only the ID field, read from its fast column when it has one.
+     */
+    case idOnly(idField: String
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyFieldProjection: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyFieldProjection: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyFieldProjection
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyFieldProjection {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .all
+        
+        case 2: return .include(fields: try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 3: return .exclude(fields: try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 4: return .idOnly(idField: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyFieldProjection, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .all:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .include(fields):
+            writeInt(&buf, Int32(2))
+            FfiConverterSequenceString.write(fields, into: &buf)
+            
+        
+        case let .exclude(fields):
+            writeInt(&buf, Int32(3))
+            FfiConverterSequenceString.write(fields, into: &buf)
+            
+        
+        case let .idOnly(idField):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(idField, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyFieldProjection_lift(_ buf: RustBuffer) throws -> TantivyFieldProjection {
+    return try FfiConverterTypeTantivyFieldProjection.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyFieldProjection_lower(_ value: TantivyFieldProjection) -> RustBuffer {
+    return FfiConverterTypeTantivyFieldProjection.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivyFieldType: Equatable, Hashable {
+    
+    case text
+    case u64
+    case i64
+    case f64
+    case bool
+    case date
+    case facet
+    case bytes
+    case json
+    case ipAddr
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyFieldType: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyFieldType: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyFieldType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyFieldType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .text
+        
+        case 2: return .u64
+        
+        case 3: return .i64
+        
+        case 4: return .f64
+        
+        case 5: return .bool
+        
+        case 6: return .date
+        
+        case 7: return .facet
+        
+        case 8: return .bytes
+        
+        case 9: return .json
+        
+        case 10: return .ipAddr
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyFieldType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .text:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .u64:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .i64:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .f64:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .bool:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .date:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .facet:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .bytes:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .json:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .ipAddr:
+            writeInt(&buf, Int32(10))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyFieldType_lift(_ buf: RustBuffer) throws -> TantivyFieldType {
+    return try FfiConverterTypeTantivyFieldType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyFieldType_lower(_ value: TantivyFieldType) -> RustBuffer {
+    return FfiConverterTypeTantivyFieldType.lower(value)
+}
+
+
+
+public enum TantivyIndexError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+
+    
+    
+    case IoError(message: String)
+    
+    case OpenDirectoryError(message: String)
+    
+    case TantivyError(message: String)
+    
+    case SerializationError(message: String)
+    
+    case DocParsingError(message: String)
+    
+    case FacetParseError(message: String)
+    
+    case IpAddrParseError(message: String)
+    
+    case TryFromIntError(message: String)
+    
+    case WriterAcquisitionError(message: String)
+    
+    case SearcherAcquisitionError(message: String)
+    
+    case ReadOnlyIndex(message: String)
+    
+    case InvalidIndexOptions(message: String)
+    
+    case DocRetrievalError(message: String)
+    
+    case MissingIdField(message: String)
+    
+    case SchemaBuilderError(message: String)
+    
+    case SchemaMismatch(message: String)
+    
+    case MigrationError(message: String)
+    
+    case QueryError(message: String)
+    
+    case QueryParseError(message: String)
+    
+
+    
+
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
+}
+
+#if compiler(>=6)
+extension TantivyIndexError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyIndexError: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyIndexError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyIndexError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .IoError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .OpenDirectoryError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .TantivyError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .SerializationError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 5: return .DocParsingError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 6: return .FacetParseError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 7: return .IpAddrParseError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 8: return .TryFromIntError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 9: return .WriterAcquisitionError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 10: return .SearcherAcquisitionError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 11: return .ReadOnlyIndex(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 12: return .InvalidIndexOptions(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 13: return .DocRetrievalError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 14: return .MissingIdField(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 15: return .SchemaBuilderError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 16: return .SchemaMismatch(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 17: return .MigrationError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 18: return .QueryError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 19: return .QueryParseError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyIndexError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        case .IoError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(1))
+        case .OpenDirectoryError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(2))
+        case .TantivyError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(3))
+        case .SerializationError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(4))
+        case .DocParsingError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(5))
+        case .FacetParseError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(6))
+        case .IpAddrParseError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(7))
+        case .TryFromIntError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(8))
+        case .WriterAcquisitionError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(9))
+        case .SearcherAcquisitionError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(10))
+        case .ReadOnlyIndex(_ /* message is ignored*/):
+            writeInt(&buf, Int32(11))
+        case .InvalidIndexOptions(_ /* message is ignored*/):
+            writeInt(&buf, Int32(12))
+        case .DocRetrievalError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(13))
+        case .MissingIdField(_ /* message is ignored*/):
+            writeInt(&buf, Int32(14))
+        case .SchemaBuilderError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(15))
+        case .SchemaMismatch(_ /* message is ignored*/):
+            writeInt(&buf, Int32(16))
+        case .MigrationError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(17))
+        case .QueryError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(18))
+        case .QueryParseError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(19))
+
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyIndexError_lift(_ buf: RustBuffer) throws -> TantivyIndexError {
+    return try FfiConverterTypeTantivyIndexError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyIndexError_lower(_ value: TantivyIndexError) -> RustBuffer {
+    return FfiConverterTypeTantivyIndexError.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivyIndexRecordOption: Equatable, Hashable {
+    
+    case basic
+    case withFreqs
+    case withFreqsAndPositions
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyIndexRecordOption: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyIndexRecordOption: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyIndexRecordOption
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyIndexRecordOption {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .basic
+        
+        case 2: return .withFreqs
+        
+        case 3: return .withFreqsAndPositions
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyIndexRecordOption, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .basic:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .withFreqs:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .withFreqsAndPositions:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyIndexRecordOption_lift(_ buf: RustBuffer) throws -> TantivyIndexRecordOption {
+    return try FfiConverterTypeTantivyIndexRecordOption.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyIndexRecordOption_lower(_ value: TantivyIndexRecordOption) -> RustBuffer {
+    return FfiConverterTypeTantivyIndexRecordOption.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivyLanguage: Equatable, Hashable {
+    
+    case arabic
+    case danish
+    case dutch
+    case english
+    case finnish
+    case french
+    case german
+    case greek
+    case hungarian
+    case italian
+    case norwegian
+    case portuguese
+    case romanian
+    case russian
+    case spanish
+    case swedish
+    case tamil
+    case turkish
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyLanguage: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyLanguage: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyLanguage
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyLanguage {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .arabic
+        
+        case 2: return .danish
+        
+        case 3: return .dutch
+        
+        case 4: return .english
+        
+        case 5: return .finnish
+        
+        case 6: return .french
+        
+        case 7: return .german
+        
+        case 8: return .greek
+        
+        case 9: return .hungarian
+        
+        case 10: return .italian
+        
+        case 11: return .norwegian
+        
+        case 12: return .portuguese
+        
+        case 13: return .romanian
+        
+        case 14: return .russian
+        
+        case 15: return .spanish
+        
+        case 16: return .swedish
+        
+        case 17: return .tamil
+        
+        case 18: return .turkish
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyLanguage, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .arabic:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .danish:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .dutch:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .english:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .finnish:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .french:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .german:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .greek:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .hungarian:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .italian:
+            writeInt(&buf, Int32(10))
+        
+        
+        case .norwegian:
+            writeInt(&buf, Int32(11))
+        
+        
+        case .portuguese:
+            writeInt(&buf, Int32(12))
+        
+        
+        case .romanian:
+            writeInt(&buf, Int32(13))
+        
+        
+        case .russian:
+            writeInt(&buf, Int32(14))
+        
+        
+        case .spanish:
+            writeInt(&buf, Int32(15))
+        
+        
+        case .swedish:
+            writeInt(&buf, Int32(16))
+        
+        
+        case .tamil:
+            writeInt(&buf, Int32(17))
+        
+        
+        case .turkish:
+            writeInt(&buf, Int32(18))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyLanguage_lift(_ buf: RustBuffer) throws -> TantivyLanguage {
+    return try FfiConverterTypeTantivyLanguage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyLanguage_lower(_ value: TantivyLanguage) -> RustBuffer {
+    return FfiConverterTypeTantivyLanguage.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivyQueryErrorKind: Equatable, Hashable {
+    
+    case syntax
+    case unknownField
+    case fieldNotIndexed
+    /**
+     * Phrase queries need a field indexed with positions.
+     */
+    case missingPositions
+    /**
+     * A value could not be parsed for the field type, e.g. `count:abc`.
+     */
+    case invalidValue
+    case unsupported
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyQueryErrorKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyQueryErrorKind: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyQueryErrorKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyQueryErrorKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .syntax
+        
+        case 2: return .unknownField
+        
+        case 3: return .fieldNotIndexed
+        
+        case 4: return .missingPositions
+        
+        case 5: return .invalidValue
+        
+        case 6: return .unsupported
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyQueryErrorKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .syntax:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .unknownField:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .fieldNotIndexed:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .missingPositions:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .invalidValue:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .unsupported:
+            writeInt(&buf, Int32(6))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyQueryErrorKind_lift(_ buf: RustBuffer) throws -> TantivyQueryErrorKind {
+    return try FfiConverterTypeTantivyQueryErrorKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyQueryErrorKind_lower(_ value: TantivyQueryErrorKind) -> RustBuffer {
+    return FfiConverterTypeTantivyQueryErrorKind.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivySortOrder: Equatable, Hashable {
+    
+    case asc
+    case desc
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivySortOrder: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivySortOrder: FfiConverterRustBuffer {
+    typealias SwiftType = TantivySortOrder
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivySortOrder {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .asc
+        
+        case 2: return .desc
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivySortOrder, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .asc:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .desc:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySortOrder_lift(_ buf: RustBuffer) throws -> TantivySortOrder {
+    return try FfiConverterTypeTantivySortOrder.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivySortOrder_lower(_ value: TantivySortOrder) -> RustBuffer {
+    return FfiConverterTypeTantivySortOrder.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Token filters, applied in the order they are added.
+ */
+
+public enum TantivyTokenFilter: Equatable, Hashable {
+    
+    case lowercase
+    case asciiFolding
+    case stemmer(language: TantivyLanguage
+    )
+    /**
+     * Built-in stop word list, not every stemmer language has one.
+     */
+    case stopWords(language: TantivyLanguage
+    )
+    case customStopWords(words: [String]
+    )
+    /**
+     * Drops tokens of `max_bytes` UTF-8 bytes or more.
+     */
+    case removeLong(maxBytes: UInt32
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyTokenFilter: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyTokenFilter: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyTokenFilter
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyTokenFilter {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .lowercase
+        
+        case 2: return .asciiFolding
+        
+        case 3: return .stemmer(language: try FfiConverterTypeTantivyLanguage.read(from: &buf)
+        )
+        
+        case 4: return .stopWords(language: try FfiConverterTypeTantivyLanguage.read(from: &buf)
+        )
+        
+        case 5: return .customStopWords(words: try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 6: return .removeLong(maxBytes: try FfiConverterUInt32.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyTokenFilter, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .lowercase:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .asciiFolding:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .stemmer(language):
+            writeInt(&buf, Int32(3))
+            FfiConverterTypeTantivyLanguage.write(language, into: &buf)
+            
+        
+        case let .stopWords(language):
+            writeInt(&buf, Int32(4))
+            FfiConverterTypeTantivyLanguage.write(language, into: &buf)
+            
+        
+        case let .customStopWords(words):
+            writeInt(&buf, Int32(5))
+            FfiConverterSequenceString.write(words, into: &buf)
+            
+        
+        case let .removeLong(maxBytes):
+            writeInt(&buf, Int32(6))
+            FfiConverterUInt32.write(maxBytes, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTokenFilter_lift(_ buf: RustBuffer) throws -> TantivyTokenFilter {
+    return try FfiConverterTypeTantivyTokenFilter.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTokenFilter_lower(_ value: TantivyTokenFilter) -> RustBuffer {
+    return FfiConverterTypeTantivyTokenFilter.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TantivyTokenizer: Equatable, Hashable {
+    
+    case raw
+    case `default`
+    case unicode
+    case enStem
+    case whitespace
+    /**
+     * An analyzer added to the schema builder with `add_analyzer`.
+     */
+    case custom(name: String
+    )
+    /**
+     * Lowercased, ASCII-folded n-grams of `min_gram..=max_gram` characters, or only
+     * the prefixes of the text (edge n-grams) with `prefix_only`.
+     */
+    case ngram(minGram: UInt32, maxGram: UInt32, prefixOnly: Bool
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension TantivyTokenizer: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTantivyTokenizer: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyTokenizer
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyTokenizer {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .raw
+        
+        case 2: return .`default`
+        
+        case 3: return .unicode
+        
+        case 4: return .enStem
+        
+        case 5: return .whitespace
+        
+        case 6: return .custom(name: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 7: return .ngram(minGram: try FfiConverterUInt32.read(from: &buf), maxGram: try FfiConverterUInt32.read(from: &buf), prefixOnly: try FfiConverterBool.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TantivyTokenizer, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .raw:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .`default`:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .unicode:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .enStem:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .whitespace:
+            writeInt(&buf, Int32(5))
+        
+        
+        case let .custom(name):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(name, into: &buf)
+            
+        
+        case let .ngram(minGram,maxGram,prefixOnly):
+            writeInt(&buf, Int32(7))
+            FfiConverterUInt32.write(minGram, into: &buf)
+            FfiConverterUInt32.write(maxGram, into: &buf)
+            FfiConverterBool.write(prefixOnly, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTokenizer_lift(_ buf: RustBuffer) throws -> TantivyTokenizer {
+    return try FfiConverterTypeTantivyTokenizer.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTantivyTokenizer_lower(_ value: TantivyTokenizer) -> RustBuffer {
+    return FfiConverterTypeTantivyTokenizer.lower(value)
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
+    typealias SwiftType = Bool?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterBool.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterBool.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTantivyMigrationProgress: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyMigrationProgress?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTantivyMigrationProgress.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTantivyMigrationProgress.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeFieldValue: FfiConverterRustBuffer {
+    typealias SwiftType = FieldValue?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFieldValue.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFieldValue.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTantivyDatePrecision: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyDatePrecision?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTantivyDatePrecision.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTantivyDatePrecision.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTantivyFieldProjection: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyFieldProjection?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTantivyFieldProjection.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTantivyFieldProjection.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTantivyIndexRecordOption: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyIndexRecordOption?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTantivyIndexRecordOption.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTantivyIndexRecordOption.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTantivyTokenizer: FfiConverterRustBuffer {
+    typealias SwiftType = TantivyTokenizer?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTantivyTokenizer.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTantivyTokenizer.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
+
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeDocumentField: FfiConverterRustBuffer {
+    typealias SwiftType = [DocumentField]
+
+    public static func write(_ value: [DocumentField], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDocumentField.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DocumentField] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DocumentField]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDocumentField.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTantivyAggregationBucket: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyAggregationBucket]
+
+    public static func write(_ value: [TantivyAggregationBucket], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyAggregationBucket.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyAggregationBucket] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyAggregationBucket]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyAggregationBucket.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTantivyAggregationResult: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyAggregationResult]
+
+    public static func write(_ value: [TantivyAggregationResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyAggregationResult.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: TantivyIndexError, into buf: inout [UInt8]) {
-        switch value {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyAggregationResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyAggregationResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyAggregationResult.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTantivyDocumentFields: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyDocumentFields]
 
-        
-        case .IoError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(1))
-        case .OpenDirectoryError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(2))
-        case .TantivyError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(3))
-        case .SerializationError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(4))
-        case .DocParsingError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(5))
-        case .FacetParseError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(6))
-        case .TryFromIntError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(7))
-        case .WriterAcquisitionError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(8))
-        case .DocRetrievalError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(9))
-        case .SchemaBuilderError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(10))
-        case .QueryError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(11))
+    public static func write(_ value: [TantivyDocumentFields], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyDocumentFields.write(item, into: &buf)
+        }
+    }
 
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyDocumentFields] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyDocumentFields]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyDocumentFields.read(from: &buf))
         }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTantivyExplanation: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyExplanation]
+
+    public static func write(_ value: [TantivyExplanation], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyExplanation.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyExplanation] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyExplanation]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyExplanation.read(from: &buf))
+        }
+        return seq
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyIndexError_lift(_ buf: RustBuffer) throws -> TantivyIndexError {
-    return try FfiConverterTypeTantivyIndexError.lift(buf)
+fileprivate struct FfiConverterSequenceTypeTantivyFacetCount: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyFacetCount]
+
+    public static func write(_ value: [TantivyFacetCount], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyFacetCount.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyFacetCount] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyFacetCount]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyFacetCount.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyIndexError_lower(_ value: TantivyIndexError) -> RustBuffer {
-    return FfiConverterTypeTantivyIndexError.lower(value)
+fileprivate struct FfiConverterSequenceTypeTantivyFacetCounts: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyFacetCounts]
+
+    public static func write(_ value: [TantivyFacetCounts], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyFacetCounts.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyFacetCounts] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyFacetCounts]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyFacetCounts.read(from: &buf))
+        }
+        return seq
+    }
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTantivyFieldChange: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyFieldChange]
 
-public enum TantivyIndexRecordOption: Equatable, Hashable {
-    
-    case basic
-    case withFreqs
-    case withFreqsAndPositions
+    public static func write(_ value: [TantivyFieldChange], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyFieldChange.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyFieldChange] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyFieldChange]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyFieldChange.read(from: &buf))
+        }
+        return seq
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTantivyFieldDescriptor: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyFieldDescriptor]
 
+    public static func write(_ value: [TantivyFieldDescriptor], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyFieldDescriptor.write(item, into: &buf)
+        }
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyFieldDescriptor] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyFieldDescriptor]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyFieldDescriptor.read(from: &buf))
+        }
+        return seq
+    }
 }
 
-#if compiler(>=6)
-extension TantivyIndexRecordOption: Sendable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+fileprivate struct FfiConverterSequenceTypeTantivyFuzzyField: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyFuzzyField]
+
+    public static func write(_ value: [TantivyFuzzyField], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyFuzzyField.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyFuzzyField] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyFuzzyField]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyFuzzyField.read(from: &buf))
+        }
+        return seq
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivyIndexRecordOption: FfiConverterRustBuffer {
-    typealias SwiftType = TantivyIndexRecordOption
+fileprivate struct FfiConverterSequenceTypeTantivyHighlightRange: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyHighlightRange]
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyIndexRecordOption {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .basic
-        
-        case 2: return .withFreqs
-        
-        case 3: return .withFreqsAndPositions
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: [TantivyHighlightRange], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyHighlightRange.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: TantivyIndexRecordOption, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .basic:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .withFreqs:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .withFreqsAndPositions:
-            writeInt(&buf, Int32(3))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyHighlightRange] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyHighlightRange]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyHighlightRange.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTantivyIdHit: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyIdHit]
+
+    public static func write(_ value: [TantivyIdHit], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyIdHit.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyIdHit] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyIdHit]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyIdHit.read(from: &buf))
         }
+        return seq
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTantivyPercentile: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyPercentile]
+
+    public static func write(_ value: [TantivyPercentile], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyPercentile.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyPercentile] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyPercentile]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyPercentile.read(from: &buf))
+        }
+        return seq
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyIndexRecordOption_lift(_ buf: RustBuffer) throws -> TantivyIndexRecordOption {
-    return try FfiConverterTypeTantivyIndexRecordOption.lift(buf)
+fileprivate struct FfiConverterSequenceTypeTantivyQueryParseError: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyQueryParseError]
+
+    public static func write(_ value: [TantivyQueryParseError], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivyQueryParseError.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyQueryParseError] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivyQueryParseError]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivyQueryParseError.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyIndexRecordOption_lower(_ value: TantivyIndexRecordOption) -> RustBuffer {
-    return FfiConverterTypeTantivyIndexRecordOption.lower(value)
-}
-
+fileprivate struct FfiConverterSequenceTypeTantivySearchHit: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivySearchHit]
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: [TantivySearchHit], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivySearchHit.write(item, into: &buf)
+        }
+    }
 
-public enum TantivyTokenizer: Equatable, Hashable {
-    
-    case raw
-    case `default`
-    case unicode
-    case enStem
-    case whitespace
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivySearchHit] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivySearchHit]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivySearchHit.read(from: &buf))
+        }
+        return seq
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTantivySearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivySearchResult]
 
+    public static func write(_ value: [TantivySearchResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivySearchResult.write(item, into: &buf)
+        }
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivySearchResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivySearchResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivySearchResult.read(from: &buf))
+        }
+        return seq
+    }
 }
 
-#if compiler(>=6)
-extension TantivyTokenizer: Sendable {}
-#endif
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTantivyTokenizer: FfiConverterRustBuffer {
-    typealias SwiftType = TantivyTokenizer
+fileprivate struct FfiConverterSequenceTypeTantivySegmentTermStats: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivySegmentTermStats]
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TantivyTokenizer {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .raw
-        
-        case 2: return .`default`
-        
-        case 3: return .unicode
-        
-        case 4: return .enStem
-        
-        case 5: return .whitespace
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: [TantivySegmentTermStats], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivySegmentTermStats.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: TantivyTokenizer, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .raw:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .`default`:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .unicode:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .enStem:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .whitespace:
-            writeInt(&buf, Int32(5))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivySegmentTermStats] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivySegmentTermStats]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivySegmentTermStats.read(from: &buf))
         }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyTokenizer_lift(_ buf: RustBuffer) throws -> TantivyTokenizer {
-    return try FfiConverterTypeTantivyTokenizer.lift(buf)
+fileprivate struct FfiConverterSequenceTypeTantivySnippet: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivySnippet]
+
+    public static func write(_ value: [TantivySnippet], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivySnippet.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivySnippet] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivySnippet]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivySnippet.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTantivyTokenizer_lower(_ value: TantivyTokenizer) -> RustBuffer {
-    return FfiConverterTypeTantivyTokenizer.lower(value)
-}
+fileprivate struct FfiConverterSequenceTypeTantivySnippetField: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivySnippetField]
+
+    public static func write(_ value: [TantivySnippetField], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivySnippetField.write(item, into: &buf)
+        }
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivySnippetField] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivySnippetField]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivySnippetField.read(from: &buf))
+        }
+        return seq
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeTantivyTokenizer: FfiConverterRustBuffer {
-    typealias SwiftType = TantivyTokenizer?
+fileprivate struct FfiConverterSequenceTypeTantivySortField: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivySortField]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [TantivySortField], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTantivySortField.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeTantivyTokenizer.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeTantivyTokenizer.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivySortField] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TantivySortField]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTantivySortField.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
+fileprivate struct FfiConverterSequenceTypeTantivySpellToken: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivySpellToken]
 
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
+    public static func write(_ value: [TantivySpellToken], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterString.write(item, into: &buf)
+            FfiConverterTypeTantivySpellToken.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivySpellToken] {
         let len: Int32 = try readInt(&buf)
-        var seq = [String]()
+        var seq = [TantivySpellToken]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
+            seq.append(try FfiConverterTypeTantivySpellToken.read(from: &buf))
         }
         return seq
     }
@@ -2320,23 +6903,23 @@ fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeDocumentField: FfiConverterRustBuffer {
-    typealias SwiftType = [DocumentField]
+fileprivate struct FfiConverterSequenceTypeTantivyTermFrequency: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyTermFrequency]
 
-    public static func write(_ value: [DocumentField], into buf: inout [UInt8]) {
+    public static func write(_ value: [TantivyTermFrequency], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeDocumentField.write(item, into: &buf)
+            FfiConverterTypeTantivyTermFrequency.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DocumentField] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyTermFrequency] {
         let len: Int32 = try readInt(&buf)
-        var seq = [DocumentField]()
+        var seq = [TantivyTermFrequency]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeDocumentField.read(from: &buf))
+            seq.append(try FfiConverterTypeTantivyTermFrequency.read(from: &buf))
         }
         return seq
     }
@@ -2345,23 +6928,23 @@ fileprivate struct FfiConverterSequenceTypeDocumentField: FfiConverterRustBuffer
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeTantivyDocumentFields: FfiConverterRustBuffer {
-    typealias SwiftType = [TantivyDocumentFields]
+fileprivate struct FfiConverterSequenceTypeTantivyTermSuggestion: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyTermSuggestion]
 
-    public static func write(_ value: [TantivyDocumentFields], into buf: inout [UInt8]) {
+    public static func write(_ value: [TantivyTermSuggestion], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeTantivyDocumentFields.write(item, into: &buf)
+            FfiConverterTypeTantivyTermSuggestion.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyDocumentFields] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyTermSuggestion] {
         let len: Int32 = try readInt(&buf)
-        var seq = [TantivyDocumentFields]()
+        var seq = [TantivyTermSuggestion]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeTantivyDocumentFields.read(from: &buf))
+            seq.append(try FfiConverterTypeTantivyTermSuggestion.read(from: &buf))
         }
         return seq
     }
@@ -2370,23 +6953,23 @@ fileprivate struct FfiConverterSequenceTypeTantivyDocumentFields: FfiConverterRu
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeTantivyFuzzyField: FfiConverterRustBuffer {
-    typealias SwiftType = [TantivyFuzzyField]
+fileprivate struct FfiConverterSequenceTypeTantivyToken: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyToken]
 
-    public static func write(_ value: [TantivyFuzzyField], into buf: inout [UInt8]) {
+    public static func write(_ value: [TantivyToken], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeTantivyFuzzyField.write(item, into: &buf)
+            FfiConverterTypeTantivyToken.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyFuzzyField] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyToken] {
         let len: Int32 = try readInt(&buf)
-        var seq = [TantivyFuzzyField]()
+        var seq = [TantivyToken]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeTantivyFuzzyField.read(from: &buf))
+            seq.append(try FfiConverterTypeTantivyToken.read(from: &buf))
         }
         return seq
     }
@@ -2395,23 +6978,23 @@ fileprivate struct FfiConverterSequenceTypeTantivyFuzzyField: FfiConverterRustBu
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeTantivySearchHit: FfiConverterRustBuffer {
-    typealias SwiftType = [TantivySearchHit]
+fileprivate struct FfiConverterSequenceTypeTantivyFieldMapping: FfiConverterRustBuffer {
+    typealias SwiftType = [TantivyFieldMapping]
 
-    public static func write(_ value: [TantivySearchHit], into buf: inout [UInt8]) {
+    public static func write(_ value: [TantivyFieldMapping], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeTantivySearchHit.write(item, into: &buf)
+            FfiConverterTypeTantivyFieldMapping.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivySearchHit] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivyFieldMapping] {
         let len: Int32 = try readInt(&buf)
-        var seq = [TantivySearchHit]()
+        var seq = [TantivyFieldMapping]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeTantivySearchHit.read(from: &buf))
+            seq.append(try FfiConverterTypeTantivyFieldMapping.read(from: &buf))
         }
         return seq
     }
@@ -2420,23 +7003,23 @@ fileprivate struct FfiConverterSequenceTypeTantivySearchHit: FfiConverterRustBuf
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeTantivySearchResult: FfiConverterRustBuffer {
-    typealias SwiftType = [TantivySearchResult]
+fileprivate struct FfiConverterSequenceOptionTypeFieldValue: FfiConverterRustBuffer {
+    typealias SwiftType = [FieldValue?]
 
-    public static func write(_ value: [TantivySearchResult], into buf: inout [UInt8]) {
+    public static func write(_ value: [FieldValue?], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeTantivySearchResult.write(item, into: &buf)
+            FfiConverterOptionTypeFieldValue.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TantivySearchResult] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FieldValue?] {
         let len: Int32 = try readInt(&buf)
-        var seq = [TantivySearchResult]()
+        var seq = [FieldValue?]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeTantivySearchResult.read(from: &buf))
+            seq.append(try FfiConverterOptionTypeFieldValue.read(from: &buf))
         }
         return seq
     }
@@ -2457,25 +7040,46 @@ private let initializationResult: InitializationResult = {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
+    if (uniffi_tantivy_checksum_method_tantivyanalyzerbuilder_add_filter() != 27523) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_aggregate() != 56514) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_analyze() != 48273) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_tantivy_checksum_method_tantivyindex_clear_index() != 27081) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_tantivy_checksum_method_tantivyindex_commit() != 43767) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_tantivy_checksum_method_tantivyindex_delete_by_query() != 4974) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_tantivy_checksum_method_tantivyindex_delete_doc() != 42366) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_tantivy_checksum_method_tantivyindex_delete_docs() != 17289) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_tantivy_checksum_method_tantivyindex_doc_exists() != 46277) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_tantivy_checksum_method_tantivyindex_docs_count() != 27948) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_tantivy_checksum_method_tantivyindex_get_doc() != 36267) {
+    if (uniffi_tantivy_checksum_method_tantivyindex_explain() != 49975) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_facet_counts() != 35520) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_tantivy_checksum_method_tantivyindex_get_docs_by_ids() != 15134) {
+    if (uniffi_tantivy_checksum_method_tantivyindex_get_doc() != 30553) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_get_docs_by_ids() != 67) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_tantivy_checksum_method_tantivyindex_index_doc() != 42651) {
@@ -2484,13 +7088,46 @@ private let initializationResult: InitializationResult = {
     if (uniffi_tantivy_checksum_method_tantivyindex_index_docs() != 51417) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_tantivy_checksum_method_tantivyindex_list_terms() != 46946) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_schema() != 58681) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_tantivy_checksum_method_tantivyindex_search() != 50109) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_tantivy_checksum_method_tantivyindex_search_doc_ids() != 800) {
+    if (uniffi_tantivy_checksum_method_tantivyindex_search_doc_ids() != 27255) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_search_dsl() != 34011) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_search_ids_dsl() != 54712) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_spell_suggest() != 20638) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_tantivy_checksum_method_tantivyindex_search_dsl() != 49563) {
+    if (uniffi_tantivy_checksum_method_tantivyindex_suggest() != 11514) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_term_stats() != 4940) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_upsert_doc() != 6588) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_upsert_docs() != 10158) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyindex_validate_query() != 60459) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivymigrationprogress_on_progress() != 24157) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyschemabuilder_add_analyzer() != 13890) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_tantivy_checksum_method_tantivyschemabuilder_add_bool_field() != 55377) {
@@ -2511,6 +7148,9 @@ private let initializationResult: InitializationResult = {
     if (uniffi_tantivy_checksum_method_tantivyschemabuilder_add_i64_field() != 12775) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_tantivy_checksum_method_tantivyschemabuilder_add_ip_addr_field() != 36995) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_tantivy_checksum_method_tantivyschemabuilder_add_json_field() != 16732) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -2520,13 +7160,41 @@ private let initializationResult: InitializationResult = {
     if (uniffi_tantivy_checksum_method_tantivyschemabuilder_add_u64_field() != 28559) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_tantivy_checksum_method_tantivyschemabuilder_diff_with_index() != 9918) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyschemabuilder_fields() != 53817) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_method_tantivyschemabuilder_to_json() != 52386) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_constructor_tantivyanalyzerbuilder_new() != 44746) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_constructor_tantivyindex_migrate() != 23368) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_constructor_tantivyindex_new_with_options() != 23555) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_tantivy_checksum_constructor_tantivyindex_new_with_schema() != 52043) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_tantivy_checksum_constructor_tantivyindex_open() != 41054) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_constructor_tantivyindex_open_read_only() != 50315) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_tantivy_checksum_constructor_tantivyschemabuilder_from_json() != 16406) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_tantivy_checksum_constructor_tantivyschemabuilder_new() != 16789) {
         return InitializationResult.apiChecksumMismatch
     }
 
+    uniffiCallbackInitTantivyMigrationProgress()
     return InitializationResult.ok
 }()
 
@@ -0,0 +1,131 @@
+import Foundation
+
+public indirect enum TantivyAggregation: Encodable, Sendable {
+    case terms(field: String, size: UInt32? = nil, minDocCount: UInt64? = nil, aggs: [String: TantivyAggregation] = [:])
+    case histogram(field: String, interval: Double, minDocCount: UInt64? = nil, aggs: [String: TantivyAggregation] = [:])
+    case dateHistogram(field: String, fixedInterval: String, minDocCount: UInt64? = nil, aggs: [String: TantivyAggregation] = [:])
+    case range(field: String, ranges: [TantivyAggregationRange], aggs: [String: TantivyAggregation] = [:])
+    case avg(field: String)
+    case min(field: String)
+    case max(field: String)
+    case sum(field: String)
+    case count(field: String)
+    case stats(field: String)
+    case percentiles(field: String, percents: [Double]? = nil)
+
+    public static func toJson(_ aggregations: [String: TantivyAggregation]) throws -> String {
+        return try TantivyJsonCoding.encode(aggregations)
+    }
+
+    private struct DynamicKey: CodingKey {
+        var stringValue: String
+        var intValue: Int? { nil }
+
+        init(_ stringValue: String) {
+            self.stringValue = stringValue
+        }
+
+        init?(stringValue: String) {
+            self.stringValue = stringValue
+        }
+
+        init?(intValue: Int) {
+            return nil
+        }
+    }
+
+    private enum BodyKeys: String, CodingKey {
+        case field
+        case size
+        case minDocCount = "min_doc_count"
+        case interval
+        case fixedInterval = "fixed_interval"
+        case ranges
+        case percents
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: DynamicKey.self)
+
+        switch self {
+        case .terms(let field, let size, let minDocCount, let aggs):
+            var body = container.nestedContainer(keyedBy: BodyKeys.self, forKey: DynamicKey("terms"))
+            try body.encode(field, forKey: .field)
+            try body.encodeIfPresent(size, forKey: .size)
+            try body.encodeIfPresent(minDocCount, forKey: .minDocCount)
+            try Self.encodeSubAggregations(aggs, into: &container)
+
+        case .histogram(let field, let interval, let minDocCount, let aggs):
+            var body = container.nestedContainer(keyedBy: BodyKeys.self, forKey: DynamicKey("histogram"))
+            try body.encode(field, forKey: .field)
+            try body.encode(interval, forKey: .interval)
+            try body.encodeIfPresent(minDocCount, forKey: .minDocCount)
+            try Self.encodeSubAggregations(aggs, into: &container)
+
+        case .dateHistogram(let field, let fixedInterval, let minDocCount, let aggs):
+            var body = container.nestedContainer(keyedBy: BodyKeys.self, forKey: DynamicKey("date_histogram"))
+            try body.encode(field, forKey: .field)
+            try body.encode(fixedInterval, forKey: .fixedInterval)
+            try body.encodeIfPresent(minDocCount, forKey: .minDocCount)
+            try Self.encodeSubAggregations(aggs, into: &container)
+
+        case .range(let field, let ranges, let aggs):
+            var body = container.nestedContainer(keyedBy: BodyKeys.self, forKey: DynamicKey("range"))
+            try body.encode(field, forKey: .field)
+            try body.encode(ranges, forKey: .ranges)
+            try Self.encodeSubAggregations(aggs, into: &container)
+
+        case .avg(let field):
+            try Self.encodeMetric("avg", field: field, into: &container)
+
+        case .min(let field):
+            try Self.encodeMetric("min", field: field, into: &container)
+
+        case .max(let field):
+            try Self.encodeMetric("max", field: field, into: &container)
+
+        case .sum(let field):
+            try Self.encodeMetric("sum", field: field, into: &container)
+
+        case .count(let field):
+            try Self.encodeMetric("value_count", field: field, into: &container)
+
+        case .stats(let field):
+            try Self.encodeMetric("stats", field: field, into: &container)
+
+        case .percentiles(let field, let percents):
+            var body = container.nestedContainer(keyedBy: BodyKeys.self, forKey: DynamicKey("percentiles"))
+            try body.encode(field, forKey: .field)
+            try body.encodeIfPresent(percents, forKey: .percents)
+        }
+    }
+
+    private static func encodeMetric(
+        _ name: String,
+        field: String,
+        into container: inout KeyedEncodingContainer<DynamicKey>
+    ) throws {
+        var body = container.nestedContainer(keyedBy: BodyKeys.self, forKey: DynamicKey(name))
+        try body.encode(field, forKey: .field)
+    }
+
+    private static func encodeSubAggregations(
+        _ aggs: [String: TantivyAggregation],
+        into container: inout KeyedEncodingContainer<DynamicKey>
+    ) throws {
+        guard !aggs.isEmpty else { return }
+        try container.encode(aggs, forKey: DynamicKey("aggs"))
+    }
+}
+
+public struct TantivyAggregationRange: Encodable, Sendable {
+    public var key: String?
+    public var from: Double?
+    public var to: Double?
+
+    public init(key: String? = nil, from: Double? = nil, to: Double? = nil) {
+        self.key = key
+        self.from = from
+        self.to = to
+    }
+}
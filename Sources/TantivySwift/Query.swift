@@ -9,9 +9,12 @@ public indirect enum TantivyQuery: Codable, Sendable {
     case phrase(field: String, terms: [String], slop: UInt32?)
     case phrasePrefix(field: String, terms: [String], maxExpansions: UInt32?)
     case range(field: String, lower: TantivyQueryValue?, upper: TantivyQueryValue?, includeLower: Bool, includeUpper: Bool)
+    case match(field: String, query: String, operator: TantivyMatchOperator = .or, minimumShouldMatch: UInt32? = nil)
+    case matchPhrase(field: String, query: String, slop: UInt32? = nil)
     case regex(field: String, pattern: String)
     case fuzzy(field: String, term: String, distance: UInt8, transposeCostOne: Bool)
     case exists(field: String)
+    case cidr(field: String, cidr: String)
     case boost(query: TantivyQuery, boost: Float)
     case constScore(query: TantivyQuery, score: Float)
     case disjunctionMax(queries: [TantivyQuery], tieBreaker: Float?)
@@ -51,6 +54,10 @@ public indirect enum TantivyQuery: Codable, Sendable {
         case score
         case defaultFields = "default_fields"
         case fuzzyFields = "fuzzy_fields"
+        case strict
+        case cidr
+        case matchOperator = "operator"
+        case minimumShouldMatch = "minimum_should_match"
     }
 
     public func encode(to encoder: Encoder) throws {
@@ -95,6 +102,19 @@ public indirect enum TantivyQuery: Codable, Sendable {
             try container.encode(includeLower, forKey: .includeLower)
             try container.encode(includeUpper, forKey: .includeUpper)
 
+        case .match(let field, let query, let matchOperator, let minimumShouldMatch):
+            try container.encode("match", forKey: .type)
+            try container.encode(field, forKey: .field)
+            try container.encode(query, forKey: .query)
+            try container.encode(matchOperator, forKey: .matchOperator)
+            try container.encodeIfPresent(minimumShouldMatch, forKey: .minimumShouldMatch)
+
+        case .matchPhrase(let field, let query, let slop):
+            try container.encode("match_phrase", forKey: .type)
+            try container.encode(field, forKey: .field)
+            try container.encode(query, forKey: .query)
+            try container.encodeIfPresent(slop, forKey: .slop)
+
         case .regex(let field, let pattern):
             try container.encode("regex", forKey: .type)
             try container.encode(field, forKey: .field)
@@ -111,6 +131,11 @@ public indirect enum TantivyQuery: Codable, Sendable {
             try container.encode("exists", forKey: .type)
             try container.encode(field, forKey: .field)
 
+        case .cidr(let field, let cidr):
+            try container.encode("cidr", forKey: .type)
+            try container.encode(field, forKey: .field)
+            try container.encode(cidr, forKey: .cidr)
+
         case .boost(let query, let boost):
             try container.encode("boost", forKey: .type)
             try container.encode(query, forKey: .query)
@@ -131,6 +156,7 @@ public indirect enum TantivyQuery: Codable, Sendable {
             try container.encode(queryString.query, forKey: .query)
             try container.encode(queryString.defaultFields, forKey: .defaultFields)
             try container.encode(queryString.fuzzyFields, forKey: .fuzzyFields)
+            try container.encode(queryString.strict, forKey: .strict)
         }
     }
 
@@ -166,6 +192,17 @@ public indirect enum TantivyQuery: Codable, Sendable {
             let includeLower = try container.decode(Bool.self, forKey: .includeLower)
             let includeUpper = try container.decode(Bool.self, forKey: .includeUpper)
             self = .range(field: field, lower: lower, upper: upper, includeLower: includeLower, includeUpper: includeUpper)
+        case "match":
+            let field = try container.decode(String.self, forKey: .field)
+            let query = try container.decode(String.self, forKey: .query)
+            let matchOperator = try container.decodeIfPresent(TantivyMatchOperator.self, forKey: .matchOperator) ?? .or
+            let minimumShouldMatch = try container.decodeIfPresent(UInt32.self, forKey: .minimumShouldMatch)
+            self = .match(field: field, query: query, operator: matchOperator, minimumShouldMatch: minimumShouldMatch)
+        case "match_phrase":
+            let field = try container.decode(String.self, forKey: .field)
+            let query = try container.decode(String.self, forKey: .query)
+            let slop = try container.decodeIfPresent(UInt32.self, forKey: .slop)
+            self = .matchPhrase(field: field, query: query, slop: slop)
         case "regex":
             let field = try container.decode(String.self, forKey: .field)
             let pattern = try container.decode(String.self, forKey: .pattern)
@@ -179,6 +216,10 @@ public indirect enum TantivyQuery: Codable, Sendable {
         case "exists":
             let field = try container.decode(String.self, forKey: .field)
             self = .exists(field: field)
+        case "cidr":
+            let field = try container.decode(String.self, forKey: .field)
+            let cidr = try container.decode(String.self, forKey: .cidr)
+            self = .cidr(field: field, cidr: cidr)
         case "boost":
             let query = try container.decode(TantivyQuery.self, forKey: .query)
             let boost = try container.decode(Float.self, forKey: .boost)
@@ -195,7 +236,10 @@ public indirect enum TantivyQuery: Codable, Sendable {
             let query = try container.decode(String.self, forKey: .query)
             let defaultFields = try container.decodeIfPresent([String].self, forKey: .defaultFields) ?? []
             let fuzzyFields = try container.decodeIfPresent([TantivyQueryFuzzyField].self, forKey: .fuzzyFields) ?? []
-            self = .queryString(TantivyQueryString(query: query, defaultFields: defaultFields, fuzzyFields: fuzzyFields))
+            let strict = try container.decodeIfPresent(Bool.self, forKey: .strict) ?? false
+            self = .queryString(
+                TantivyQueryString(query: query, defaultFields: defaultFields, fuzzyFields: fuzzyFields, strict: strict)
+            )
         default:
             throw DecodingError.dataCorruptedError(
                 forKey: .type,
@@ -226,6 +270,7 @@ public enum TantivyQueryValue: Codable, Sendable {
     case bytes(Data)
     case facet(String)
     case json(String)
+    case ipAddr(String)
 
     public static func from(date value: Date) -> TantivyQueryValue {
         let micros = Int64((value.timeIntervalSince1970 * 1_000_000).rounded())
@@ -268,6 +313,9 @@ public enum TantivyQueryValue: Codable, Sendable {
         case .json(let value):
             try container.encode("json", forKey: .type)
             try container.encode(value, forKey: .value)
+        case .ipAddr(let value):
+            try container.encode("ip_addr", forKey: .type)
+            try container.encode(value, forKey: .value)
         }
     }
 
@@ -295,6 +343,8 @@ public enum TantivyQueryValue: Codable, Sendable {
             self = .facet(try container.decode(String.self, forKey: .value))
         case "json":
             self = .json(try container.decode(String.self, forKey: .value))
+        case "ip_addr":
+            self = .ipAddr(try container.decode(String.self, forKey: .value))
         default:
             throw DecodingError.dataCorruptedError(
                 forKey: .type,
@@ -305,6 +355,11 @@ public enum TantivyQueryValue: Codable, Sendable {
     }
 }
 
+public enum TantivyMatchOperator: String, Codable, Sendable {
+    case or
+    case and
+}
+
 public enum TantivyQueryOccur: String, Codable, Sendable {
     case must
     case should
@@ -325,17 +380,34 @@ public struct TantivyQueryString: Codable, Sendable {
     public var query: String
     public var defaultFields: [String]
     public var fuzzyFields: [TantivyQueryFuzzyField]
-
-    public init(query: String, defaultFields: [String] = [], fuzzyFields: [TantivyQueryFuzzyField] = []) {
+    /// Throw `TantivyIndexError.QueryParseError` instead of skipping invalid parts of the query.
+    public var strict: Bool
+
+    public init(
+        query: String,
+        defaultFields: [String] = [],
+        fuzzyFields: [TantivyQueryFuzzyField] = [],
+        strict: Bool = false
+    ) {
         self.query = query
         self.defaultFields = defaultFields
         self.fuzzyFields = fuzzyFields
+        self.strict = strict
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        query = try container.decode(String.self, forKey: .query)
+        defaultFields = try container.decodeIfPresent([String].self, forKey: .defaultFields) ?? []
+        fuzzyFields = try container.decodeIfPresent([TantivyQueryFuzzyField].self, forKey: .fuzzyFields) ?? []
+        strict = try container.decodeIfPresent(Bool.self, forKey: .strict) ?? false
     }
 
     private enum CodingKeys: String, CodingKey {
         case query
         case defaultFields = "default_fields"
         case fuzzyFields = "fuzzy_fields"
+        case strict
     }
 }
 
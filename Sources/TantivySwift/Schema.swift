@@ -420,6 +420,55 @@ public struct FacetField<Value: Codable & Sendable>: TantivySchemaField, Codable
     }
 }
 
+@propertyWrapper
+public struct IpAddrField<Value: Codable & Sendable>: TantivySchemaField, Codable, Sendable {
+    public var wrappedValue: Value
+    public var fieldName: String = ""
+
+    public var indexed: Bool
+    public var stored: Bool
+    public var fast: Bool
+    public var fieldnorms: Bool
+
+    public init(
+        wrappedValue: Value,
+        indexed: Bool = true,
+        stored: Bool = true,
+        fast: Bool = false,
+        fieldnorms: Bool = false
+    ) {
+        self.wrappedValue = wrappedValue
+        self.indexed = indexed
+        self.stored = stored
+        self.fast = fast
+        self.fieldnorms = fieldnorms
+    }
+
+    public func register(with builder: TantivySchemaBuilder) {
+        let options = NumericFieldOptions(
+            indexed: indexed,
+            stored: stored,
+            fast: fast,
+            fieldnorms: fieldnorms
+        )
+        builder.addIpAddrField(name: fieldName, options: options)
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        self.wrappedValue = try container.decode(Value.self)
+        self.indexed = true
+        self.stored = true
+        self.fast = false
+        self.fieldnorms = false
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(wrappedValue)
+    }
+}
+
 @propertyWrapper
 public struct JsonField<Value: Codable & Sendable>: TantivySchemaField, Codable, Sendable {
     public var wrappedValue: Value
@@ -541,12 +590,86 @@ public protocol TantivyDocument: TantivySearchableDocument, Codable, Sendable {
     init(fromFields fields: TantivyDocumentFields) throws
 }
 
+private final class MigrationProgress: TantivyMigrationProgress {
+    let handler: @Sendable (UInt64, UInt64) -> Void
+
+    init(_ handler: @escaping @Sendable (UInt64, UInt64) -> Void) {
+        self.handler = handler
+    }
+
+    func onProgress(migrated: UInt64, total: UInt64) {
+        handler(migrated, total)
+    }
+}
+
 public actor TantivySwiftIndex<Doc: TantivyDocument> {
     let index: TantivyIndex
     
-    public init(path: String) throws {
+    /// `analyzers` are registered before the schema's text fields reference them as
+    /// `.custom(name:)` tokenizers. They are saved with the index, leave them out to reuse them.
+    ///
+    /// `options` size the writer's memory and threads, e.g. down to 15 MB for an app extension.
+    public init(
+        path: String,
+        analyzers: [TantivyAnalyzerBuilder] = [],
+        options: TantivyIndexOptions = TantivyIndexOptions()
+    ) throws {
         let schemaBuilder = TantivySchemaExtractor.buildSchema(for: Doc.self)
-        self.index = try TantivyIndex.newWithSchema(path: path, schemaBuilder: schemaBuilder)
+        for analyzer in analyzers {
+            schemaBuilder.addAnalyzer(analyzer: analyzer)
+        }
+        self.index = try TantivyIndex.newWithOptions(path: path, schemaBuilder: schemaBuilder, options: options)
+    }
+
+    init(index: TantivyIndex) {
+        self.index = index
+    }
+
+    /// Opens an existing index with the schema saved in it, without checking it against `Doc`.
+    public static func open(
+        path: String,
+        options: TantivyIndexOptions = TantivyIndexOptions()
+    ) throws -> TantivySwiftIndex<Doc> {
+        return TantivySwiftIndex(index: try TantivyIndex.open(path: path, options: options))
+    }
+
+    /// How `Doc`'s schema differs from the index at `path`. Opening an index whose schema
+    /// drifted throws `TantivyIndexError.SchemaMismatch`, which only carries a message, so
+    /// call this to inspect the differences.
+    public static func schemaDiff(path: String) throws -> TantivySchemaDiff {
+        return try TantivySchemaExtractor.buildSchema(for: Doc.self).diffWithIndex(path: path)
+    }
+
+    /// Reindexes the index at `path` into `Doc`'s schema and opens it. Stored fields keep
+    /// their name unless `mappings` rename or drop them, and defaults fill fields `Doc` adds.
+    /// The old index stays in place until the new one is complete. Release other handles to
+    /// the index first; `progress` receives the migrated and total document counts. `options`
+    /// size the writer that builds the new index and are used to open it.
+    public static func migrate(
+        path: String,
+        mappings: [TantivyFieldMapping] = [],
+        analyzers: [TantivyAnalyzerBuilder] = [],
+        options: TantivyIndexOptions = TantivyIndexOptions(),
+        progress: (@Sendable (UInt64, UInt64) -> Void)? = nil
+    ) throws -> TantivySwiftIndex<Doc> {
+        let schemaBuilder = TantivySchemaExtractor.buildSchema(for: Doc.self)
+        for analyzer in analyzers {
+            schemaBuilder.addAnalyzer(analyzer: analyzer)
+        }
+        let index = try TantivyIndex.migrate(
+            path: path,
+            schemaBuilder: schemaBuilder,
+            fieldMappings: mappings,
+            options: options,
+            progress: progress.map(MigrationProgress.init)
+        )
+        return TantivySwiftIndex(index: index)
+    }
+
+    /// Opens an existing index for searching only, e.g. from a widget or Spotlight extension
+    /// while the app keeps writing to it. Write methods throw `TantivyIndexError.ReadOnlyIndex`.
+    public static func openReadOnly(path: String) throws -> TantivySwiftIndex<Doc> {
+        return TantivySwiftIndex(index: try TantivyIndex.openReadOnly(path: path))
     }
     
     public func clear() throws {
@@ -580,10 +703,33 @@ public actor TantivySwiftIndex<Doc: TantivyDocument> {
         try index.commit()
     }
 
+    public func upsert(doc: Doc, idField: Doc.CodingKeys) throws {
+        try index.upsertDoc(idField: idField.stringValue, doc: try doc.toTantivyDocument())
+        try commit()
+    }
+
+    public func upsert(docs: [Doc], idField: Doc.CodingKeys) throws {
+        let nativeDocs = try docs.map { try $0.toTantivyDocument() }
+        try index.upsertDocs(idField: idField.stringValue, docs: nativeDocs)
+        try commit()
+    }
+
     public func deleteDoc(id: DocumentField) throws {
         try index.deleteDoc(id: id)
     }
 
+    /// Stages deletions without committing; call `commit()` to apply them.
+    @discardableResult
+    public func deleteDocs(ids: [DocumentField]) throws -> UInt64 {
+        return try index.deleteDocs(ids: ids)
+    }
+
+    /// Stages deletions without committing; call `commit()` to apply them.
+    @discardableResult
+    public func deleteDocs(matching query: TantivyQuery) throws -> UInt64 {
+        return try index.deleteByQuery(queryJson: try query.toJson())
+    }
+
     public func docExists(id: DocumentField) throws -> Bool {
         return try index.docExists(id: id)
     }
@@ -593,26 +739,181 @@ public actor TantivySwiftIndex<Doc: TantivyDocument> {
         return try Doc(fromFields: fields)
     }
 
+    public func getDocFields(id: DocumentField, fields: TantivyFieldProjection) throws -> [DocumentField] {
+        return try index.getDoc(id: id, fields: fields).fields
+    }
+
     public func getDocs(ids: [DocumentField]) throws -> [Doc] {
         let fields = try index.getDocsByIds(ids: ids)
         return try fields.map { try Doc(fromFields: $0) }
     }
 
-    public func search(query: TantivyQuery, limit: UInt32 = 10, offset: UInt32 = 0) throws -> TantivySearchResults<Doc> {
+    public func search(
+        query: TantivyQuery,
+        limit: UInt32 = 10,
+        offset: UInt32 = 0,
+        sort: [TantivySortField] = [],
+        snippetFields: [TantivySnippetField] = [],
+        cursor: String? = nil
+    ) throws -> TantivySearchResults<Doc> {
         let queryJson = try query.toJson()
         let results = try index.searchDsl(
             queryJson: queryJson,
             topDocLimit: limit,
-            topDocOffset: offset
+            topDocOffset: offset,
+            sort: sort,
+            snippetFields: snippetFields,
+            cursor: cursor
         )
         let docs = try results.docs.map { result in
-            TantivySearchResult(score: result.score, doc: try Doc(fromFields: result.doc))
+            TantivySearchResult(
+                score: result.score,
+                doc: try Doc(fromFields: result.doc),
+                sortValues: result.sortValues,
+                snippets: result.snippets
+            )
         }
-        return TantivySearchResults(count: results.count, docs: docs)
+        return TantivySearchResults(count: results.count, docs: docs, nextCursor: results.nextCursor)
+    }
+
+    /// Searches without decoding hits into `Doc`, returning only the projected stored fields.
+    public func search(
+        query: TantivyQuery,
+        fields: TantivyFieldProjection,
+        limit: UInt32 = 10,
+        offset: UInt32 = 0,
+        sort: [TantivySortField] = [],
+        cursor: String? = nil
+    ) throws -> TantivyProjectedSearchResults {
+        let results = try index.searchDsl(
+            queryJson: try query.toJson(),
+            topDocLimit: limit,
+            topDocOffset: offset,
+            sort: sort,
+            cursor: cursor,
+            fields: fields
+        )
+        let docs = results.docs.map { result in
+            TantivyProjectedSearchResult(
+                score: result.score,
+                fields: result.doc.fields,
+                sortValues: result.sortValues
+            )
+        }
+        return TantivyProjectedSearchResults(count: results.count, docs: docs, nextCursor: results.nextCursor)
+    }
+
+    /// Returns the IDs of the matching documents, read from the fast column of `idField`.
+    /// Throws `TantivyIndexError.MissingIdField` when a hit has no ID.
+    public func searchIds(
+        query: TantivyQuery,
+        idField: Doc.CodingKeys,
+        limit: UInt32 = 10,
+        offset: UInt32 = 0,
+        sort: [TantivySortField] = [],
+        cursor: String? = nil
+    ) throws -> TantivyIdSearchResults {
+        return try index.searchIdsDsl(
+            queryJson: try query.toJson(),
+            idField: idField.stringValue,
+            topDocLimit: limit,
+            topDocOffset: offset,
+            sort: sort,
+            cursor: cursor
+        )
     }
 
     public func search(query: TantivySwiftSearchQuery<Doc>) throws -> TantivySearchResults<Doc> {
-        return try search(query: query.toTantivyQuery(), limit: query.limit, offset: query.offset)
+        return try search(
+            query: query.toTantivyQuery(),
+            limit: query.limit,
+            offset: query.offset,
+            sort: query.sort.map { $0.toSortField() },
+            snippetFields: query.snippetFields.map { $0.toSnippetField() },
+            cursor: query.cursor
+        )
+    }
+
+    public func validate(query: TantivySwiftSearchQuery<Doc>) throws -> TantivyQueryValidation {
+        return try index.validateQuery(
+            queryStr: query.queryStr,
+            defaultFields: query.defaultFields.map { $0.stringValue },
+            fuzzyFields: query.fuzzyFields.map {
+                TantivyFuzzyField(
+                    fieldName: $0.field.stringValue,
+                    prefix: $0.prefix,
+                    distance: $0.distance,
+                    transposeCostOne: $0.transposeCostOne
+                )
+            }
+        )
+    }
+
+    public func explain(query: TantivyQuery, id: DocumentField) throws -> TantivyExplanation {
+        return try index.explain(queryJson: try query.toJson(), id: id)
+    }
+
+    /// Completes the last word of `prefix` from the indexed terms of `field`, most frequent first.
+    public func suggest(field: Doc.CodingKeys, prefix: String, limit: UInt32 = 10) throws -> [TantivyTermSuggestion] {
+        return try index.suggest(field: field.stringValue, prefix: prefix, limit: limit)
+    }
+
+    /// Suggests indexed terms within `maxDistance` edits (at most 2) for each word of `text`.
+    public func spellSuggest(field: Doc.CodingKeys, text: String, maxDistance: UInt8 = 2) throws -> TantivySpellSuggestion {
+        return try index.spellSuggest(field: field.stringValue, text: text, maxDistance: maxDistance)
+    }
+
+    /// Lists the indexed terms of `field` in order, pass `nextCursor` back for the next page.
+    public func listTerms(
+        field: Doc.CodingKeys,
+        limit: UInt32 = 100,
+        prefix: String? = nil,
+        cursor: String? = nil
+    ) throws -> TantivyTermPage {
+        return try index.listTerms(field: field.stringValue, limit: limit, prefix: prefix, cursor: cursor)
+    }
+
+    public func termStats(field: Doc.CodingKeys, term: String) throws -> TantivyTermStats {
+        return try index.termStats(field: field.stringValue, term: term)
+    }
+
+    /// Runs `text` through the analyzer `field` is indexed with.
+    public func analyze(field: Doc.CodingKeys, text: String) throws -> [TantivyToken] {
+        return try index.analyze(fieldOrTokenizer: field.stringValue, text: text)
+    }
+
+    /// Runs `text` through a registered tokenizer, e.g. `unicode` or a custom analyzer.
+    public func analyze(tokenizer: String, text: String) throws -> [TantivyToken] {
+        return try index.analyze(fieldOrTokenizer: tokenizer, text: text)
+    }
+
+    /// The fields of the index as stored on disk, in schema order.
+    public func schema() -> [TantivyFieldDescriptor] {
+        return index.schema()
+    }
+
+    public func facetCounts(
+        query: TantivyQuery = .all,
+        field: Doc.CodingKeys,
+        roots: [String] = ["/"],
+        topK: UInt32? = nil
+    ) throws -> [TantivyFacetCounts] {
+        return try index.facetCounts(
+            queryJson: try query.toJson(),
+            field: field.stringValue,
+            rootPaths: roots,
+            topK: topK
+        )
+    }
+
+    public func aggregate(
+        query: TantivyQuery = .all,
+        aggregations: [String: TantivyAggregation]
+    ) throws -> TantivyAggregationResults {
+        return try index.aggregate(
+            queryJson: try query.toJson(),
+            aggregationsJson: try TantivyAggregation.toJson(aggregations)
+        )
     }
 }
 
@@ -753,6 +1054,18 @@ public struct TantivyDocumentFieldMap: Sendable {
         }
     }
 
+    public func ipAddr(_ name: String) -> String? {
+        guard case let .ipAddr(value) = firstValue(for: name) else { return nil }
+        return value
+    }
+
+    public func ipAddrs(_ name: String) -> [String] {
+        values(for: name).compactMap { value in
+            guard case let .ipAddr(ipAddr) = value else { return nil }
+            return ipAddr
+        }
+    }
+
     public func json(_ name: String) -> String? {
         guard case let .json(value) = firstValue(for: name) else { return nil }
         return value
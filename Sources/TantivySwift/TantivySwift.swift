@@ -10,19 +10,33 @@ public struct TantivySwiftSearchQuery<TantivyDoc: TantivySearchableDocument & Se
     public var fuzzyFields: [TantivySwiftFuzzyField<TantivyDoc>]
     public var limit: UInt32
     public var offset: UInt32
+    public var sort: [TantivySwiftSortField<TantivyDoc>]
+    public var snippetFields: [TantivySwiftSnippetField<TantivyDoc>]
+    /// `nextCursor` of a previous page, hits are returned from right after it.
+    public var cursor: String?
+    /// Throw on query parse errors instead of skipping the invalid parts.
+    public var strict: Bool
 
     public init(
         queryStr: String,
         defaultFields: [TantivyDoc.CodingKeys] = [],
         fuzzyFields: [TantivySwiftFuzzyField<TantivyDoc>] = [],
         limit: UInt32 = 10,
-        offset: UInt32 = 0
+        offset: UInt32 = 0,
+        sort: [TantivySwiftSortField<TantivyDoc>] = [],
+        snippetFields: [TantivySwiftSnippetField<TantivyDoc>] = [],
+        cursor: String? = nil,
+        strict: Bool = false
     ) {
         self.queryStr = queryStr
         self.defaultFields = defaultFields
         self.fuzzyFields = fuzzyFields
         self.limit = limit
         self.offset = offset
+        self.sort = sort
+        self.snippetFields = snippetFields
+        self.cursor = cursor
+        self.strict = strict
     }
 
     func toTantivyQuery() -> TantivyQuery {
@@ -30,7 +44,8 @@ public struct TantivySwiftSearchQuery<TantivyDoc: TantivySearchableDocument & Se
             TantivyQueryString(
                 query: queryStr,
                 defaultFields: defaultFields.map { $0.stringValue },
-                fuzzyFields: fuzzyFields.map { $0.toQueryFuzzyField() }
+                fuzzyFields: fuzzyFields.map { $0.toQueryFuzzyField() },
+                strict: strict
             )
         )
     }
@@ -64,14 +79,102 @@ public struct TantivySwiftFuzzyField<TantivyDoc: TantivySearchableDocument & Sen
     }
 }
 
+public struct TantivySwiftSortField<TantivyDoc: TantivySearchableDocument & Sendable>: Sendable {
+    public var field: TantivyDoc.CodingKeys
+    public var order: TantivySortOrder
+
+    public init(field: TantivyDoc.CodingKeys, order: TantivySortOrder = .desc) {
+        self.field = field
+        self.order = order
+    }
+
+    func toSortField() -> TantivySortField {
+        return TantivySortField(field: field.stringValue, order: order)
+    }
+}
+
+public struct TantivySwiftSnippetField<TantivyDoc: TantivySearchableDocument & Sendable>: Sendable {
+    public var field: TantivyDoc.CodingKeys
+    public var maxNumChars: UInt32?
+
+    public init(field: TantivyDoc.CodingKeys, maxNumChars: UInt32? = nil) {
+        self.field = field
+        self.maxNumChars = maxNumChars
+    }
+
+    func toSnippetField() -> TantivySnippetField {
+        return TantivySnippetField(field: field.stringValue, maxNumChars: maxNumChars)
+    }
+}
+
 // search result struct
 // building this struct in Swift so it can use generics
 public struct TantivySearchResults<TantivyDoc: TantivySearchableDocument & Sendable>: Sendable {
     public let count: UInt64
     public let docs: [TantivySearchResult<TantivyDoc>]
+    /// Pass back as `cursor` to fetch the next page, `nil` on the last page.
+    public let nextCursor: String?
 }
 
 public struct TantivySearchResult<TantivyDoc: TantivySearchableDocument & Sendable>: Sendable {
     public let score: Float
     public let doc: TantivyDoc
+    public let sortValues: [FieldValue?]
+    public let snippets: [TantivySnippet]
+
+    public func snippet(for field: TantivyDoc.CodingKeys) -> TantivySnippet? {
+        return snippets.first { $0.field == field.stringValue }
+    }
+}
+
+public struct TantivyProjectedSearchResults: Sendable {
+    public let count: UInt64
+    public let docs: [TantivyProjectedSearchResult]
+    public let nextCursor: String?
+}
+
+public struct TantivyProjectedSearchResult: Sendable {
+    public let score: Float
+    public let fields: [DocumentField]
+    public let sortValues: [FieldValue?]
+}
+
+extension TantivySnippet {
+    /// Highlighted ranges converted from UTF-8 byte offsets into `fragment` indices.
+    public var highlightedRanges: [Range<String.Index>] {
+        let utf8 = fragment.utf8
+        return highlighted.compactMap { range in
+            guard
+                let lower = utf8.index(utf8.startIndex, offsetBy: Int(range.start), limitedBy: utf8.endIndex),
+                let upper = utf8.index(utf8.startIndex, offsetBy: Int(range.end), limitedBy: utf8.endIndex)
+            else {
+                return nil
+            }
+            return lower..<upper
+        }
+    }
+
+    @available(macOS 12, iOS 15, *)
+    public func attributedFragment(highlight: AttributeContainer) -> AttributedString {
+        var attributed = AttributedString(fragment)
+        for range in highlightedRanges {
+            guard let attributedRange = Range(range, in: attributed) else { continue }
+            attributed[attributedRange].mergeAttributes(highlight)
+        }
+        return attributed
+    }
+}
+
+extension TantivyToken {
+    /// The token's range in the analyzed `text`, from its UTF-16 offsets.
+    public func range(in text: String) -> Range<String.Index>? {
+        let utf16 = text.utf16
+        guard
+            let lower = utf16.index(utf16.startIndex, offsetBy: Int(utf16Start), limitedBy: utf16.endIndex),
+            let upper = utf16.index(utf16.startIndex, offsetBy: Int(utf16End), limitedBy: utf16.endIndex)
+        else {
+            return nil
+        }
+        return lower..<upper
+    }
 }
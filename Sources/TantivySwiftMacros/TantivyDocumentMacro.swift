@@ -64,7 +64,7 @@ public struct TantivyDocumentMacro: MemberMacro, ExtensionMacro {
                 if let attr = attribute.as(AttributeSyntax.self),
                    let attrName = attr.attributeName.as(IdentifierTypeSyntax.self) {
                     let name = attrName.name.text
-                    if ["IDField", "TextField", "U64Field", "I64Field", "F64Field", "BoolField", "DateField", "BytesField", "FacetField", "JsonField"].contains(name) {
+                    if ["IDField", "TextField", "U64Field", "I64Field", "F64Field", "BoolField", "DateField", "BytesField", "FacetField", "IpAddrField", "JsonField"].contains(name) {
                         wrapperType = name
                         break
                     }
@@ -221,6 +221,18 @@ public struct TantivyDocumentMacro: MemberMacro, ExtensionMacro {
             }
             return "_\(name) = \(wrapper)(wrappedValue: map.facet(\"\(name)\") ?? \"\")"
 
+        case "IpAddrField":
+            if isStringArray {
+                if isOptional {
+                    return "_\(name) = \(wrapper)(wrappedValue: { let values = map.ipAddrs(\"\(name)\"); return values.isEmpty ? nil : values }())"
+                }
+                return "_\(name) = \(wrapper)(wrappedValue: map.ipAddrs(\"\(name)\"))"
+            }
+            if isOptional {
+                return "_\(name) = \(wrapper)(wrappedValue: map.ipAddr(\"\(name)\"))"
+            }
+            return "_\(name) = \(wrapper)(wrappedValue: map.ipAddr(\"\(name)\") ?? \"\")"
+
         case "JsonField":
             if isArray {
                 let elementType = arrayElementType(type) ?? "String"
@@ -419,6 +431,13 @@ public struct TantivyDocumentMacro: MemberMacro, ExtensionMacro {
             return "for value in \(name) { fields.append(DocumentField(name: \"\(name)\", value: .facet(value))) }"
         }
 
+        if wrapper == "IpAddrField", isStringArray {
+            if isOptional {
+                return "if let values = \(name) { for value in values { fields.append(DocumentField(name: \"\(name)\", value: .ipAddr(value))) } }"
+            }
+            return "for value in \(name) { fields.append(DocumentField(name: \"\(name)\", value: .ipAddr(value))) }"
+        }
+
         let valueExpr: String
         switch wrapper {
         case "IDField", "TextField":
@@ -437,6 +456,8 @@ public struct TantivyDocumentMacro: MemberMacro, ExtensionMacro {
             valueExpr = ".bytes(\(valueName))"
         case "FacetField":
             valueExpr = ".facet(String(describing: \(valueName)))"
+        case "IpAddrField":
+            valueExpr = ".ipAddr(String(describing: \(valueName)))"
         default:
             valueExpr = ".text(String(describing: \(valueName)))"
         }
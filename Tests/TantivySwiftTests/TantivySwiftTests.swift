@@ -93,6 +93,91 @@ struct MultiValueAllDoc: Sendable {
     }
 }
 
+@TantivyDocument
+struct ConnectionDoc: Sendable {
+    @IDField var id: String
+    @IpAddrField(indexed: true, stored: true, fast: true, fieldnorms: false)
+    var remote: String
+    @IpAddrField var hops: [String] = []
+
+    init(id: String, remote: String, hops: [String]) {
+        self.id = id
+        self.remote = remote
+        self.hops = hops
+    }
+}
+
+struct ProductMeta: Codable, Sendable, Equatable {
+    var country: String
+    var stock: Int
+}
+
+@TantivyDocument
+struct ProductDoc: Sendable {
+    @IDField var id: String
+    @JsonField(stored: true, indexed: true, fast: true, tokenizer: .raw)
+    var metadata: ProductMeta
+
+    init(id: String, metadata: ProductMeta) {
+        self.id = id
+        self.metadata = metadata
+    }
+}
+
+@TantivyDocument
+struct NoteDoc: Sendable {
+    @IDField var id: String
+    @TextField(tokenizer: .custom(name: "french"))
+    var note: String
+
+    init(id: String, note: String) {
+        self.id = id
+        self.note = note
+    }
+}
+
+@TantivyDocument
+struct MerchantDoc: Sendable {
+    @IDField var id: String
+    @TextField(tokenizer: .ngram(minGram: 2, maxGram: 10, prefixOnly: true))
+    var name: String
+
+    init(id: String, name: String) {
+        self.id = id
+        self.name = name
+    }
+}
+
+@TantivyDocument
+struct StoreDoc: Sendable {
+    @IDField var id: String
+    @TextField var store: String
+    @TextField var country: String
+
+    init(id: String, store: String, country: String) {
+        self.id = id
+        self.store = store
+        self.country = country
+    }
+}
+
+private final class ProgressReports: @unchecked Sendable {
+    private let lock = NSLock()
+    private var reported: [UInt64] = []
+
+    var values: [UInt64] {
+        lock.lock()
+        defer { lock.unlock() }
+        return reported
+    }
+
+    func append(_ migrated: UInt64) {
+        lock.lock()
+        defer { lock.unlock() }
+        reported.append(migrated)
+    }
+}
+
 private func makeIndex(_ name: String) throws -> TantivySwiftIndex<UnifiedDoc> {
     let indexPath = "./test_data/\(name)"
     let fileManager = FileManager.default
@@ -102,6 +187,28 @@ private func makeIndex(_ name: String) throws -> TantivySwiftIndex<UnifiedDoc> {
     return try TantivySwiftIndex<UnifiedDoc>(path: indexPath)
 }
 
+private func freshIndexPath(_ name: String) throws -> String {
+    let indexPath = "./test_data/\(name)"
+    let fileManager = FileManager.default
+    if fileManager.fileExists(atPath: indexPath) {
+        try fileManager.removeItem(atPath: indexPath)
+    }
+    return indexPath
+}
+
+private func makeIndex<Doc: TantivyDocument>(_ name: String, as _: Doc.Type) throws -> TantivySwiftIndex<Doc> {
+    return try TantivySwiftIndex<Doc>(path: freshIndexPath(name))
+}
+
+private func makeSearchIndex(_ name: String) async throws -> TantivySwiftIndex<UnifiedDoc> {
+    let index = try makeIndex(name)
+    try await index.index(docs: [
+        UnifiedDoc(id: "1", title: "Swift and Rust", body: "Exploring search indexes", score: 10.0, isActive: true, category: "/tech", meta: ArticleMeta(source: "swift", rating: 5)),
+        UnifiedDoc(id: "2", title: "Cooking Pasta", body: "Simple recipes", score: 6.0, isActive: false, category: "/food", meta: ArticleMeta(source: "kitchen", rating: 4)),
+    ])
+    return index
+}
+
 private func makeMultiValueIndex(_ name: String) throws -> TantivySwiftIndex<MultiValueDoc> {
     let indexPath = "./test_data/\(name)"
     let fileManager = FileManager.default
@@ -183,6 +290,52 @@ private func makeMultiValueAllIndex(_ name: String) throws -> TantivySwiftIndex<
         #expect(await index.count() == 0)
     }
 
+    @Test func upsertReplacesDocument() async throws {
+        let index = try makeIndex("unified_index_upsert")
+        try await index.clear()
+
+        let original = UnifiedDoc(id: "1", title: "Draft", body: "Old body", score: 1.0, isActive: false, category: "/drafts", meta: ArticleMeta(source: "draft", rating: 1))
+        try await index.index(doc: original)
+
+        let updated = UnifiedDoc(id: "1", title: "Published", body: "New body", score: 2.0, isActive: true, category: "/posts", meta: ArticleMeta(source: "final", rating: 5))
+        try await index.upsert(doc: updated, idField: .id)
+        #expect(await index.count() == 1)
+
+        let idField = DocumentField(field: UnifiedDoc.CodingKeys.id, value: .text("1"))
+        let retrieved = try await index.getDoc(id: idField)
+        #expect(retrieved?.title == "Published")
+
+        let another = UnifiedDoc(id: "2", title: "Second", body: "Another body", score: 3.0, isActive: true, category: "/posts", meta: ArticleMeta(source: "final", rating: 3))
+        try await index.upsert(docs: [updated, another], idField: .id)
+        #expect(await index.count() == 2)
+    }
+
+    @Test func batchedDeletesWaitForCommit() async throws {
+        let index = try makeIndex("unified_index_batched_deletes")
+        try await index.clear()
+
+        let docs = [
+            UnifiedDoc(id: "a1", title: "Alpha", body: "First letter", score: 1.0, isActive: true, category: "/letters", meta: ArticleMeta(source: "alpha", rating: 1)),
+            UnifiedDoc(id: "b2", title: "Beta", body: "Second letter", score: 2.0, isActive: false, category: "/letters", meta: ArticleMeta(source: "beta", rating: 2)),
+            UnifiedDoc(id: "c3", title: "Charlie", body: "Third letter", score: 3.0, isActive: true, category: "/numbers", meta: ArticleMeta(source: "charlie", rating: 3)),
+        ]
+        try await index.index(docs: docs)
+
+        let matchedIds = try await index.deleteDocs(ids: [
+            DocumentField(field: UnifiedDoc.CodingKeys.id, value: .text("a1")),
+        ])
+        #expect(matchedIds == 1)
+
+        let matchedQuery = try await index.deleteDocs(
+            matching: .term(TantivyQueryTerm(name: "category", value: .facet("/numbers")))
+        )
+        #expect(matchedQuery == 1)
+        #expect(await index.count() == 3)
+
+        try await index.commit()
+        #expect(await index.count() == 1)
+    }
+
     @Test func bulkIndexAndGetDocs() async throws {
         let index = try makeIndex("unified_index_bulk")
         try await index.clear()
@@ -265,6 +418,185 @@ private func makeMultiValueAllIndex(_ name: String) throws -> TantivySwiftIndex<
         #expect(results2.docs.first?.doc.id == "2")
     }
 
+    @Test func sortByFastField() async throws {
+        let index = try await makeSearchIndex("unified_index_sort")
+
+        let sortedQuery = TantivySwiftSearchQuery<UnifiedDoc>(
+            queryStr: "*",
+            defaultFields: [.title, .body],
+            sort: [TantivySwiftSortField(field: .score, order: .asc)]
+        )
+        let sorted = try await index.search(query: sortedQuery)
+        #expect(sorted.docs.map { $0.doc.id } == ["2", "1"])
+        if case let .f64(value)? = sorted.docs.first?.sortValues.first {
+            #expect(value == 6.0)
+        } else {
+            Issue.record("Expected f64 sort value")
+        }
+    }
+
+    @Test func highlightedSnippets() async throws {
+        let index = try await makeSearchIndex("unified_index_snippets")
+
+        let snippetQuery = TantivySwiftSearchQuery<UnifiedDoc>(
+            queryStr: "pasta",
+            defaultFields: [.title, .body],
+            snippetFields: [TantivySwiftSnippetField(field: .title)]
+        )
+        let snippetResults = try await index.search(query: snippetQuery)
+        let snippet = try #require(snippetResults.docs.first?.snippet(for: .title))
+        #expect(snippet.html == "Cooking <b>Pasta</b>")
+        #expect(snippet.highlightedRanges.map { String(snippet.fragment[$0]) } == ["Pasta"])
+    }
+
+    @Test func cursorPagination() async throws {
+        let index = try await makeSearchIndex("unified_index_cursor")
+
+        var pagedQuery = TantivySwiftSearchQuery<UnifiedDoc>(
+            queryStr: "*",
+            defaultFields: [.title, .body],
+            limit: 1,
+            sort: [TantivySwiftSortField(field: .score)]
+        )
+        let firstPage = try await index.search(query: pagedQuery)
+        #expect(firstPage.docs.map { $0.doc.id } == ["1"])
+        pagedQuery.cursor = try #require(firstPage.nextCursor)
+        let secondPage = try await index.search(query: pagedQuery)
+        #expect(secondPage.docs.map { $0.doc.id } == ["2"])
+    }
+
+    @Test func strictQueryValidation() async throws {
+        let index = try await makeSearchIndex("unified_index_validation")
+
+        var typoQuery = TantivySwiftSearchQuery<UnifiedDoc>(queryStr: "title:(swift", defaultFields: [.title])
+        let validation = try await index.validate(query: typoQuery)
+        #expect(validation.errors.contains { $0.kind == .syntax && $0.position != nil })
+        typoQuery.strict = true
+        await #expect(throws: TantivyIndexError.self) {
+            try await index.search(query: typoQuery)
+        }
+    }
+
+    @Test func explainScore() async throws {
+        let index = try await makeSearchIndex("unified_index_explain")
+        let textQuery = TantivyQuery.queryString(
+            TantivyQueryString(query: "swift", defaultFields: ["title", "body"])
+        )
+
+        let explanation = try await index.explain(
+            query: .boost(query: textQuery, boost: 2.0),
+            id: DocumentField(field: UnifiedDoc.CodingKeys.id, value: .text("1"))
+        )
+        #expect(explanation.value > 0)
+        #expect(explanation.children.count == 1)
+    }
+
+    @Test func fieldProjection() async throws {
+        let index = try await makeSearchIndex("unified_index_projection")
+        let textQuery = TantivyQuery.queryString(
+            TantivyQueryString(query: "swift", defaultFields: ["title", "body"])
+        )
+
+        let projected = try await index.search(query: textQuery, fields: .include(fields: ["title"]))
+        #expect(projected.docs.first?.fields.map { $0.name } == ["title"])
+        let idsOnly = try await index.search(query: textQuery, fields: .idOnly(idField: "id"))
+        #expect(idsOnly.docs.first?.fields.first?.value == .text("1"))
+    }
+
+    @Test func searchIdsByTextField() async throws {
+        let index = try await makeSearchIndex("unified_index_search_ids")
+        let textQuery = TantivyQuery.queryString(
+            TantivyQueryString(query: "swift", defaultFields: ["title", "body"])
+        )
+
+        let ids = try await index.searchIds(query: textQuery, idField: .id)
+        #expect(ids.count == 1)
+        #expect(ids.hits.first?.id == .text("1"))
+    }
+
+    @Test func analyzedMatchQueries() async throws {
+        let index = try await makeSearchIndex("unified_index_match")
+
+        let phrase = try await index.search(query: .matchPhrase(field: "title", query: "COOKING pasta"))
+        #expect(phrase.docs.map { $0.doc.id } == ["2"])
+        let allTerms = try await index.search(query: .match(field: "title", query: "cooking swift", operator: .and))
+        #expect(allTerms.count == 0)
+    }
+
+    @Test func prefixSuggestions() async throws {
+        let index = try await makeSearchIndex("unified_index_suggest")
+
+        let suggestions = try await index.suggest(field: .title, prefix: "Co")
+        #expect(suggestions.map { $0.term } == ["cooking"])
+    }
+
+    @Test func spellingSuggestions() async throws {
+        let index = try await makeSearchIndex("unified_index_spelling")
+
+        let spelling = try await index.spellSuggest(field: .title, text: "Cookng psta")
+        #expect(spelling.corrected == "cooking pasta")
+        #expect(spelling.tokens.first?.found == false)
+    }
+
+    @Test func termVocabularyAndStats() async throws {
+        let index = try await makeSearchIndex("unified_index_terms")
+
+        let vocabulary = try await index.listTerms(field: .title, prefix: "c")
+        #expect(vocabulary.terms.map { $0.term } == ["cooking"])
+        let pasta = try await index.termStats(field: .title, term: "pasta")
+        #expect(pasta.docFreq == 1)
+        #expect(pasta.totalTermFreq == 1)
+    }
+
+    @Test func analyzeText() async throws {
+        let index = try await makeSearchIndex("unified_index_analyze")
+
+        let title = "🍝 Café Straße"
+        let tokens = try await index.analyze(field: .title, text: title)
+        #expect(tokens.map { $0.text } == ["cafe", "strasse"])
+        #expect(tokens.last.flatMap { $0.range(in: title) }.map { String(title[$0]) } == "Straße")
+        let raw = try await index.analyze(tokenizer: "raw", text: title)
+        #expect(raw.count == 1)
+    }
+
+    @Test func aggregationsOverFastFields() async throws {
+        let index = try makeIndex("unified_index_aggregations")
+        try await index.clear()
+
+        let docs = [
+            UnifiedDoc(id: "a1", title: "Alpha", body: "First letter", score: 1.0, isActive: true, category: "/letters", meta: ArticleMeta(source: "alpha", rating: 1)),
+            UnifiedDoc(id: "b2", title: "Beta", body: "Second letter", score: 2.0, isActive: false, category: "/letters", meta: ArticleMeta(source: "beta", rating: 2)),
+            UnifiedDoc(id: "c3", title: "Charlie", body: "Third letter", score: 6.0, isActive: true, category: "/letters", meta: ArticleMeta(source: "charlie", rating: 3)),
+        ]
+        try await index.index(docs: docs)
+
+        let results = try await index.aggregate(
+            query: .term(TantivyQueryTerm(name: "isActive", value: .bool(true))),
+            aggregations: [
+                "scoreStats": .stats(field: "score"),
+                "scoreRanges": .range(field: "score", ranges: [
+                    TantivyAggregationRange(to: 5.0),
+                    TantivyAggregationRange(from: 5.0),
+                ]),
+            ]
+        )
+        #expect(results.count == 2)
+        #expect(results.aggregations.map { $0.name } == ["scoreRanges", "scoreStats"])
+
+        guard case let .buckets(buckets, _, _) = results.aggregations[0].value else {
+            Issue.record("Expected range buckets")
+            return
+        }
+        #expect(buckets.map { $0.docCount } == [1, 1])
+
+        guard case let .stats(count, sum, _, _, _) = results.aggregations[1].value else {
+            Issue.record("Expected stats")
+            return
+        }
+        #expect(count == 2)
+        #expect(sum == 7.0)
+    }
+
     @Test func multiValueTextAndFacetFields() async throws {
         let doc = MultiValueDoc(
             id: "receipt-1",
@@ -308,6 +640,11 @@ private func makeMultiValueAllIndex(_ name: String) throws -> TantivySwiftIndex<
         let facetResults = try await index.search(query: facetQuery, limit: 10, offset: 0)
         #expect(facetResults.count == 1)
         #expect(facetResults.docs.first?.doc.id == "receipt-1")
+
+        let counts = try await index.facetCounts(field: .receiptTagIds, roots: ["/receipt/tags"])
+        #expect(counts.first?.root == "/receipt/tags")
+        #expect(Set(counts.first?.children.map { $0.path } ?? []) == Set(doc.receiptTagIds))
+        #expect(counts.first?.children.allSatisfy { $0.count == 1 } == true)
     }
 
     @Test func multiValueAllSupportedFieldTypes() async throws {
@@ -431,4 +768,168 @@ private func makeMultiValueAllIndex(_ name: String) throws -> TantivySwiftIndex<
         )
         #expect(facetResults.count == 1)
     }
+
+    @Test func ipAddrFields() async throws {
+        let index = try makeIndex("ip_addr_fields", as: ConnectionDoc.self)
+        try await index.index(docs: [
+            ConnectionDoc(id: "c1", remote: "192.168.1.20", hops: ["10.0.0.1", "fe80::1"]),
+            ConnectionDoc(id: "c2", remote: "2001:db8::7", hops: []),
+        ])
+
+        let retrieved = try await index.getDoc(
+            id: DocumentField(field: ConnectionDoc.CodingKeys.id, value: .text("c1"))
+        )
+        #expect(retrieved?.remote == "192.168.1.20")
+        #expect(retrieved?.hops == ["10.0.0.1", "fe80::1"])
+
+        let term = try await index.search(
+            query: .term(TantivyQueryTerm(name: "hops", value: .ipAddr("fe80::1")))
+        )
+        #expect(term.docs.map { $0.doc.id } == ["c1"])
+
+        let cidr = try await index.search(query: .cidr(field: "remote", cidr: "2001:db8::/32"))
+        #expect(cidr.docs.map { $0.doc.id } == ["c2"])
+    }
+
+    @Test func jsonPathQueries() async throws {
+        let index = try makeIndex("json_path_queries", as: ProductDoc.self)
+        try await index.index(docs: [
+            ProductDoc(id: "p1", metadata: ProductMeta(country: "DE", stock: 12)),
+            ProductDoc(id: "p2", metadata: ProductMeta(country: "US", stock: 3)),
+        ])
+
+        let term = try await index.search(
+            query: .term(TantivyQueryTerm(name: "metadata.country", value: .text("US")))
+        )
+        #expect(term.docs.map { $0.doc.id } == ["p2"])
+
+        let range = try await index.search(
+            query: .range(field: "metadata.stock", lower: .i64(10), upper: nil, includeLower: true, includeUpper: false)
+        )
+        #expect(range.docs.map { $0.doc.id } == ["p1"])
+
+        let exists = try await index.search(query: .exists(field: "metadata.country"))
+        #expect(exists.count == 2)
+    }
+
+    @Test func customAnalyzers() async throws {
+        let indexPath = try freshIndexPath("custom_analyzers")
+        let french = TantivyAnalyzerBuilder(name: "french", tokenizer: .simple)
+        french.addFilter(filter: .lowercase)
+        french.addFilter(filter: .asciiFolding)
+        french.addFilter(filter: .stopWords(language: .french))
+        french.addFilter(filter: .stemmer(language: .french))
+
+        let index = try TantivySwiftIndex<NoteDoc>(path: indexPath, analyzers: [french])
+        try await index.index(docs: [NoteDoc(id: "n1", note: "Les Épiceries du quartier")])
+
+        let stemmed = try await index.search(
+            query: .match(field: "note", query: "le quartier epicerie", operator: .and)
+        )
+        #expect(stemmed.docs.map { $0.doc.id } == ["n1"])
+
+        // The analyzer is saved with the index, reopening does not need it again.
+        let reopened = try TantivySwiftIndex<NoteDoc>(path: indexPath)
+        let again = try await reopened.search(query: .match(field: "note", query: "épicerie"))
+        #expect(again.count == 1)
+    }
+
+    @Test func edgeNgramAutocomplete() async throws {
+        let index = try makeIndex("edge_ngram_autocomplete", as: MerchantDoc.self)
+        try await index.index(docs: [
+            MerchantDoc(id: "m1", name: "Starbucks"),
+            MerchantDoc(id: "m2", name: "Starlight Diner"),
+        ])
+
+        let typed = try await index.search(query: .match(field: "name", query: "Starb", operator: .and))
+        #expect(typed.docs.map { $0.doc.id } == ["m1"])
+
+        let shorter = try await index.search(query: .match(field: "name", query: "sta", operator: .and))
+        #expect(shorter.count == 2)
+    }
+
+    @Test func writerOptions() async throws {
+        let indexPath = try freshIndexPath("writer_options")
+        let index = try TantivySwiftIndex<MerchantDoc>(
+            path: indexPath,
+            options: TantivyIndexOptions(writerHeapBytes: 15_000_000, numThreads: 1)
+        )
+        try await index.index(doc: MerchantDoc(id: "m1", name: "Starbucks"))
+
+        let searchOnly = try TantivySwiftIndex<MerchantDoc>(path: indexPath, options: TantivyIndexOptions(writable: false))
+        #expect(await searchOnly.count() == 1)
+        await #expect(throws: TantivyIndexError.self) {
+            try await searchOnly.index(doc: MerchantDoc(id: "m2", name: "Target"))
+        }
+
+        let extensionIndex = try TantivySwiftIndex<MerchantDoc>.openReadOnly(path: indexPath)
+        let found = try await extensionIndex.search(query: .match(field: "name", query: "star", operator: .and))
+        #expect(found.docs.map { $0.doc.id } == ["m1"])
+        await #expect(throws: TantivyIndexError.self) {
+            try await extensionIndex.clear()
+        }
+    }
+
+    @Test func schemaDrift() async throws {
+        let indexPath = try freshIndexPath("schema_drift")
+        let merchants = try TantivySwiftIndex<MerchantDoc>(path: indexPath)
+        try await merchants.index(doc: MerchantDoc(id: "m1", name: "Starbucks"))
+
+        let unchanged = try TantivySwiftIndex<MerchantDoc>.schemaDiff(path: indexPath)
+        #expect(unchanged.added.isEmpty && unchanged.removed.isEmpty && unchanged.changed.isEmpty)
+        let diff = try TantivySwiftIndex<ConnectionDoc>.schemaDiff(path: indexPath)
+        #expect(diff.added == ["remote", "hops"])
+        #expect(diff.removed == ["name"])
+        #expect(throws: TantivyIndexError.self) {
+            try TantivySwiftIndex<ConnectionDoc>(path: indexPath, options: TantivyIndexOptions(writable: false))
+        }
+
+        let reopened = try TantivySwiftIndex<MerchantDoc>.open(path: indexPath)
+        #expect(await reopened.count() == 1)
+    }
+
+    @Test func schemaIntrospection() async throws {
+        let merchants = try makeIndex("schema_introspection", as: MerchantDoc.self)
+        let fields = await merchants.schema()
+        #expect(fields.map(\.name) == ["id", "name"])
+        #expect(fields[0].tokenizer == .raw)
+        #expect(fields[1].tokenizer == .ngram(minGram: 2, maxGram: 10, prefixOnly: true))
+
+        let json = try TantivySchemaExtractor.buildSchema(for: MerchantDoc.self).toJson()
+        let builder = try TantivySchemaBuilder.fromJson(json: json)
+        #expect(builder.fields() == fields)
+        #expect(throws: TantivyIndexError.self) {
+            try TantivySchemaBuilder.fromJson(json: "{}")
+        }
+    }
+
+    @Test func migrateToNewSchema() async throws {
+        let indexPath = try freshIndexPath("migrate")
+        do {
+            let merchants = try TantivySwiftIndex<MerchantDoc>(path: indexPath)
+            try await merchants.index(docs: [
+                MerchantDoc(id: "m1", name: "Starbucks"),
+                MerchantDoc(id: "m2", name: "Starlight Diner"),
+            ])
+        }
+
+        let reports = ProgressReports()
+        let stores = try TantivySwiftIndex<StoreDoc>.migrate(
+            path: indexPath,
+            mappings: [
+                .rename(from: "name", to: "store"),
+                .default(field: "country", value: .text("US")),
+            ],
+            progress: { migrated, _ in reports.append(migrated) }
+        )
+        #expect(reports.values == [2])
+        #expect(await stores.count() == 2)
+        let store = try await stores.getDoc(id: DocumentField(field: StoreDoc.CodingKeys.id, value: .text("m1")))
+        #expect(store?.store == "Starbucks")
+        #expect(store?.country == "US")
+
+        #expect(throws: TantivyIndexError.self) {
+            try TantivySwiftIndex<MerchantDoc>.migrate(path: indexPath)
+        }
+    }
 }
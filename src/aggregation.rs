@@ -0,0 +1,365 @@
+use tantivy::aggregation::agg_req::Aggregations;
+use tantivy::aggregation::agg_result::{
+    AggregationResult, AggregationResults, BucketEntries, BucketEntry, BucketResult, MetricResult,
+    RangeBucketEntry,
+};
+use tantivy::aggregation::metric::PercentileValues;
+use tantivy::aggregation::{AggregationCollector, AggregationLimitsGuard, Key};
+use tantivy::collector::Count;
+
+use crate::{TantivyIndex, TantivyIndexError, TantivyQueryDsl};
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum TantivyAggregationKey {
+    Str(String),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+}
+
+impl From<Key> for TantivyAggregationKey {
+    fn from(key: Key) -> Self {
+        match key {
+            Key::Str(text) => TantivyAggregationKey::Str(text),
+            Key::I64(val) => TantivyAggregationKey::I64(val),
+            Key::U64(val) => TantivyAggregationKey::U64(val),
+            Key::F64(val) => TantivyAggregationKey::F64(val),
+        }
+    }
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyAggregationBucket {
+    pub key: TantivyAggregationKey,
+    /// Formatted key, e.g. RFC 3339 for date histograms.
+    pub key_as_string: Option<String>,
+    pub doc_count: u64,
+    /// Lower bound of a range bucket.
+    pub from: Option<f64>,
+    /// Upper bound of a range bucket.
+    pub to: Option<f64>,
+    pub sub_aggregations: Vec<TantivyAggregationResult>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyPercentile {
+    pub percent: f64,
+    pub value: Option<f64>,
+}
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum TantivyAggregationValue {
+    Buckets {
+        buckets: Vec<TantivyAggregationBucket>,
+        /// Only set for terms aggregations.
+        sum_other_doc_count: Option<u64>,
+        doc_count_error_upper_bound: Option<u64>,
+    },
+    /// Result of avg, min, max, sum, count and cardinality aggregations.
+    Single {
+        value: Option<f64>,
+    },
+    Stats {
+        count: u64,
+        sum: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+        avg: Option<f64>,
+    },
+    Percentiles {
+        values: Vec<TantivyPercentile>,
+    },
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyAggregationResult {
+    pub name: String,
+    pub value: TantivyAggregationValue,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyAggregationResults {
+    pub count: u64,
+    pub aggregations: Vec<TantivyAggregationResult>,
+}
+
+fn convert_results(
+    results: AggregationResults,
+) -> Result<Vec<TantivyAggregationResult>, TantivyIndexError> {
+    let mut converted = Vec::with_capacity(results.0.len());
+    for (name, result) in results.0 {
+        converted.push(TantivyAggregationResult {
+            name,
+            value: convert_result(result)?,
+        });
+    }
+    converted.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(converted)
+}
+
+fn convert_result(result: AggregationResult) -> Result<TantivyAggregationValue, TantivyIndexError> {
+    match result {
+        AggregationResult::BucketResult(bucket) => convert_bucket_result(bucket),
+        AggregationResult::MetricResult(metric) => convert_metric_result(metric),
+    }
+}
+
+fn convert_bucket_entry(entry: BucketEntry) -> Result<TantivyAggregationBucket, TantivyIndexError> {
+    Ok(TantivyAggregationBucket {
+        key: entry.key.into(),
+        key_as_string: entry.key_as_string,
+        doc_count: entry.doc_count,
+        from: None,
+        to: None,
+        sub_aggregations: convert_results(entry.sub_aggregation)?,
+    })
+}
+
+fn convert_range_entry(
+    entry: RangeBucketEntry,
+) -> Result<TantivyAggregationBucket, TantivyIndexError> {
+    Ok(TantivyAggregationBucket {
+        key: entry.key.into(),
+        key_as_string: None,
+        doc_count: entry.doc_count,
+        from: entry.from,
+        to: entry.to,
+        sub_aggregations: convert_results(entry.sub_aggregation)?,
+    })
+}
+
+fn entries_in_order<T>(entries: BucketEntries<T>, sort_key: impl Fn(&T) -> f64) -> Vec<T> {
+    match entries {
+        BucketEntries::Vec(entries) => entries,
+        BucketEntries::HashMap(map) => {
+            // Keyed responses lose their order, restore it so Swift gets a stable series.
+            let mut entries = map.into_values().collect::<Vec<_>>();
+            entries.sort_by(|a, b| sort_key(a).total_cmp(&sort_key(b)));
+            entries
+        }
+    }
+}
+
+fn key_as_f64(key: &Key) -> f64 {
+    match key {
+        Key::Str(_) => 0.0,
+        Key::I64(val) => *val as f64,
+        Key::U64(val) => *val as f64,
+        Key::F64(val) => *val,
+    }
+}
+
+fn convert_bucket_result(
+    bucket: BucketResult,
+) -> Result<TantivyAggregationValue, TantivyIndexError> {
+    match bucket {
+        BucketResult::Range { buckets } => {
+            let entries = entries_in_order(buckets, |entry| entry.from.unwrap_or(f64::MIN));
+            let mut converted = Vec::with_capacity(entries.len());
+            for entry in entries {
+                converted.push(convert_range_entry(entry)?);
+            }
+            Ok(TantivyAggregationValue::Buckets {
+                buckets: converted,
+                sum_other_doc_count: None,
+                doc_count_error_upper_bound: None,
+            })
+        }
+        BucketResult::Histogram { buckets } => {
+            let entries = entries_in_order(buckets, |entry| key_as_f64(&entry.key));
+            let mut converted = Vec::with_capacity(entries.len());
+            for entry in entries {
+                converted.push(convert_bucket_entry(entry)?);
+            }
+            Ok(TantivyAggregationValue::Buckets {
+                buckets: converted,
+                sum_other_doc_count: None,
+                doc_count_error_upper_bound: None,
+            })
+        }
+        BucketResult::Terms {
+            buckets,
+            sum_other_doc_count,
+            doc_count_error_upper_bound,
+        } => {
+            let mut converted = Vec::with_capacity(buckets.len());
+            for entry in buckets {
+                converted.push(convert_bucket_entry(entry)?);
+            }
+            Ok(TantivyAggregationValue::Buckets {
+                buckets: converted,
+                sum_other_doc_count: Some(sum_other_doc_count),
+                doc_count_error_upper_bound,
+            })
+        }
+    }
+}
+
+fn convert_percentiles(
+    values: PercentileValues,
+) -> Result<Vec<TantivyPercentile>, TantivyIndexError> {
+    let mut percentiles = match values {
+        PercentileValues::HashMap(map) => {
+            let mut percentiles = Vec::with_capacity(map.len());
+            for (percent, value) in map {
+                let percent = percent.parse::<f64>().map_err(|_| {
+                    TantivyIndexError::QueryError(format!("Invalid percentile key: {percent}"))
+                })?;
+                percentiles.push(TantivyPercentile {
+                    percent,
+                    value: value.is_finite().then_some(value),
+                });
+            }
+            percentiles
+        }
+        PercentileValues::Vec(entries) => {
+            // Entry fields are private upstream, go through their serialized form.
+            let entries: Vec<serde_json::Value> =
+                serde_json::from_value(serde_json::to_value(entries)?)?;
+            entries
+                .into_iter()
+                .map(|entry| {
+                    let percent = entry["key"].as_f64().ok_or_else(|| {
+                        TantivyIndexError::QueryError(format!("Invalid percentile key: {entry}"))
+                    })?;
+                    Ok(TantivyPercentile {
+                        percent,
+                        value: entry["value"].as_f64(),
+                    })
+                })
+                .collect::<Result<_, TantivyIndexError>>()?
+        }
+    };
+    percentiles.sort_by(|a, b| a.percent.total_cmp(&b.percent));
+    Ok(percentiles)
+}
+
+fn convert_metric_result(
+    metric: MetricResult,
+) -> Result<TantivyAggregationValue, TantivyIndexError> {
+    match metric {
+        MetricResult::Average(single)
+        | MetricResult::Count(single)
+        | MetricResult::Max(single)
+        | MetricResult::Min(single)
+        | MetricResult::Sum(single)
+        | MetricResult::Cardinality(single) => Ok(TantivyAggregationValue::Single {
+            value: single.value,
+        }),
+        MetricResult::Stats(stats) => Ok(TantivyAggregationValue::Stats {
+            count: stats.count,
+            sum: stats.sum,
+            min: stats.min,
+            max: stats.max,
+            avg: stats.avg,
+        }),
+        MetricResult::ExtendedStats(stats) => Ok(TantivyAggregationValue::Stats {
+            count: stats.count,
+            sum: stats.sum,
+            min: stats.min,
+            max: stats.max,
+            avg: stats.avg,
+        }),
+        MetricResult::Percentiles(percentiles) => Ok(TantivyAggregationValue::Percentiles {
+            values: convert_percentiles(percentiles.values)?,
+        }),
+        MetricResult::TopHits(_) => Err(TantivyIndexError::QueryError(
+            "top_hits aggregations are not supported".to_string(),
+        )),
+    }
+}
+
+#[uniffi::export]
+impl TantivyIndex {
+    /// Runs Elasticsearch-style aggregations over the documents matching the query DSL.
+    ///
+    /// Aggregated fields must be fast fields.
+    #[uniffi::method]
+    fn aggregate(
+        &self,
+        query_json: String,
+        aggregations_json: String,
+    ) -> Result<TantivyAggregationResults, TantivyIndexError> {
+        let schema = self.index.schema();
+        let query_dsl: TantivyQueryDsl = serde_json::from_str(&query_json)?;
+        let query = query_dsl.to_query(&self.index, &schema)?;
+        let aggregations: Aggregations = serde_json::from_str(&aggregations_json)?;
+
+        let collector =
+            AggregationCollector::from_aggs(aggregations, AggregationLimitsGuard::default());
+
+        let searcher = self.reader.searcher();
+        let (doc_count, results) = searcher.search(&query, &(Count, collector))?;
+
+        Ok(TantivyAggregationResults {
+            count: doc_count as u64,
+            aggregations: convert_results(results)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{make_index, receipt};
+
+    use super::*;
+
+    #[test]
+    fn aggregate_terms_range_and_stats() {
+        let index = make_index("aggregate");
+        index
+            .index_docs(vec![
+                receipt("r1", "costco", 10.0),
+                receipt("r2", "costco", 30.0),
+                receipt("r3", "target", 50.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let aggregations = r#"{
+            "by_total": {
+                "range": { "field": "total", "ranges": [{ "to": 20.0 }, { "from": 20.0 }] },
+                "aggs": { "total_stats": { "stats": { "field": "total" } } }
+            },
+            "spend": { "sum": { "field": "total" } },
+            "p50": { "percentiles": { "field": "total", "percents": [50.0] } }
+        }"#;
+        let results = index
+            .aggregate(r#"{"type":"all"}"#.to_string(), aggregations.to_string())
+            .unwrap();
+
+        assert_eq!(results.count, 3);
+        let names = results
+            .aggregations
+            .iter()
+            .map(|agg| agg.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["by_total", "p50", "spend"]);
+
+        let TantivyAggregationValue::Buckets { buckets, .. } = &results.aggregations[0].value
+        else {
+            panic!("expected range buckets");
+        };
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].doc_count, 1);
+        assert_eq!(buckets[0].to, Some(20.0));
+        assert_eq!(buckets[1].doc_count, 2);
+        let TantivyAggregationValue::Stats { count, sum, .. } =
+            &buckets[1].sub_aggregations[0].value
+        else {
+            panic!("expected stats sub aggregation");
+        };
+        assert_eq!((*count, *sum), (2, 80.0));
+
+        let TantivyAggregationValue::Percentiles { values } = &results.aggregations[1].value else {
+            panic!("expected percentiles");
+        };
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].percent, 50.0);
+
+        let TantivyAggregationValue::Single { value } = &results.aggregations[2].value else {
+            panic!("expected sum");
+        };
+        assert_eq!(*value, Some(90.0));
+    }
+}
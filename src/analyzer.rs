@@ -0,0 +1,695 @@
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tantivy::Index;
+use tantivy::schema::FieldType;
+use tantivy::tokenizer::{
+    AsciiFoldingFilter, Language, LowerCaser, NgramTokenizer, RawTokenizer, RemoveLongFilter,
+    SimpleTokenizer, Stemmer, StopWordFilter, TextAnalyzer, TextAnalyzerBuilder,
+    WhitespaceTokenizer,
+};
+
+use crate::unicode_tokenizer::UnicodeTokenizer;
+use crate::{TantivyIndex, TantivyIndexError};
+
+/// A token as produced by an analyzer. Offsets point into the analyzed text, in
+/// UTF-8 bytes for Rust and UTF-16 code units for `NSString`/`String.UTF16View`.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct TantivyToken {
+    pub text: String,
+    pub position: u64,
+    pub byte_start: u64,
+    pub byte_end: u64,
+    pub utf16_start: u64,
+    pub utf16_end: u64,
+}
+
+/// Analyzer definitions are not part of tantivy's meta.json, they are kept next to it
+/// so that reopening the index registers them again.
+pub(crate) const ANALYZERS_FILE: &str = "analyzers.json";
+
+/// Tokenizer names tantivy or this crate register on every index.
+const BUILT_IN_TOKENIZERS: &[&str] = &["raw", "default", "en_stem", "whitespace", "unicode"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TantivyBaseTokenizer {
+    /// Unicode word boundaries, the tokenizer behind the `unicode` analyzer.
+    Unicode,
+    /// Splits on anything that is not alphanumeric.
+    Simple,
+    Whitespace,
+    /// The whole text as a single token.
+    Raw,
+    /// Every n-gram of `min_gram..=max_gram` characters, or only the prefixes.
+    Ngram {
+        min_gram: u32,
+        max_gram: u32,
+        prefix_only: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "snake_case")]
+pub enum TantivyLanguage {
+    Arabic,
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Greek,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Tamil,
+    Turkish,
+}
+
+impl From<TantivyLanguage> for Language {
+    fn from(language: TantivyLanguage) -> Self {
+        match language {
+            TantivyLanguage::Arabic => Language::Arabic,
+            TantivyLanguage::Danish => Language::Danish,
+            TantivyLanguage::Dutch => Language::Dutch,
+            TantivyLanguage::English => Language::English,
+            TantivyLanguage::Finnish => Language::Finnish,
+            TantivyLanguage::French => Language::French,
+            TantivyLanguage::German => Language::German,
+            TantivyLanguage::Greek => Language::Greek,
+            TantivyLanguage::Hungarian => Language::Hungarian,
+            TantivyLanguage::Italian => Language::Italian,
+            TantivyLanguage::Norwegian => Language::Norwegian,
+            TantivyLanguage::Portuguese => Language::Portuguese,
+            TantivyLanguage::Romanian => Language::Romanian,
+            TantivyLanguage::Russian => Language::Russian,
+            TantivyLanguage::Spanish => Language::Spanish,
+            TantivyLanguage::Swedish => Language::Swedish,
+            TantivyLanguage::Tamil => Language::Tamil,
+            TantivyLanguage::Turkish => Language::Turkish,
+        }
+    }
+}
+
+/// Token filters, applied in the order they are added.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TantivyTokenFilter {
+    Lowercase,
+    AsciiFolding,
+    Stemmer {
+        language: TantivyLanguage,
+    },
+    /// Built-in stop word list, not every stemmer language has one.
+    StopWords {
+        language: TantivyLanguage,
+    },
+    CustomStopWords {
+        words: Vec<String>,
+    },
+    /// Drops tokens of `max_bytes` UTF-8 bytes or more.
+    RemoveLong {
+        max_bytes: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TantivyAnalyzerDefinition {
+    name: String,
+    tokenizer: TantivyBaseTokenizer,
+    filters: Vec<TantivyTokenFilter>,
+}
+
+/// Defines a named analyzer, usable as `TantivyTokenizer::Custom` once added to
+/// the schema builder.
+#[derive(uniffi::Object)]
+pub struct TantivyAnalyzerBuilder {
+    definition: Mutex<TantivyAnalyzerDefinition>,
+}
+
+#[uniffi::export]
+impl TantivyAnalyzerBuilder {
+    #[uniffi::constructor]
+    pub fn new(name: String, tokenizer: TantivyBaseTokenizer) -> Self {
+        Self {
+            definition: Mutex::new(TantivyAnalyzerDefinition {
+                name,
+                tokenizer,
+                filters: Vec::new(),
+            }),
+        }
+    }
+
+    #[uniffi::method]
+    pub fn add_filter(&self, filter: TantivyTokenFilter) {
+        self.definition.lock().unwrap().filters.push(filter);
+    }
+}
+
+impl TantivyAnalyzerBuilder {
+    pub(crate) fn definition(&self) -> TantivyAnalyzerDefinition {
+        self.definition.lock().unwrap().clone()
+    }
+}
+
+fn ngram_tokenizer(
+    min_gram: u32,
+    max_gram: u32,
+    prefix_only: bool,
+) -> Result<NgramTokenizer, TantivyIndexError> {
+    Ok(NgramTokenizer::new(
+        min_gram.try_into()?,
+        max_gram.try_into()?,
+        prefix_only,
+    )?)
+}
+
+/// N-gram tokenizers are named after their settings, e.g. `edge_ngram_2_10`, so the
+/// schema alone tells which ones to register when an index is opened.
+pub(crate) fn ngram_tokenizer_name(min_gram: u32, max_gram: u32, prefix_only: bool) -> String {
+    let kind = if prefix_only { "edge_ngram" } else { "ngram" };
+    format!("{kind}_{min_gram}_{max_gram}")
+}
+
+pub(crate) fn parse_ngram_tokenizer_name(name: &str) -> Option<(u32, u32, bool)> {
+    let (grams, prefix_only) = match name.strip_prefix("edge_ngram_") {
+        Some(grams) => (grams, true),
+        None => (name.strip_prefix("ngram_")?, false),
+    };
+    let (min_gram, max_gram) = grams.split_once('_')?;
+    Some((min_gram.parse().ok()?, max_gram.parse().ok()?, prefix_only))
+}
+
+/// Registers the n-gram tokenizers the text and JSON fields of the schema are indexed
+/// or made fast with.
+pub(crate) fn register_ngram_tokenizers(index: &Index) -> Result<(), TantivyIndexError> {
+    let schema = index.schema();
+    for (_, field_entry) in schema.fields() {
+        let tokenizers = match field_entry.field_type() {
+            FieldType::Str(options) => [
+                options
+                    .get_indexing_options()
+                    .map(|indexing| indexing.tokenizer()),
+                options.get_fast_field_tokenizer_name(),
+            ],
+            FieldType::JsonObject(options) => [
+                options
+                    .get_text_indexing_options()
+                    .map(|indexing| indexing.tokenizer()),
+                options.get_fast_field_tokenizer_name(),
+            ],
+            _ => continue,
+        };
+        for name in tokenizers.into_iter().flatten() {
+            let Some((min_gram, max_gram, prefix_only)) = parse_ngram_tokenizer_name(name) else {
+                continue;
+            };
+            let analyzer = TextAnalyzer::builder(ngram_tokenizer(min_gram, max_gram, prefix_only)?)
+                .filter(LowerCaser)
+                .filter(AsciiFoldingFilter)
+                .build();
+            register_analyzer(index, name, analyzer);
+        }
+    }
+    Ok(())
+}
+
+/// Registers an analyzer for indexed text and for fast fields, which tantivy looks
+/// up in separate managers.
+pub(crate) fn register_analyzer(index: &Index, name: &str, analyzer: TextAnalyzer) {
+    index
+        .fast_field_tokenizer()
+        .register(name, analyzer.clone());
+    index.tokenizers().register(name, analyzer);
+}
+
+impl TantivyAnalyzerDefinition {
+    fn build(&self) -> Result<TextAnalyzer, TantivyIndexError> {
+        if BUILT_IN_TOKENIZERS.contains(&self.name.as_str())
+            || parse_ngram_tokenizer_name(&self.name).is_some()
+        {
+            return Err(TantivyIndexError::SchemaBuilderError(format!(
+                "Analyzer name {} is reserved for a built-in tokenizer",
+                self.name
+            )));
+        }
+
+        let mut builder: TextAnalyzerBuilder = match &self.tokenizer {
+            TantivyBaseTokenizer::Unicode => {
+                TextAnalyzer::builder(UnicodeTokenizer::default()).dynamic()
+            }
+            TantivyBaseTokenizer::Simple => {
+                TextAnalyzer::builder(SimpleTokenizer::default()).dynamic()
+            }
+            TantivyBaseTokenizer::Whitespace => {
+                TextAnalyzer::builder(WhitespaceTokenizer::default()).dynamic()
+            }
+            TantivyBaseTokenizer::Raw => TextAnalyzer::builder(RawTokenizer::default()).dynamic(),
+            TantivyBaseTokenizer::Ngram {
+                min_gram,
+                max_gram,
+                prefix_only,
+            } => TextAnalyzer::builder(ngram_tokenizer(*min_gram, *max_gram, *prefix_only)?)
+                .dynamic(),
+        };
+
+        for filter in &self.filters {
+            builder = match filter {
+                TantivyTokenFilter::Lowercase => builder.filter_dynamic(LowerCaser),
+                TantivyTokenFilter::AsciiFolding => builder.filter_dynamic(AsciiFoldingFilter),
+                TantivyTokenFilter::Stemmer { language } => {
+                    builder.filter_dynamic(Stemmer::new((*language).into()))
+                }
+                TantivyTokenFilter::StopWords { language } => {
+                    let filter = StopWordFilter::new((*language).into()).ok_or_else(|| {
+                        TantivyIndexError::SchemaBuilderError(format!(
+                            "No stop word list for {language:?}"
+                        ))
+                    })?;
+                    builder.filter_dynamic(filter)
+                }
+                TantivyTokenFilter::CustomStopWords { words } => {
+                    builder.filter_dynamic(StopWordFilter::remove(words.iter().cloned()))
+                }
+                TantivyTokenFilter::RemoveLong { max_bytes } => {
+                    builder.filter_dynamic(RemoveLongFilter::limit((*max_bytes).try_into()?))
+                }
+            };
+        }
+        Ok(builder.build())
+    }
+}
+
+/// Registers the analyzers saved with the index and the ones passed in.
+///
+/// With `save`, analyzers passed in are saved too, replacing saved ones of the same
+/// name. Handles without a writer leave the index directory untouched.
+pub(crate) fn register_analyzers(
+    index: &Index,
+    index_path: &Path,
+    analyzers: &[TantivyAnalyzerDefinition],
+    save: bool,
+) -> Result<(), TantivyIndexError> {
+    let analyzers_path = index_path.join(ANALYZERS_FILE);
+    let mut definitions: Vec<TantivyAnalyzerDefinition> = match std::fs::read(&analyzers_path) {
+        Ok(json) => serde_json::from_slice(&json)?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err.into()),
+    };
+    for analyzer in analyzers {
+        match definitions
+            .iter_mut()
+            .find(|definition| definition.name == analyzer.name)
+        {
+            Some(saved) => *saved = analyzer.clone(),
+            None => definitions.push(analyzer.clone()),
+        }
+    }
+
+    let built = definitions
+        .iter()
+        .map(|definition| Ok((definition.name.as_str(), definition.build()?)))
+        .collect::<Result<Vec<_>, TantivyIndexError>>()?;
+    if save && !analyzers.is_empty() {
+        let tmp_path = analyzers_path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec(&definitions)?)?;
+        std::fs::rename(&tmp_path, &analyzers_path)?;
+    }
+    for (name, analyzer) in built {
+        register_analyzer(index, name, analyzer);
+    }
+    Ok(())
+}
+
+/// Converts byte offsets to UTF-16 offsets, cheaply when they mostly increase.
+struct Utf16Offsets<'a> {
+    text: &'a str,
+    byte: usize,
+    utf16: usize,
+}
+
+impl Utf16Offsets<'_> {
+    fn of(&mut self, byte: usize) -> usize {
+        if byte < self.byte {
+            self.byte = 0;
+            self.utf16 = 0;
+        }
+        self.utf16 += self
+            .text
+            .get(self.byte..byte)
+            .map_or(0, |slice| slice.encode_utf16().count());
+        self.byte = byte;
+        self.utf16
+    }
+}
+
+#[uniffi::export]
+impl TantivyIndex {
+    /// Runs `text` through the analyzer of a field, or through a registered
+    /// tokenizer such as `unicode` when no field has that name.
+    #[uniffi::method]
+    fn analyze(
+        &self,
+        field_or_tokenizer: String,
+        text: String,
+    ) -> Result<Vec<TantivyToken>, TantivyIndexError> {
+        let mut analyzer = match self.index.schema().get_field(&field_or_tokenizer) {
+            Ok(field) => self.index.tokenizer_for_field(field)?,
+            Err(_) => self
+                .index
+                .tokenizers()
+                .get(&field_or_tokenizer)
+                .ok_or_else(|| {
+                    TantivyIndexError::QueryError(format!(
+                        "No field or tokenizer named {field_or_tokenizer}"
+                    ))
+                })?,
+        };
+
+        let mut offsets = Utf16Offsets {
+            text: &text,
+            byte: 0,
+            utf16: 0,
+        };
+        let mut tokens = Vec::new();
+        let mut token_stream = analyzer.token_stream(&text);
+        while let Some(token) = token_stream.next() {
+            tokens.push(TantivyToken {
+                text: token.text.clone(),
+                position: token.position.try_into()?,
+                byte_start: token.offset_from.try_into()?,
+                byte_end: token.offset_to.try_into()?,
+                utf16_start: offsets.of(token.offset_from).try_into()?,
+                utf16_end: offsets.of(token.offset_to).try_into()?,
+            });
+        }
+        Ok(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{make_index_with, temp_index_path};
+    use crate::{
+        DocumentField, FieldValue, JsonFieldOptions, TantivyDocumentFields, TantivyIndex,
+        TantivyIndexOptions, TantivyIndexRecordOption, TantivySchemaBuilder, TantivyTokenizer,
+        TextFieldOptions,
+    };
+
+    use super::*;
+
+    fn french_analyzer() -> TantivyAnalyzerBuilder {
+        let analyzer =
+            TantivyAnalyzerBuilder::new("french".to_string(), TantivyBaseTokenizer::Simple);
+        analyzer.add_filter(TantivyTokenFilter::Lowercase);
+        analyzer.add_filter(TantivyTokenFilter::AsciiFolding);
+        analyzer.add_filter(TantivyTokenFilter::StopWords {
+            language: TantivyLanguage::French,
+        });
+        analyzer.add_filter(TantivyTokenFilter::Stemmer {
+            language: TantivyLanguage::French,
+        });
+        analyzer
+    }
+
+    fn note_schema(analyzer: Option<&TantivyAnalyzerBuilder>) -> TantivySchemaBuilder {
+        let builder = TantivySchemaBuilder::new();
+        if let Some(analyzer) = analyzer {
+            builder.add_analyzer(analyzer);
+        }
+        builder.add_text_field(
+            "note".to_string(),
+            TextFieldOptions {
+                tokenizer: TantivyTokenizer::Custom {
+                    name: "french".to_string(),
+                },
+                record: TantivyIndexRecordOption::WithFreqsAndPositions,
+                ..TextFieldOptions::default()
+            },
+        );
+        builder
+    }
+
+    fn note_matches(index: &TantivyIndex, text: &str) -> u64 {
+        let query =
+            format!(r#"{{"type":"match","field":"note","query":"{text}","operator":"and"}}"#);
+        index
+            .search_dsl(query, 10, 0, vec![], vec![], None, None)
+            .unwrap()
+            .count
+    }
+
+    #[test]
+    fn custom_analyzers_stem_and_drop_stop_words() {
+        let path = temp_index_path("analyzer");
+
+        let index =
+            TantivyIndex::new_with_schema(path.clone(), &note_schema(Some(&french_analyzer())))
+                .unwrap();
+        index
+            .index_doc(TantivyDocumentFields {
+                fields: vec![DocumentField {
+                    name: "note".to_string(),
+                    value: FieldValue::Text("Les Épiceries du quartier".to_string()),
+                }],
+            })
+            .unwrap();
+        index.commit().unwrap();
+
+        assert_eq!(note_matches(&index, "epicerie"), 1);
+        assert_eq!(note_matches(&index, "le quartier"), 1);
+        drop(index);
+
+        // Reopening with a builder that lacks the analyzer uses the saved definition.
+        let reopened = TantivyIndex::new_with_schema(path.clone(), &note_schema(None)).unwrap();
+        assert_eq!(note_matches(&reopened, "épicerie"), 1);
+        drop(reopened);
+
+        // Passing another analyzer adds it to the saved ones.
+        let words =
+            TantivyAnalyzerBuilder::new("words".to_string(), TantivyBaseTokenizer::Whitespace);
+        let extended =
+            TantivyIndex::new_with_schema(path.clone(), &note_schema(Some(&words))).unwrap();
+        drop(extended);
+        let reopened = TantivyIndex::new_with_schema(path.clone(), &note_schema(None)).unwrap();
+        assert_eq!(note_matches(&reopened, "épicerie"), 1);
+        assert_eq!(
+            reopened
+                .analyze("words".to_string(), "Les Épiceries".to_string())
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // A read-only handle uses the analyzers passed in without saving them.
+        let shout = TantivyAnalyzerBuilder::new("shout".to_string(), TantivyBaseTokenizer::Raw);
+        let read_only = TantivyIndex::new_with_options(
+            path.clone(),
+            &note_schema(Some(&shout)),
+            TantivyIndexOptions {
+                writable: false,
+                ..TantivyIndexOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(
+            read_only
+                .analyze("shout".to_string(), "Hé".to_string())
+                .is_ok()
+        );
+        drop(read_only);
+        let read_only = TantivyIndex::open_read_only(path).unwrap();
+        assert!(
+            read_only
+                .analyze("shout".to_string(), "Hé".to_string())
+                .is_err()
+        );
+
+        let reserved =
+            TantivyAnalyzerBuilder::new("unicode".to_string(), TantivyBaseTokenizer::Raw);
+        assert!(
+            TantivyIndex::new_with_schema(
+                temp_index_path("analyzer_reserved"),
+                &note_schema(Some(&reserved))
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn json_fast_fields_use_custom_and_unicode_analyzers() {
+        let words =
+            TantivyAnalyzerBuilder::new("words".to_string(), TantivyBaseTokenizer::Whitespace);
+        words.add_filter(TantivyTokenFilter::Lowercase);
+        let builder = TantivySchemaBuilder::new();
+        builder.add_analyzer(&words);
+        for (name, tokenizer) in [
+            (
+                "labels",
+                TantivyTokenizer::Custom {
+                    name: "words".to_string(),
+                },
+            ),
+            ("origin", TantivyTokenizer::Unicode),
+        ] {
+            builder.add_json_field(
+                name.to_string(),
+                JsonFieldOptions {
+                    fast: true,
+                    fast_tokenizer: Some(tokenizer),
+                    ..JsonFieldOptions::default()
+                },
+            );
+        }
+        let index = make_index_with("json_fast_tokenizers", &builder);
+        index
+            .index_docs(vec![TantivyDocumentFields {
+                fields: vec![
+                    DocumentField {
+                        name: "labels".to_string(),
+                        value: FieldValue::Json(r#"{"brand":"ACME"}"#.to_string()),
+                    },
+                    DocumentField {
+                        name: "origin".to_string(),
+                        value: FieldValue::Json(r#"{"city":"Zürich"}"#.to_string()),
+                    },
+                ],
+            }])
+            .unwrap();
+        index.commit().unwrap();
+
+        let searcher = index.reader.searcher();
+        let terms = |path: &str| {
+            let column = searcher
+                .segment_reader(0)
+                .fast_fields()
+                .str(path)
+                .unwrap()
+                .unwrap();
+            (0..column.num_terms() as u64)
+                .map(|ord| {
+                    let mut term = String::new();
+                    column.ord_to_str(ord, &mut term).unwrap();
+                    term
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(terms("labels.brand"), vec!["acme"]);
+        assert_eq!(terms("origin.city"), vec!["zurich"]);
+    }
+
+    fn merchant(name: &str) -> TantivyDocumentFields {
+        TantivyDocumentFields {
+            fields: ["merchant", "merchant_grams"]
+                .into_iter()
+                .map(|field| DocumentField {
+                    name: field.to_string(),
+                    value: FieldValue::Text(name.to_string()),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn ngram_tokenizers_match_partial_words() {
+        let builder = TantivySchemaBuilder::new();
+        for (name, min_gram, max_gram, prefix_only) in
+            [("merchant", 2, 10, true), ("merchant_grams", 3, 3, false)]
+        {
+            builder.add_text_field(
+                name.to_string(),
+                TextFieldOptions {
+                    tokenizer: TantivyTokenizer::Ngram {
+                        min_gram,
+                        max_gram,
+                        prefix_only,
+                    },
+                    ..TextFieldOptions::default()
+                },
+            );
+        }
+        let index = make_index_with("ngram", &builder);
+        index
+            .index_docs(vec![
+                merchant("Starbucks"),
+                merchant("Starlight Diner"),
+                merchant("Stop & Shop"),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let matches = |field: &str, text: &str| {
+            let query = format!(
+                r#"{{"type":"match","field":"{field}","query":"{text}","operator":"and"}}"#
+            );
+            index
+                .search_dsl(query, 10, 0, vec![], vec![], None, None)
+                .unwrap()
+                .count
+        };
+        assert_eq!(matches("merchant", "starb"), 1);
+        assert_eq!(matches("merchant", "STA"), 2);
+        assert_eq!(matches("merchant", "st"), 3);
+        // Edge n-grams only cover the start of the text, n-grams any substring.
+        assert_eq!(matches("merchant", "bucks"), 0);
+        assert_eq!(matches("merchant_grams", "bucks"), 1);
+        assert_eq!(matches("merchant_grams", "shop"), 1);
+
+        let reserved =
+            TantivyAnalyzerBuilder::new("edge_ngram_2_10".to_string(), TantivyBaseTokenizer::Raw);
+        let with_reserved = TantivySchemaBuilder::new();
+        with_reserved.add_analyzer(&reserved);
+        with_reserved.add_text_field("merchant".to_string(), TextFieldOptions::default());
+        assert!(
+            TantivyIndex::new_with_schema(temp_index_path("ngram_reserved"), &with_reserved)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn analyze_reports_byte_and_utf16_offsets() {
+        let index = crate::tests::make_index("analyze");
+        let text = "🍕 Café Straße";
+        let tokens = index
+            .analyze("merchant".to_string(), text.to_string())
+            .unwrap();
+        let summary = tokens
+            .iter()
+            .map(|token| {
+                (
+                    token.text.as_str(),
+                    token.position,
+                    (token.byte_start, token.byte_end),
+                    (token.utf16_start, token.utf16_end),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("cafe", 0, (5, 10), (3, 7)),
+                ("strasse", 1, (11, 18), (8, 14)),
+            ]
+        );
+        let utf16 = text.encode_utf16().collect::<Vec<_>>();
+        assert_eq!(String::from_utf16(&utf16[8..14]).unwrap(), "Straße");
+
+        let raw = index.analyze("raw".to_string(), text.to_string()).unwrap();
+        assert_eq!(raw.len(), 1);
+        assert_eq!(raw[0].text, text);
+        assert_eq!(raw[0].utf16_end, 14);
+
+        assert!(
+            index
+                .analyze("missing".to_string(), text.to_string())
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use serde::Deserialize;
+use tantivy::collector::TopDocs;
+use tantivy::query::TermQuery;
+use tantivy::schema::IndexRecordOption;
+
+use crate::{
+    DocumentField, TantivyIndex, TantivyIndexError, TantivyQueryDsl, term_from_document_field,
+};
+
+/// One node of a score explanation: how `value` was computed from its children.
+#[derive(Debug, Clone, Deserialize, uniffi::Record)]
+pub struct TantivyExplanation {
+    pub value: f32,
+    pub description: String,
+    /// Extra notes attached by the query, e.g. the term being scored.
+    #[serde(default)]
+    pub context: Vec<String>,
+    #[serde(default, rename = "details")]
+    pub children: Vec<TantivyExplanation>,
+}
+
+#[uniffi::export]
+impl TantivyIndex {
+    /// Explains how the query DSL scores the document identified by `id`.
+    ///
+    /// Fails if the document does not match the query.
+    #[uniffi::method]
+    fn explain(
+        &self,
+        query_json: String,
+        id: DocumentField,
+    ) -> Result<TantivyExplanation, TantivyIndexError> {
+        let schema = self.index.schema();
+        let query_dsl: TantivyQueryDsl = serde_json::from_str(&query_json)?;
+        let query = query_dsl.to_query(&self.index, &schema)?;
+
+        let term = term_from_document_field(&self.index, &schema, &id)?;
+        let searcher = self.reader.searcher();
+        let id_query = TermQuery::new(term, IndexRecordOption::Basic);
+        let top_docs = searcher.search(&id_query, &TopDocs::with_limit(1))?;
+        let Some((_, doc_address)) = top_docs.first() else {
+            return Err(TantivyIndexError::DocRetrievalError(id.name));
+        };
+
+        let explanation = query.explain(&searcher, *doc_address)?;
+        // `Explanation` only exposes its top-level value; its JSON has the same shape as
+        // `TantivyExplanation`.
+        Ok(serde_json::from_value(serde_json::to_value(&explanation)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{id_field, make_index, receipt};
+
+    #[test]
+    fn explain_breaks_down_boosted_scores() {
+        let index = make_index("explain");
+        index
+            .index_docs(vec![
+                receipt("r1", "costco wholesale", 10.0),
+                receipt("r2", "target", 20.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let query = r#"{
+            "type": "boost",
+            "boost": 2.0,
+            "query": {
+                "type": "query_string",
+                "query": "costco",
+                "default_fields": ["merchant"],
+                "fuzzy_fields": []
+            }
+        }"#;
+        let explanation = index.explain(query.to_string(), id_field("r1")).unwrap();
+        assert!(explanation.value > 0.0);
+        assert!(explanation.description.contains("Boost"));
+        assert_eq!(explanation.children.len(), 1);
+        assert!((explanation.children[0].value * 2.0 - explanation.value).abs() < 1e-6);
+        assert!(!explanation.children[0].children.is_empty());
+
+        assert!(index.explain(query.to_string(), id_field("r2")).is_err());
+        assert!(index.explain(query.to_string(), id_field("r9")).is_err());
+    }
+}
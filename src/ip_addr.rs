@@ -0,0 +1,166 @@
+use std::net::{IpAddr, Ipv6Addr};
+use std::ops::RangeInclusive;
+
+use crate::TantivyIndexError;
+
+/// Parses an IPv4 or IPv6 address, IPv4 is stored as an IPv4-mapped IPv6 address.
+pub(crate) fn parse_ip_addr(text: &str) -> Result<Ipv6Addr, TantivyIndexError> {
+    let ip_addr: IpAddr = text.trim().parse()?;
+    Ok(match ip_addr {
+        IpAddr::V4(ipv4) => ipv4.to_ipv6_mapped(),
+        IpAddr::V6(ipv6) => ipv6,
+    })
+}
+
+/// Formats an address the way it was indexed, IPv4-mapped addresses as IPv4.
+pub(crate) fn format_ip_addr(ip_addr: Ipv6Addr) -> String {
+    match ip_addr.to_ipv4_mapped() {
+        Some(ipv4) => ipv4.to_string(),
+        None => ip_addr.to_string(),
+    }
+}
+
+/// The addresses of a CIDR block such as `10.0.0.0/8` or `fe80::/10`.
+///
+/// A bare address is a block of one.
+pub(crate) fn cidr_range(cidr: &str) -> Result<RangeInclusive<Ipv6Addr>, TantivyIndexError> {
+    let (addr, prefix_len) = match cidr.split_once('/') {
+        Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+        None => (cidr, None),
+    };
+    let ip_addr: IpAddr = addr.trim().parse()?;
+    let max_prefix_len = if ip_addr.is_ipv4() { 32 } else { 128 };
+    let prefix_len = match prefix_len {
+        Some(prefix_len) => prefix_len
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|prefix_len| *prefix_len <= max_prefix_len)
+            .ok_or_else(|| {
+                TantivyIndexError::QueryError(format!("Invalid CIDR prefix length: {cidr}"))
+            })?,
+        None => max_prefix_len,
+    };
+
+    let (start, prefix_len) = match ip_addr {
+        IpAddr::V4(ipv4) => (ipv4.to_ipv6_mapped(), prefix_len + 96),
+        IpAddr::V6(ipv6) => (ipv6, prefix_len),
+    };
+    let host_mask = u128::MAX.checked_shr(prefix_len).unwrap_or(0);
+    let start = u128::from(start) & !host_mask;
+    Ok(Ipv6Addr::from(start)..=Ipv6Addr::from(start | host_mask))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::make_index_with;
+    use crate::{
+        DocumentField, FieldValue, NumericFieldOptions, TantivyDocumentFields, TantivyIndex,
+        TantivySchemaBuilder, TextFieldOptions,
+    };
+
+    use super::*;
+
+    fn connection(host: &str, ip: &str) -> TantivyDocumentFields {
+        TantivyDocumentFields {
+            fields: vec![
+                DocumentField {
+                    name: "host".to_string(),
+                    value: FieldValue::Text(host.to_string()),
+                },
+                DocumentField {
+                    name: "ip".to_string(),
+                    value: FieldValue::IpAddr(ip.to_string()),
+                },
+            ],
+        }
+    }
+
+    fn hosts(index: &TantivyIndex, query: &str) -> Vec<String> {
+        let mut hosts = index
+            .search_dsl(query.to_string(), 10, 0, vec![], vec![], None, None)
+            .unwrap()
+            .docs
+            .into_iter()
+            .flat_map(|hit| hit.doc.fields)
+            .filter_map(|field| match field.value {
+                FieldValue::Text(host) if field.name == "host" => Some(host),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        hosts.sort();
+        hosts
+    }
+
+    #[test]
+    fn cidr_range_masks_host_bits() {
+        let range = cidr_range("192.168.1.77/24").unwrap();
+        assert_eq!(format_ip_addr(*range.start()), "192.168.1.0");
+        assert_eq!(format_ip_addr(*range.end()), "192.168.1.255");
+
+        let range = cidr_range("fe80::1/10").unwrap();
+        assert_eq!(format_ip_addr(*range.start()), "fe80::");
+        assert_eq!(
+            format_ip_addr(*range.end()),
+            "febf:ffff:ffff:ffff:ffff:ffff:ffff:ffff"
+        );
+
+        let range = cidr_range("::/0").unwrap();
+        assert_eq!(u128::from(*range.end()), u128::MAX);
+        assert!(cidr_range("10.0.0.0/33").is_err());
+        assert!(cidr_range("10.0.0/8").is_err());
+    }
+
+    #[test]
+    fn ip_addr_fields_round_trip_and_match_cidr_ranges() {
+        let builder = TantivySchemaBuilder::new();
+        builder.add_text_field("host".to_string(), TextFieldOptions::default());
+        builder.add_ip_addr_field(
+            "ip".to_string(),
+            NumericFieldOptions {
+                fast: true,
+                ..NumericFieldOptions::default()
+            },
+        );
+        let index = make_index_with("ip_addr", &builder);
+        index
+            .index_docs(vec![
+                connection("router", "192.168.1.1"),
+                connection("nas", "192.168.1.20"),
+                connection("dns", "8.8.8.8"),
+                connection("link-local", "fe80::1"),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let doc = index
+            .get_doc(
+                DocumentField {
+                    name: "ip".to_string(),
+                    value: FieldValue::IpAddr("8.8.8.8".to_string()),
+                },
+                None,
+            )
+            .unwrap();
+        assert!(doc.fields.iter().any(|field| matches!(
+            &field.value,
+            FieldValue::IpAddr(ip) if ip == "8.8.8.8"
+        )));
+
+        let term_set = r#"{"type":"term_set","terms":[
+            {"name":"ip","value":{"type":"ip_addr","value":"8.8.8.8"}},
+            {"name":"ip","value":{"type":"ip_addr","value":"fe80::1"}}
+        ]}"#;
+        assert_eq!(hosts(&index, term_set), vec!["dns", "link-local"]);
+
+        let cidr = r#"{"type":"cidr","field":"ip","cidr":"192.168.1.0/24"}"#;
+        assert_eq!(hosts(&index, cidr), vec!["nas", "router"]);
+
+        let range = r#"{"type":"range","field":"ip",
+            "lower":{"type":"ip_addr","value":"192.168.1.10"},"upper":null,
+            "include_lower":true,"include_upper":false}"#;
+        assert_eq!(hosts(&index, range), vec!["link-local", "nas"]);
+
+        assert!(index.index_doc(connection("bad", "not-an-ip")).is_err());
+    }
+}
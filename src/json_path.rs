@@ -0,0 +1,236 @@
+use tantivy::schema::{DATE_TIME_PRECISION_INDEXED, Field, FieldType, Schema};
+use tantivy::{DateTime, Index, TantivyError, Term};
+
+use crate::{FieldValue, TantivyIndexError};
+
+/// Splits `metadata.vendor.country` into the `metadata` field and the `vendor.country`
+/// path inside it. The path is empty when `path` names a field.
+pub(crate) fn resolve_field_path<'a>(
+    schema: &Schema,
+    path: &'a str,
+) -> Result<(Field, &'a str), TantivyIndexError> {
+    let (field, json_path) = schema
+        .find_field(path)
+        .ok_or_else(|| TantivyError::FieldNotFound(path.to_string()))?;
+    if !json_path.is_empty()
+        && !matches!(
+            schema.get_field_entry(field).field_type(),
+            FieldType::JsonObject(_)
+        )
+    {
+        return Err(TantivyError::FieldNotFound(path.to_string()).into());
+    }
+    Ok((field, json_path))
+}
+
+/// Builds the term of a typed value at `json_path` inside a JSON field.
+///
+/// Values are encoded the way JSON documents are indexed: text through the field's
+/// tokenizer, integers as i64 whenever they fit, and dates truncated to seconds for
+/// term lookups in the inverted index.
+pub(crate) fn json_term(
+    index: &Index,
+    schema: &Schema,
+    field: Field,
+    json_path: &str,
+    value: &FieldValue,
+    truncate_dates: bool,
+) -> Result<Term, TantivyIndexError> {
+    let FieldType::JsonObject(options) = schema.get_field_entry(field).field_type() else {
+        return Err(TantivyIndexError::QueryError(format!(
+            "Field {} is not a JSON field",
+            schema.get_field_name(field)
+        )));
+    };
+    let mut term = Term::from_field_json_path(field, json_path, options.is_expand_dots_enabled());
+    match value {
+        FieldValue::Text(text) if options.get_text_indexing_options().is_some() => {
+            term.append_type_and_str(&json_text_token(index, schema, field, json_path, text)?)
+        }
+        FieldValue::Text(text) => term.append_type_and_str(text),
+        FieldValue::I64(val) => term.append_type_and_fast_value(*val),
+        FieldValue::U64(val) => match i64::try_from(*val) {
+            Ok(val) => term.append_type_and_fast_value(val),
+            Err(_) => term.append_type_and_fast_value(*val),
+        },
+        FieldValue::F64(val) => term.append_type_and_fast_value(*val),
+        FieldValue::Bool(val) => term.append_type_and_fast_value(*val),
+        FieldValue::Date(ts) => {
+            let mut date = DateTime::from_timestamp_micros(*ts);
+            if truncate_dates {
+                date = date.truncate(DATE_TIME_PRECISION_INDEXED);
+            }
+            term.append_type_and_fast_value(date);
+        }
+        _ => {
+            return Err(TantivyIndexError::QueryError(format!(
+                "JSON path {}.{} only supports text, i64, u64, f64, bool and date values",
+                schema.get_field_name(field),
+                json_path
+            )));
+        }
+    }
+    Ok(term)
+}
+
+/// Runs `text` through the tokenizer of a JSON field, which has to leave the single
+/// token a term can hold.
+fn json_text_token(
+    index: &Index,
+    schema: &Schema,
+    field: Field,
+    json_path: &str,
+    text: &str,
+) -> Result<String, TantivyIndexError> {
+    let mut analyzer = index.tokenizer_for_field(field)?;
+    let mut tokens = Vec::new();
+    analyzer
+        .token_stream(text)
+        .process(&mut |token| tokens.push(token.text.clone()));
+    match <[String; 1]>::try_from(tokens) {
+        Ok([token]) => Ok(token),
+        Err(tokens) => Err(TantivyIndexError::QueryError(format!(
+            "Text {text:?} at JSON path {}.{json_path} analyzes to {} tokens instead of one",
+            schema.get_field_name(field),
+            tokens.len()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::make_index_with;
+    use crate::{
+        DocumentField, FieldValue, JsonFieldOptions, TantivyDocumentFields, TantivyIndex,
+        TantivySchemaBuilder, TantivyTokenizer, TextFieldOptions,
+    };
+
+    fn item(name: &str, metadata: &str) -> TantivyDocumentFields {
+        TantivyDocumentFields {
+            fields: vec![
+                DocumentField {
+                    name: "name".to_string(),
+                    value: FieldValue::Text(name.to_string()),
+                },
+                DocumentField {
+                    name: "metadata".to_string(),
+                    value: FieldValue::Json(metadata.to_string()),
+                },
+            ],
+        }
+    }
+
+    fn names(index: &TantivyIndex, query: &str) -> Vec<String> {
+        let mut names = index
+            .search_dsl(query.to_string(), 10, 0, vec![], vec![], None, None)
+            .unwrap()
+            .docs
+            .into_iter()
+            .flat_map(|hit| hit.doc.fields)
+            .filter_map(|field| match field.value {
+                FieldValue::Text(name) if field.name == "name" => Some(name),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    fn country(country: &str) -> String {
+        format!(
+            r#"{{"type":"term","term":{{"name":"metadata.vendor.country","value":{{"type":"text","value":"{country}"}}}}}}"#
+        )
+    }
+
+    #[test]
+    fn json_paths_support_typed_term_range_and_exists_queries() {
+        let builder = TantivySchemaBuilder::new();
+        builder.add_text_field("name".to_string(), TextFieldOptions::default());
+        builder.add_json_field(
+            "metadata".to_string(),
+            JsonFieldOptions {
+                indexed: true,
+                fast: true,
+                tokenizer: TantivyTokenizer::Raw,
+                expand_dots: true,
+                ..JsonFieldOptions::default()
+            },
+        );
+        let index = make_index_with("json_path", &builder);
+        index
+            .index_docs(vec![
+                item(
+                    "kettle",
+                    r#"{"vendor":{"country":"DE"},"weight":1.5,"stock":12,"active":true,"added":"2024-03-01T10:00:00Z"}"#,
+                ),
+                item(
+                    "toaster",
+                    r#"{"vendor.country":"US","weight":2.25,"stock":3,"active":false}"#,
+                ),
+                item("mug", r#"{"stock":40}"#),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        assert_eq!(names(&index, &country("DE")), vec!["kettle"]);
+        // expand_dots makes the dotted key addressable by the same path.
+        assert_eq!(names(&index, &country("US")), vec!["toaster"]);
+
+        let term_set = r#"{"type":"term_set","terms":[
+            {"name":"metadata.active","value":{"type":"bool","value":true}},
+            {"name":"metadata.stock","value":{"type":"u64","value":40}}
+        ]}"#;
+        assert_eq!(names(&index, term_set), vec!["kettle", "mug"]);
+
+        let added = r#"{"type":"term","term":{"name":"metadata.added","value":{"type":"date","value":1709287200000000}}}"#;
+        assert_eq!(names(&index, added), vec!["kettle"]);
+
+        let stock_range = r#"{"type":"range","field":"metadata.stock",
+            "lower":{"type":"i64","value":10},"upper":null,
+            "include_lower":true,"include_upper":false}"#;
+        assert_eq!(names(&index, stock_range), vec!["kettle", "mug"]);
+
+        let weight_range = r#"{"type":"range","field":"metadata.weight",
+            "lower":{"type":"f64","value":2.0},"upper":{"type":"f64","value":3.0},
+            "include_lower":true,"include_upper":true}"#;
+        assert_eq!(names(&index, weight_range), vec!["toaster"]);
+
+        let exists = r#"{"type":"exists","field":"metadata.vendor"}"#;
+        assert_eq!(names(&index, exists), vec!["kettle", "toaster"]);
+
+        let unknown = r#"{"type":"exists","field":"name.vendor"}"#;
+        assert!(
+            index
+                .search_dsl(unknown.to_string(), 10, 0, vec![], vec![], None, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn json_text_terms_go_through_the_field_tokenizer() {
+        let builder = TantivySchemaBuilder::new();
+        builder.add_text_field("name".to_string(), TextFieldOptions::default());
+        builder.add_json_field(
+            "metadata".to_string(),
+            JsonFieldOptions {
+                indexed: true,
+                tokenizer: TantivyTokenizer::Default,
+                ..JsonFieldOptions::default()
+            },
+        );
+        let index = make_index_with("json_path_tokenizer", &builder);
+        index
+            .index_docs(vec![item("kettle", r#"{"vendor":{"country":"DE"}}"#)])
+            .unwrap();
+        index.commit().unwrap();
+
+        // The default tokenizer lowercased the indexed value.
+        assert_eq!(names(&index, &country("DE")), vec!["kettle"]);
+        assert_eq!(names(&index, &country("de")), vec!["kettle"]);
+        assert!(
+            index
+                .search_dsl(country("DE AT"), 10, 0, vec![], vec![], None, None)
+                .is_err()
+        );
+    }
+}
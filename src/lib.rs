@@ -1,33 +1,67 @@
 use serde::{Deserialize, Serialize};
-use std::ops::Bound;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::ops::{Bound, Deref, DerefMut};
 use std::path::Path;
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard};
 
 use tantivy::IndexReader;
 use tantivy::IndexWriter;
+use tantivy::Searcher;
 use tantivy::TantivyDocument;
 use tantivy::Term;
 use tantivy::collector::Count;
+use tantivy::collector::FacetCollector;
 use tantivy::collector::TopDocs;
 use tantivy::directory::MmapDirectory;
 use tantivy::query::Occur;
 use tantivy::query::{
     AllQuery, BooleanQuery, BoostQuery, ConstScoreQuery, DisjunctionMaxQuery, EmptyQuery,
-    ExistsQuery, FuzzyTermQuery, PhrasePrefixQuery, PhraseQuery, QueryParser, RangeQuery,
-    RegexQuery, TermQuery, TermSetQuery,
+    ExistsQuery, QueryParser, RangeQuery, RegexQuery, TermQuery, TermSetQuery,
 };
 
 use tantivy::schema::{
-    DateOptions as TantivyDateOptions, DateTimePrecision, Facet, FacetOptions, FieldType,
-    IndexRecordOption, JsonObjectOptions, NumericOptions as TantivyNumericOptions, OwnedValue,
-    Schema, TextFieldIndexing, TextOptions as TantivyTextOptions, Value,
+    DateOptions as TantivyDateOptions, DateTimePrecision, Facet, FacetOptions, FieldEntry,
+    FieldType, IndexRecordOption, IpAddrOptions, JsonObjectOptions,
+    NumericOptions as TantivyNumericOptions, OwnedValue, Schema, TextFieldIndexing,
+    TextOptions as TantivyTextOptions, Value,
 };
 use tantivy::tokenizer::AsciiFoldingFilter;
 use tantivy::tokenizer::LowerCaser;
 use tantivy::tokenizer::TextAnalyzer;
-use tantivy::{Document, Index};
-
+use tantivy::{Document, Index, IndexSettings, TantivyError};
+
+mod aggregation;
+mod analyzer;
+mod explain;
+mod ip_addr;
+mod json_path;
+mod match_query;
+mod migrate;
+mod projection;
+mod query_parse;
+mod schema_diff;
+mod schema_info;
+mod snippet;
+mod sort;
+mod suggest;
+mod terms;
 mod unicode_tokenizer;
+use crate::analyzer::{
+    TantivyAnalyzerBuilder, TantivyAnalyzerDefinition, ngram_tokenizer_name,
+    parse_ngram_tokenizer_name, register_analyzer, register_analyzers, register_ngram_tokenizers,
+};
+use crate::ip_addr::{cidr_range, format_ip_addr, parse_ip_addr};
+use crate::json_path::{json_term, resolve_field_path};
+use crate::match_query::{
+    TantivyMatchOperator, fuzzy_query, match_phrase_query, match_query, phrase_prefix_query,
+    phrase_query,
+};
+use crate::projection::{FieldProjector, TantivyFieldProjection, fast_id_value, is_fast_id_field};
+use crate::query_parse::parse_query_string;
+use crate::schema_diff::diff_schemas;
+use crate::snippet::{SnippetBuilder, TantivySnippet, TantivySnippetField};
+use crate::sort::{SearchCursor, TantivySortField, search_top_docs, take_page};
 use crate::unicode_tokenizer::UnicodeTokenizer;
 
 #[derive(Debug, thiserror::Error, uniffi::Error)]
@@ -45,40 +79,93 @@ pub enum TantivyIndexError {
     DocParsingError(#[from] tantivy::schema::document::DocParsingError),
     #[error("Facet parse error: {0}")]
     FacetParseError(#[from] tantivy::schema::FacetParseError),
+    #[error("IP address parse error: {0}")]
+    IpAddrParseError(#[from] std::net::AddrParseError),
     #[error("TryFromInt error: {0}")]
     TryFromIntError(#[from] std::num::TryFromIntError),
     #[error("Index writer acquisition error")]
     WriterAcquisitionError,
+    #[error("Pinned searcher acquisition error")]
+    SearcherAcquisitionError,
+    #[error("Index was opened without a writer")]
+    ReadOnlyIndex,
+    #[error("Invalid index options: {0}")]
+    InvalidIndexOptions(String),
     #[error("Document not found for: {0}")]
     DocRetrievalError(String),
+    #[error("Document is missing ID field: {0}")]
+    MissingIdField(String),
     #[error("Schema builder error: {0}")]
     SchemaBuilderError(String),
+    #[error("Schema does not match the index on disk: {0}")]
+    SchemaMismatch(String),
+    #[error("Migration error: {0}")]
+    MigrationError(String),
     #[error("Query error: {0}")]
     QueryError(String),
+    #[error("Query parse error: {0}")]
+    QueryParseError(String),
 }
 
-#[derive(Debug, Clone, Copy, uniffi::Enum)]
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
 pub enum TantivyTokenizer {
     Raw,
     Default,
     Unicode,
     EnStem,
     Whitespace,
+    /// An analyzer added to the schema builder with `add_analyzer`.
+    Custom {
+        name: String,
+    },
+    /// Lowercased, ASCII-folded n-grams of `min_gram..=max_gram` characters, or only
+    /// the prefixes of the text (edge n-grams) with `prefix_only`.
+    Ngram {
+        min_gram: u32,
+        max_gram: u32,
+        prefix_only: bool,
+    },
 }
 
 impl TantivyTokenizer {
-    fn as_str(&self) -> &'static str {
+    fn name(&self) -> Cow<'_, str> {
         match self {
-            TantivyTokenizer::Raw => "raw",
-            TantivyTokenizer::Default => "default",
-            TantivyTokenizer::Unicode => "unicode",
-            TantivyTokenizer::EnStem => "en_stem",
-            TantivyTokenizer::Whitespace => "whitespace",
+            TantivyTokenizer::Raw => "raw".into(),
+            TantivyTokenizer::Default => "default".into(),
+            TantivyTokenizer::Unicode => "unicode".into(),
+            TantivyTokenizer::EnStem => "en_stem".into(),
+            TantivyTokenizer::Whitespace => "whitespace".into(),
+            TantivyTokenizer::Custom { name } => name.into(),
+            TantivyTokenizer::Ngram {
+                min_gram,
+                max_gram,
+                prefix_only,
+            } => ngram_tokenizer_name(*min_gram, *max_gram, *prefix_only).into(),
+        }
+    }
+
+    fn from_name(name: &str) -> Self {
+        match name {
+            "raw" => TantivyTokenizer::Raw,
+            "default" => TantivyTokenizer::Default,
+            "unicode" => TantivyTokenizer::Unicode,
+            "en_stem" => TantivyTokenizer::EnStem,
+            "whitespace" => TantivyTokenizer::Whitespace,
+            name => match parse_ngram_tokenizer_name(name) {
+                Some((min_gram, max_gram, prefix_only)) => TantivyTokenizer::Ngram {
+                    min_gram,
+                    max_gram,
+                    prefix_only,
+                },
+                None => TantivyTokenizer::Custom {
+                    name: name.to_string(),
+                },
+            },
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, uniffi::Enum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
 pub enum TantivyIndexRecordOption {
     Basic,
     WithFreqs,
@@ -97,11 +184,24 @@ impl From<TantivyIndexRecordOption> for IndexRecordOption {
     }
 }
 
-#[derive(Debug, Clone, Copy, uniffi::Enum)]
+impl From<IndexRecordOption> for TantivyIndexRecordOption {
+    fn from(opt: IndexRecordOption) -> Self {
+        match opt {
+            IndexRecordOption::Basic => TantivyIndexRecordOption::Basic,
+            IndexRecordOption::WithFreqs => TantivyIndexRecordOption::WithFreqs,
+            IndexRecordOption::WithFreqsAndPositions => {
+                TantivyIndexRecordOption::WithFreqsAndPositions
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
 pub enum TantivyDatePrecision {
     Seconds,
     Milliseconds,
     Microseconds,
+    Nanoseconds,
 }
 
 impl From<TantivyDatePrecision> for DateTimePrecision {
@@ -110,6 +210,18 @@ impl From<TantivyDatePrecision> for DateTimePrecision {
             TantivyDatePrecision::Seconds => DateTimePrecision::Seconds,
             TantivyDatePrecision::Milliseconds => DateTimePrecision::Milliseconds,
             TantivyDatePrecision::Microseconds => DateTimePrecision::Microseconds,
+            TantivyDatePrecision::Nanoseconds => DateTimePrecision::Nanoseconds,
+        }
+    }
+}
+
+impl From<DateTimePrecision> for TantivyDatePrecision {
+    fn from(precision: DateTimePrecision) -> Self {
+        match precision {
+            DateTimePrecision::Seconds => TantivyDatePrecision::Seconds,
+            DateTimePrecision::Milliseconds => TantivyDatePrecision::Milliseconds,
+            DateTimePrecision::Microseconds => TantivyDatePrecision::Microseconds,
+            DateTimePrecision::Nanoseconds => TantivyDatePrecision::Nanoseconds,
         }
     }
 }
@@ -216,6 +328,7 @@ impl Default for JsonFieldOptions {
 #[derive(uniffi::Object)]
 pub struct TantivySchemaBuilder {
     builder: Mutex<Option<tantivy::schema::SchemaBuilder>>,
+    analyzers: Mutex<Vec<TantivyAnalyzerDefinition>>,
 }
 
 #[uniffi::export]
@@ -224,9 +337,16 @@ impl TantivySchemaBuilder {
     pub fn new() -> Self {
         Self {
             builder: Mutex::new(Some(Schema::builder())),
+            analyzers: Mutex::new(Vec::new()),
         }
     }
 
+    /// Registers a named analyzer on indexes built from this schema.
+    #[uniffi::method]
+    pub fn add_analyzer(&self, analyzer: &TantivyAnalyzerBuilder) {
+        self.analyzers.lock().unwrap().push(analyzer.definition());
+    }
+
     #[uniffi::method]
     pub fn add_text_field(&self, name: String, options: TextFieldOptions) {
         let mut guard = self.builder.lock().unwrap();
@@ -234,7 +354,7 @@ impl TantivySchemaBuilder {
             let mut text_options = TantivyTextOptions::default();
 
             let indexing = TextFieldIndexing::default()
-                .set_tokenizer(options.tokenizer.as_str())
+                .set_tokenizer(&options.tokenizer.name())
                 .set_index_option(options.record.into())
                 .set_fieldnorms(options.fieldnorms);
 
@@ -399,6 +519,29 @@ impl TantivySchemaBuilder {
         }
     }
 
+    #[uniffi::method]
+    pub fn add_ip_addr_field(&self, name: String, options: NumericFieldOptions) {
+        let mut guard = self.builder.lock().unwrap();
+        if let Some(builder) = guard.as_mut() {
+            let mut opts = IpAddrOptions::default();
+
+            if options.indexed {
+                opts = opts.set_indexed();
+            }
+            if options.stored {
+                opts = opts.set_stored();
+            }
+            if options.fast {
+                opts = opts.set_fast();
+            }
+            if options.fieldnorms {
+                opts = opts.set_fieldnorms();
+            }
+
+            builder.add_ip_addr_field(&name, opts);
+        }
+    }
+
     #[uniffi::method]
     pub fn add_json_field(&self, name: String, options: JsonFieldOptions) {
         let mut guard = self.builder.lock().unwrap();
@@ -411,15 +554,15 @@ impl TantivySchemaBuilder {
 
             if options.indexed {
                 let indexing = TextFieldIndexing::default()
-                    .set_tokenizer(options.tokenizer.as_str())
+                    .set_tokenizer(&options.tokenizer.name())
                     .set_index_option(options.record.into())
                     .set_fieldnorms(options.fieldnorms);
                 opts = opts.set_indexing_options(indexing);
             }
 
             if options.fast {
-                let tokenizer = options.fast_tokenizer.map(|tokenizer| tokenizer.as_str());
-                opts = opts.set_fast(tokenizer);
+                let tokenizer = options.fast_tokenizer.as_ref().map(TantivyTokenizer::name);
+                opts = opts.set_fast(tokenizer.as_deref());
             }
 
             if options.expand_dots {
@@ -431,11 +574,42 @@ impl TantivySchemaBuilder {
     }
 }
 
+impl Default for TantivySchemaBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TantivySchemaBuilder {
     fn take_and_build(&self) -> Option<Schema> {
         let mut guard = self.builder.lock().unwrap();
         guard.take().map(|b| b.build())
     }
+
+    /// Builds the schema without consuming the builder.
+    fn peek_schema(&self) -> Option<Schema> {
+        let mut guard = self.builder.lock().unwrap();
+        let schema = guard.take()?.build();
+        let mut builder = Schema::builder();
+        for (_, field_entry) in schema.fields() {
+            builder.add_field(field_entry.clone());
+        }
+        *guard = Some(builder);
+        Some(schema)
+    }
+
+    fn add_field_entries(&self, field_entries: Vec<FieldEntry>) {
+        let mut guard = self.builder.lock().unwrap();
+        if let Some(builder) = guard.as_mut() {
+            for field_entry in field_entries {
+                builder.add_field(field_entry);
+            }
+        }
+    }
+
+    fn take_analyzers(&self) -> Vec<TantivyAnalyzerDefinition> {
+        std::mem::take(&mut *self.analyzers.lock().unwrap())
+    }
 }
 
 // ============================================================================
@@ -456,6 +630,8 @@ pub enum FieldValue {
     Bytes(Vec<u8>),
     Facet(String),
     Json(String),
+    /// IPv4 or IPv6 address in its textual form
+    IpAddr(String),
 }
 
 /// A single document field
@@ -478,6 +654,24 @@ pub struct TantivySearchQuery {
     pub fuzzy_fields: Vec<TantivyFuzzyField>,
     pub top_doc_limit: u32,
     pub top_doc_offset: u32,
+    #[serde(default)]
+    #[uniffi(default = [])]
+    pub sort: Vec<TantivySortField>,
+    #[serde(default)]
+    #[uniffi(default = [])]
+    pub snippet_fields: Vec<TantivySnippetField>,
+    /// `next_cursor` of a previous page, hits are returned from right after it.
+    #[serde(default)]
+    #[uniffi(default = None)]
+    pub cursor: Option<String>,
+    /// Fail with the parse errors instead of skipping the invalid parts of the query.
+    #[serde(default)]
+    #[uniffi(default = false)]
+    pub strict: bool,
+    /// Stored fields to return with each hit, all of them when `None`.
+    #[serde(default)]
+    #[uniffi(default = None)]
+    pub fields: Option<TantivyFieldProjection>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, uniffi::Record)]
@@ -533,6 +727,22 @@ pub enum TantivyQueryDsl {
         include_lower: bool,
         include_upper: bool,
     },
+    /// Text run through the field's analyzer, matching any or all of its terms.
+    Match {
+        field: String,
+        query: String,
+        #[serde(default)]
+        operator: TantivyMatchOperator,
+        #[serde(default)]
+        minimum_should_match: Option<u32>,
+    },
+    /// Text run through the field's analyzer, matching its terms as a phrase.
+    MatchPhrase {
+        field: String,
+        query: String,
+        #[serde(default)]
+        slop: Option<u32>,
+    },
     Regex {
         field: String,
         pattern: String,
@@ -546,6 +756,11 @@ pub enum TantivyQueryDsl {
     Exists {
         field: String,
     },
+    /// Addresses of an IP field within a CIDR block, e.g. `10.0.0.0/8`.
+    Cidr {
+        field: String,
+        cidr: String,
+    },
     Boost {
         query: Box<TantivyQueryDsl>,
         boost: f32,
@@ -562,14 +777,89 @@ pub enum TantivyQueryDsl {
         query: String,
         default_fields: Vec<String>,
         fuzzy_fields: Vec<TantivyFuzzyField>,
+        #[serde(default)]
+        strict: bool,
     },
 }
 
+/// Searcher generations kept alive for cursor pagination.
+const MAX_PINNED_SEARCHERS: usize = 8;
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyIndexOptions {
+    /// Memory budget of the writer in bytes, shared by its indexing threads. Each
+    /// thread needs at least 15 MB.
+    #[uniffi(default = 100000000)]
+    pub writer_heap_bytes: u64,
+    /// Indexing threads, picked from the number of cores when `None`. Zero threads
+    /// fail with `InvalidIndexOptions`.
+    #[uniffi(default = None)]
+    pub num_threads: Option<u32>,
+    /// Without a writer the index can only be searched, write methods fail with
+    /// `ReadOnlyIndex`.
+    #[uniffi(default = true)]
+    pub writable: bool,
+}
+
+impl Default for TantivyIndexOptions {
+    fn default() -> Self {
+        Self {
+            writer_heap_bytes: 100_000_000,
+            num_threads: None,
+            writable: true,
+        }
+    }
+}
+
+impl TantivyIndexOptions {
+    fn create_writer(&self, index: &Index) -> Result<Option<IndexWriter>, TantivyIndexError> {
+        if !self.writable {
+            return Ok(None);
+        }
+        self.writer(index).map(Some)
+    }
+
+    /// A writer sized by these options, even when they are not `writable`.
+    pub(crate) fn writer(&self, index: &Index) -> Result<IndexWriter, TantivyIndexError> {
+        let heap_bytes = self.writer_heap_bytes.try_into()?;
+        let writer = match self.num_threads {
+            Some(0) => {
+                return Err(TantivyIndexError::InvalidIndexOptions(
+                    "Writer needs at least one thread".to_string(),
+                ));
+            }
+            Some(num_threads) => {
+                index.writer_with_num_threads(num_threads.try_into()?, heap_bytes)?
+            }
+            None => index.writer(heap_bytes)?,
+        };
+        Ok(writer)
+    }
+}
+
 #[derive(uniffi::Object)]
 pub struct TantivyIndex {
     index: Index,
-    writer: Mutex<IndexWriter>,
+    writer: Mutex<Option<IndexWriter>>,
     reader: IndexReader,
+    pinned_searchers: Mutex<VecDeque<Searcher>>,
+}
+
+/// A locked writer of an index that has one.
+struct WriterGuard<'a>(MutexGuard<'a, Option<IndexWriter>>);
+
+impl Deref for WriterGuard<'_> {
+    type Target = IndexWriter;
+
+    fn deref(&self) -> &IndexWriter {
+        self.0.as_ref().expect("writer checked when locked")
+    }
+}
+
+impl DerefMut for WriterGuard<'_> {
+    fn deref_mut(&mut self) -> &mut IndexWriter {
+        self.0.as_mut().expect("writer checked when locked")
+    }
 }
 
 fn add_field_value(
@@ -587,7 +877,7 @@ fn add_field_value(
             let dt = tantivy::DateTime::from_timestamp_micros(*ts);
             doc.add_date(field, dt);
         }
-        FieldValue::Bytes(b) => doc.add_bytes(field, &b),
+        FieldValue::Bytes(b) => doc.add_bytes(field, b),
         FieldValue::Facet(path) => {
             let facet = Facet::from_text(path)?;
             doc.add_facet(field, facet);
@@ -597,10 +887,38 @@ fn add_field_value(
             let owned_value: OwnedValue = json_value.into();
             doc.add_field_value(field, &owned_value);
         }
+        FieldValue::IpAddr(ip_addr) => doc.add_ip_addr(field, parse_ip_addr(ip_addr)?),
     }
     Ok(())
 }
 
+fn tantivy_doc_from_fields(
+    schema: &Schema,
+    doc: &TantivyDocumentFields,
+) -> Result<TantivyDocument, TantivyIndexError> {
+    let mut tantivy_doc = TantivyDocument::default();
+    for field in &doc.fields {
+        if let Ok(field_handle) = schema.get_field(&field.name) {
+            add_field_value(&mut tantivy_doc, field_handle, &field.value)?;
+        }
+    }
+    Ok(tantivy_doc)
+}
+
+fn id_term_from_document(
+    index: &Index,
+    schema: &Schema,
+    id_field: &str,
+    doc: &TantivyDocumentFields,
+) -> Result<Term, TantivyIndexError> {
+    let id = doc
+        .fields
+        .iter()
+        .find(|field| field.name == id_field)
+        .ok_or_else(|| TantivyIndexError::MissingIdField(id_field.to_string()))?;
+    term_from_document_field(index, schema, id)
+}
+
 fn term_from_field_value(
     schema: &Schema,
     field: tantivy::schema::Field,
@@ -622,9 +940,13 @@ fn term_from_field_value(
             let facet = Facet::from_text(path)?;
             Ok(Term::from_facet(field, &facet))
         }
-        (FieldType::JsonObject(_), FieldValue::Json(_)) => Err(TantivyIndexError::QueryError(
-            "JSON term queries are not supported yet".to_string(),
-        )),
+        (FieldType::IpAddr(_), FieldValue::IpAddr(ip_addr)) => {
+            Ok(Term::from_field_ip_addr(field, parse_ip_addr(ip_addr)?))
+        }
+        (FieldType::JsonObject(_), _) => Err(TantivyIndexError::QueryError(format!(
+            "JSON field {0} must be queried by path, e.g. {0}.key",
+            schema.get_field_name(field)
+        ))),
         (_, _) => Err(TantivyIndexError::QueryError(format!(
             "Field value type does not match schema field {}",
             schema.get_field_name(field)
@@ -633,11 +955,16 @@ fn term_from_field_value(
 }
 
 fn term_from_document_field(
+    index: &Index,
     schema: &Schema,
     doc_field: &DocumentField,
 ) -> Result<Term, TantivyIndexError> {
-    let field = schema.get_field(&doc_field.name)?;
-    term_from_field_value(schema, field, &doc_field.value)
+    let (field, json_path) = resolve_field_path(schema, &doc_field.name)?;
+    if json_path.is_empty() {
+        term_from_field_value(schema, field, &doc_field.value)
+    } else {
+        json_term(index, schema, field, json_path, &doc_field.value, true)
+    }
 }
 
 fn occur_from_dsl(occur: &TantivyOccur) -> Occur {
@@ -691,17 +1018,24 @@ fn document_field_from_value(
             let json = serde_json::to_string(&value)?;
             Ok(Some(FieldValue::Json(json)))
         }
-        FieldType::IpAddr(_) => Ok(None),
+        FieldType::IpAddr(_) => match value {
+            OwnedValue::IpAddr(ip_addr) => Ok(Some(FieldValue::IpAddr(format_ip_addr(ip_addr)))),
+            _ => Ok(None),
+        },
     }
 }
 
 fn doc_to_fields(
     schema: &Schema,
     doc: TantivyDocument,
+    projector: &FieldProjector,
 ) -> Result<TantivyDocumentFields, TantivyIndexError> {
     let mut fields = Vec::new();
 
     for (field, value) in doc.iter_fields_and_values() {
+        if !projector.keeps(field) {
+            continue;
+        }
         let owned: OwnedValue = OwnedValue::from(value.as_value());
         if let Some(field_value) = document_field_from_value(schema, field, owned)? {
             fields.push(DocumentField {
@@ -750,7 +1084,7 @@ impl TantivyQueryDsl {
             TantivyQueryDsl::All => Ok(Box::new(AllQuery)),
             TantivyQueryDsl::Empty => Ok(Box::new(EmptyQuery)),
             TantivyQueryDsl::Term { term } => {
-                let term = term_from_document_field(schema, term)?;
+                let term = term_from_document_field(index, schema, term)?;
                 Ok(Box::new(TermQuery::new(term, IndexRecordOption::Basic)))
             }
             TantivyQueryDsl::TermSet { terms } => {
@@ -759,7 +1093,7 @@ impl TantivyQueryDsl {
                 }
                 let mut parsed_terms = Vec::with_capacity(terms.len());
                 for term in terms {
-                    parsed_terms.push(term_from_document_field(schema, term)?);
+                    parsed_terms.push(term_from_document_field(index, schema, term)?);
                 }
                 Ok(Box::new(TermSetQuery::new(parsed_terms)))
             }
@@ -778,16 +1112,7 @@ impl TantivyQueryDsl {
                         "Phrase query requires at least two terms".to_string(),
                     ));
                 }
-                let field = schema.get_field(field)?;
-                let term_list = terms
-                    .iter()
-                    .map(|term| Term::from_field_text(field, term))
-                    .collect::<Vec<_>>();
-                let mut query = PhraseQuery::new(term_list);
-                if let Some(slop) = slop {
-                    query.set_slop(*slop);
-                }
-                Ok(Box::new(query))
+                phrase_query(index, schema, field, terms, *slop)
             }
             TantivyQueryDsl::PhrasePrefix {
                 field,
@@ -799,16 +1124,7 @@ impl TantivyQueryDsl {
                         "Phrase prefix query requires at least one term".to_string(),
                     ));
                 }
-                let field = schema.get_field(field)?;
-                let term_list = terms
-                    .iter()
-                    .map(|term| Term::from_field_text(field, term))
-                    .collect::<Vec<_>>();
-                let mut query = PhrasePrefixQuery::new(term_list);
-                if let Some(max_expansions) = max_expansions {
-                    query.set_max_expansions(*max_expansions);
-                }
-                Ok(Box::new(query))
+                phrase_prefix_query(index, schema, field, terms, *max_expansions)
             }
             TantivyQueryDsl::Range {
                 field,
@@ -817,11 +1133,18 @@ impl TantivyQueryDsl {
                 include_lower,
                 include_upper,
             } => {
-                let field = schema.get_field(field)?;
+                let (field, json_path) = resolve_field_path(schema, field)?;
+                let bound_term = |value| {
+                    if json_path.is_empty() {
+                        term_from_field_value(schema, field, value)
+                    } else {
+                        json_term(index, schema, field, json_path, value, false)
+                    }
+                };
 
                 let lower_bound = match lower {
                     Some(value) => {
-                        let term = term_from_field_value(schema, field, value)?;
+                        let term = bound_term(value)?;
                         if *include_lower {
                             Bound::Included(term)
                         } else {
@@ -833,7 +1156,7 @@ impl TantivyQueryDsl {
 
                 let upper_bound = match upper {
                     Some(value) => {
-                        let term = term_from_field_value(schema, field, value)?;
+                        let term = bound_term(value)?;
                         if *include_upper {
                             Bound::Included(term)
                         } else {
@@ -853,6 +1176,22 @@ impl TantivyQueryDsl {
 
                 Ok(Box::new(RangeQuery::new(lower_bound, upper_bound)))
             }
+            TantivyQueryDsl::Match {
+                field,
+                query,
+                operator,
+                minimum_should_match,
+            } => match_query(
+                index,
+                schema,
+                field,
+                query,
+                *operator,
+                *minimum_should_match,
+            ),
+            TantivyQueryDsl::MatchPhrase { field, query, slop } => {
+                match_phrase_query(index, schema, field, query, *slop)
+            }
             TantivyQueryDsl::Regex { field, pattern } => {
                 let field = schema.get_field(field)?;
                 let query = RegexQuery::from_pattern(pattern, field)?;
@@ -863,19 +1202,34 @@ impl TantivyQueryDsl {
                 term,
                 distance,
                 transpose_cost_one,
-            } => {
+            } => fuzzy_query(index, schema, field, term, *distance, *transpose_cost_one),
+            TantivyQueryDsl::Exists { field: path } => {
+                let (field, _) = resolve_field_path(schema, path)?;
+                // Any value below a JSON path counts, e.g. `metadata.vendor.country`
+                // for `metadata.vendor`.
+                let json_subpaths = matches!(
+                    schema.get_field_entry(field).field_type(),
+                    FieldType::JsonObject(_)
+                );
+                Ok(Box::new(ExistsQuery::new(path.clone(), json_subpaths)))
+            }
+            TantivyQueryDsl::Cidr { field, cidr } => {
                 let field = schema.get_field(field)?;
-                let term = Term::from_field_text(field, term);
-                Ok(Box::new(FuzzyTermQuery::new(
-                    term,
-                    *distance,
-                    *transpose_cost_one,
+                if !matches!(
+                    schema.get_field_entry(field).field_type(),
+                    FieldType::IpAddr(_)
+                ) {
+                    return Err(TantivyIndexError::QueryError(format!(
+                        "CIDR query requires an IP address field: {}",
+                        schema.get_field_name(field)
+                    )));
+                }
+                let range = cidr_range(cidr)?;
+                Ok(Box::new(RangeQuery::new(
+                    Bound::Included(Term::from_field_ip_addr(field, *range.start())),
+                    Bound::Included(Term::from_field_ip_addr(field, *range.end())),
                 )))
             }
-            TantivyQueryDsl::Exists { field } => {
-                let _ = schema.get_field(field)?;
-                Ok(Box::new(ExistsQuery::new(field.clone(), false)))
-            }
             TantivyQueryDsl::Boost { query, boost } => {
                 let query = query.to_query(index, schema)?;
                 Ok(Box::new(BoostQuery::new(query, *boost)))
@@ -904,11 +1258,8 @@ impl TantivyQueryDsl {
                 query,
                 default_fields,
                 fuzzy_fields,
-            } => {
-                let query_parser = build_query_parser(index, schema, default_fields, fuzzy_fields)?;
-                let parsed_query = query_parser.parse_query_lenient(query).0;
-                Ok(parsed_query)
-            }
+                strict,
+            } => parse_query_string(index, schema, query, default_fields, fuzzy_fields, *strict),
         }
     }
 }
@@ -919,16 +1270,50 @@ pub struct TantivySearchHit {
     pub score: f32,
 }
 
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyIdHit {
+    /// Text, u64 or i64 value of the ID field.
+    pub id: FieldValue,
+    pub score: f32,
+    pub sort_values: Vec<Option<FieldValue>>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyIdSearchResults {
+    pub count: u64,
+    pub hits: Vec<TantivyIdHit>,
+    /// Opaque cursor to fetch the page after this one, `None` on the last page.
+    pub next_cursor: Option<String>,
+}
+
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct TantivySearchResult {
     pub score: f32,
     pub doc: TantivyDocumentFields,
+    /// Values of the requested sort fields, `None` when the document has no value.
+    pub sort_values: Vec<Option<FieldValue>>,
+    /// Highlighted snippets of the requested snippet fields that matched the query.
+    pub snippets: Vec<TantivySnippet>,
 }
 
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct TantivySearchResults {
     pub count: u64,
     pub docs: Vec<TantivySearchResult>,
+    /// Opaque cursor to fetch the page after this one, `None` on the last page.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyFacetCount {
+    pub path: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyFacetCounts {
+    pub root: String,
+    pub children: Vec<TantivyFacetCount>,
 }
 
 #[uniffi::export]
@@ -937,6 +1322,16 @@ impl TantivyIndex {
     pub fn new_with_schema(
         path: String,
         schema_builder: &TantivySchemaBuilder,
+    ) -> Result<Self, TantivyIndexError> {
+        Self::new_with_options(path, schema_builder, TantivyIndexOptions::default())
+    }
+
+    /// Like `new_with_schema`, sizing the writer or leaving it out.
+    #[uniffi::constructor]
+    pub fn new_with_options(
+        path: String,
+        schema_builder: &TantivySchemaBuilder,
+        options: TantivyIndexOptions,
     ) -> Result<Self, TantivyIndexError> {
         let index_path = Path::new(&path);
 
@@ -955,34 +1350,52 @@ impl TantivyIndex {
             TantivyIndexError::SchemaBuilderError("Schema already built or empty".to_string())
         })?;
 
-        let index = match Index::open_or_create(directory, schema) {
-            Ok(idx) => idx,
-            Err(e) => return Err(TantivyIndexError::TantivyError(e)),
+        let index = if Index::exists(&directory).map_err(TantivyError::from)? {
+            let index = Index::open(directory)?;
+            let diff = diff_schemas(&index.schema(), &schema)?;
+            if !diff.is_empty() {
+                return Err(TantivyIndexError::SchemaMismatch(diff.to_string()));
+            }
+            index
+        } else {
+            Index::create(directory, schema, IndexSettings::default())?
         };
 
-        let tokenizer = TextAnalyzer::builder(UnicodeTokenizer::default())
-            .filter(LowerCaser)
-            .filter(AsciiFoldingFilter)
-            .build();
-
-        index.tokenizers().register("unicode", tokenizer);
+        register_tokenizers(
+            &index,
+            index_path,
+            &schema_builder.take_analyzers(),
+            options.writable,
+        )?;
+        let writer = options.create_writer(&index)?;
+        Self::from_index(index, writer)
+    }
 
-        let writer = index.writer(100_000_000)?;
-        let reader = index.reader()?;
+    /// Opens an existing index with the schema saved in it.
+    #[uniffi::constructor]
+    pub fn open(path: String, options: TantivyIndexOptions) -> Result<Self, TantivyIndexError> {
+        let index_path = Path::new(&path);
+        let index = Index::open(MmapDirectory::open(index_path)?)?;
+        register_tokenizers(&index, index_path, &[], false)?;
+        let writer = options.create_writer(&index)?;
+        Self::from_index(index, writer)
+    }
 
-        Ok(TantivyIndex {
-            index,
-            writer: Mutex::new(writer),
-            reader,
-        })
+    /// Opens an existing index for searching only, with the schema it was created with.
+    ///
+    /// No writer is created, so no directory lock is taken and the index can be
+    /// searched while another process writes to it. Its commits show up shortly after.
+    #[uniffi::constructor]
+    pub fn open_read_only(path: String) -> Result<Self, TantivyIndexError> {
+        let index_path = Path::new(&path);
+        let index = Index::open(MmapDirectory::open(index_path)?)?;
+        register_tokenizers(&index, index_path, &[], false)?;
+        Self::from_index(index, None)
     }
 
     #[uniffi::method]
     fn clear_index(&self) -> Result<(), TantivyIndexError> {
-        let mut writer = match self.writer.lock() {
-            Ok(wtr) => wtr,
-            Err(_) => return Err(TantivyIndexError::WriterAcquisitionError),
-        };
+        let mut writer = self.lock_writer()?;
 
         writer.delete_all_documents()?;
         writer.commit()?;
@@ -995,18 +1408,9 @@ impl TantivyIndex {
     fn index_doc(&self, doc: TantivyDocumentFields) -> Result<(), TantivyIndexError> {
         let schema = self.index.schema();
 
-        let writer = match self.writer.lock() {
-            Ok(wtr) => wtr,
-            Err(_) => return Err(TantivyIndexError::WriterAcquisitionError),
-        };
-
-        let mut tantivy_doc = TantivyDocument::default();
-        for field in doc.fields {
-            if let Ok(field_handle) = schema.get_field(&field.name) {
-                add_field_value(&mut tantivy_doc, field_handle, &field.value)?;
-            }
-        }
+        let writer = self.lock_writer()?;
 
+        let tantivy_doc = tantivy_doc_from_fields(&schema, &doc)?;
         writer.add_document(tantivy_doc)?;
 
         Ok(())
@@ -1016,18 +1420,48 @@ impl TantivyIndex {
     fn index_docs(&self, docs: Vec<TantivyDocumentFields>) -> Result<(), TantivyIndexError> {
         let schema = self.index.schema();
 
-        let writer = match self.writer.lock() {
-            Ok(wtr) => wtr,
-            Err(_) => return Err(TantivyIndexError::WriterAcquisitionError),
-        };
+        let writer = self.lock_writer()?;
 
         for doc in docs {
-            let mut tantivy_doc = TantivyDocument::default();
-            for field in doc.fields {
-                if let Ok(field_handle) = schema.get_field(&field.name) {
-                    add_field_value(&mut tantivy_doc, field_handle, &field.value)?;
-                }
-            }
+            let tantivy_doc = tantivy_doc_from_fields(&schema, &doc)?;
+            writer.add_document(tantivy_doc)?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces any document sharing `id_field`'s value with `doc`.
+    ///
+    /// The delete and the add are staged under the same writer lock, so they land
+    /// in the same commit and readers never observe the document as missing.
+    #[uniffi::method]
+    fn upsert_doc(
+        &self,
+        id_field: String,
+        doc: TantivyDocumentFields,
+    ) -> Result<(), TantivyIndexError> {
+        self.upsert_docs(id_field, vec![doc])
+    }
+
+    #[uniffi::method]
+    fn upsert_docs(
+        &self,
+        id_field: String,
+        docs: Vec<TantivyDocumentFields>,
+    ) -> Result<(), TantivyIndexError> {
+        let schema = self.index.schema();
+
+        let mut staged = Vec::with_capacity(docs.len());
+        for doc in &docs {
+            let term = id_term_from_document(&self.index, &schema, &id_field, doc)?;
+            let tantivy_doc = tantivy_doc_from_fields(&schema, doc)?;
+            staged.push((term, tantivy_doc));
+        }
+
+        let writer = self.lock_writer()?;
+
+        for (term, tantivy_doc) in staged {
+            writer.delete_term(term);
             writer.add_document(tantivy_doc)?;
         }
 
@@ -1036,10 +1470,7 @@ impl TantivyIndex {
 
     #[uniffi::method]
     fn commit(&self) -> Result<(), TantivyIndexError> {
-        let mut writer = match self.writer.lock() {
-            Ok(wtr) => wtr,
-            Err(_) => return Err(TantivyIndexError::WriterAcquisitionError),
-        };
+        let mut writer = self.lock_writer()?;
 
         writer.commit()?;
         self.reader.reload()?;
@@ -1050,12 +1481,9 @@ impl TantivyIndex {
     #[uniffi::method]
     fn delete_doc(&self, id: DocumentField) -> Result<(), TantivyIndexError> {
         let schema = self.index.schema();
-        let term = term_from_document_field(&schema, &id)?;
+        let term = term_from_document_field(&self.index, &schema, &id)?;
 
-        let mut writer = match self.writer.lock() {
-            Ok(wtr) => wtr,
-            Err(_) => return Err(TantivyIndexError::WriterAcquisitionError),
-        };
+        let mut writer = self.lock_writer()?;
 
         writer.delete_term(term);
         writer.commit()?;
@@ -1064,10 +1492,40 @@ impl TantivyIndex {
         Ok(())
     }
 
+    /// Stages the deletion of every document matching one of `ids` without committing.
+    ///
+    /// Returns how many documents currently visible to the reader matched.
+    #[uniffi::method]
+    fn delete_docs(&self, ids: Vec<DocumentField>) -> Result<u64, TantivyIndexError> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let schema = self.index.schema();
+        let mut terms = Vec::with_capacity(ids.len());
+        for id in &ids {
+            terms.push(term_from_document_field(&self.index, &schema, id)?);
+        }
+
+        self.stage_delete_query(Box::new(TermSetQuery::new(terms)))
+    }
+
+    /// Stages the deletion of every document matching the query DSL without committing.
+    ///
+    /// Returns how many documents currently visible to the reader matched.
+    #[uniffi::method]
+    fn delete_by_query(&self, query_json: String) -> Result<u64, TantivyIndexError> {
+        let schema = self.index.schema();
+        let query_dsl: TantivyQueryDsl = serde_json::from_str(&query_json)?;
+        let query = query_dsl.to_query(&self.index, &schema)?;
+
+        self.stage_delete_query(query)
+    }
+
     #[uniffi::method]
     fn doc_exists(&self, id: DocumentField) -> Result<bool, TantivyIndexError> {
         let schema = self.index.schema();
-        let term = term_from_document_field(&schema, &id)?;
+        let term = term_from_document_field(&self.index, &schema, &id)?;
 
         let searcher = self.reader.searcher();
         let query = TermQuery::new(term, IndexRecordOption::Basic);
@@ -1076,36 +1534,42 @@ impl TantivyIndex {
         Ok(!top_docs.is_empty())
     }
 
-    #[uniffi::method]
-    fn get_doc(&self, id: DocumentField) -> Result<TantivyDocumentFields, TantivyIndexError> {
+    #[uniffi::method(default(fields = None))]
+    fn get_doc(
+        &self,
+        id: DocumentField,
+        fields: Option<TantivyFieldProjection>,
+    ) -> Result<TantivyDocumentFields, TantivyIndexError> {
         let schema = self.index.schema();
-        let term = term_from_document_field(&schema, &id)?;
+        let projector = FieldProjector::new(&schema, fields.as_ref())?;
+        let term = term_from_document_field(&self.index, &schema, &id)?;
 
         let searcher = self.reader.searcher();
         let query = TermQuery::new(term, IndexRecordOption::Basic);
         let top_docs = searcher.search(&query, &TopDocs::with_limit(1))?;
 
         if let Some((_, doc_address)) = top_docs.first() {
-            let retrieved_doc: TantivyDocument = searcher.doc(*doc_address)?;
-            doc_to_fields(&schema, retrieved_doc)
+            projector.project(&schema, &searcher, *doc_address, None)
         } else {
-            Err(TantivyIndexError::DocRetrievalError(format!("{}", id.name)))
+            Err(TantivyIndexError::DocRetrievalError(id.name.to_string()))
         }
     }
 
-    #[uniffi::method]
+    #[uniffi::method(default(fields = None))]
     fn get_docs_by_ids(
         &self,
         ids: Vec<DocumentField>,
+        fields: Option<TantivyFieldProjection>,
     ) -> Result<Vec<TantivyDocumentFields>, TantivyIndexError> {
         if ids.is_empty() {
             return Ok(Vec::new());
         }
 
         let schema = self.index.schema();
+        let projector = FieldProjector::new(&schema, fields.as_ref())?;
         let mut terms = Vec::with_capacity(ids.len());
         for id in &ids {
-            terms.push(term_from_document_field(&schema, id)?);
+            terms.push(term_from_document_field(&self.index, &schema, id)?);
         }
 
         let searcher = self.reader.searcher();
@@ -1114,8 +1578,7 @@ impl TantivyIndex {
 
         let mut docs = Vec::with_capacity(top_docs.len());
         for (_, doc_address) in top_docs {
-            let retrieved_doc: TantivyDocument = searcher.doc(doc_address)?;
-            docs.push(doc_to_fields(&schema, retrieved_doc)?);
+            docs.push(projector.project(&schema, &searcher, doc_address, None)?);
         }
 
         Ok(docs)
@@ -1127,118 +1590,872 @@ impl TantivyIndex {
         searcher.num_docs()
     }
 
+    /// Returns the u64 IDs of the hits, read from the fast column of `id_field`.
+    ///
+    /// Hits start after `cursor` when set. Only IDs are returned, so `fields` and
+    /// `snippet_fields` fail with `QueryError` when set.
     #[uniffi::method]
     fn search_doc_ids(
         &self,
         query: TantivySearchQuery,
         id_field: String,
     ) -> Result<Vec<TantivySearchHit>, TantivyIndexError> {
+        if query.fields.is_some() || !query.snippet_fields.is_empty() {
+            return Err(TantivyIndexError::QueryError(
+                "search_doc_ids returns IDs only, without fields or snippets".to_string(),
+            ));
+        }
         let schema = self.index.schema();
-        let query_parser = build_query_parser(
+        let parsed_query = parse_query_string(
             &self.index,
             &schema,
+            &query.query_str,
             &query.default_fields,
             &query.fuzzy_fields,
+            query.strict,
         )?;
 
-        let parsed_query = query_parser.parse_query_lenient(&query.query_str).0;
-
         let limit: usize = query.top_doc_limit.try_into()?;
         let offset: usize = query.top_doc_offset.try_into()?;
-
-        let searcher = self.reader.searcher();
-        let top_docs = searcher.search(
+        let cursor = query
+            .cursor
+            .as_deref()
+            .map(SearchCursor::decode)
+            .transpose()?;
+
+        let searcher = self.cursor_searcher(cursor.as_ref())?;
+        let (_, top_docs) = search_top_docs(
+            &searcher,
+            &schema,
             &parsed_query,
-            &TopDocs::with_limit(limit).and_offset(offset),
+            limit,
+            offset,
+            &query.sort,
+            cursor.as_ref(),
         )?;
 
         let _ = schema.get_field(&id_field)?;
         let mut hits = Vec::with_capacity(top_docs.len());
 
-        for (score, doc_address) in top_docs {
+        for ranked in top_docs {
+            let doc_address = ranked.address;
             let segment_reader = searcher.segment_reader(doc_address.segment_ord);
             let fast_field = segment_reader.fast_fields().u64(&id_field)?;
             if let Some(doc_id) = fast_field.first(doc_address.doc_id) {
-                hits.push(TantivySearchHit { doc_id, score });
+                hits.push(TantivySearchHit {
+                    doc_id,
+                    score: ranked.score,
+                });
             }
         }
 
         Ok(hits)
     }
 
-    #[uniffi::method]
-    fn search_dsl(
+    /// Returns the IDs of the documents matching the query DSL, read from the fast
+    /// column of `id_field` without loading stored documents. A hit without an ID
+    /// fails the search with `MissingIdField`.
+    #[uniffi::method(default(sort = [], cursor = None))]
+    fn search_ids_dsl(
         &self,
         query_json: String,
+        id_field: String,
         top_doc_limit: u32,
         top_doc_offset: u32,
-    ) -> Result<TantivySearchResults, TantivyIndexError> {
+        sort: Vec<TantivySortField>,
+        cursor: Option<String>,
+    ) -> Result<TantivyIdSearchResults, TantivyIndexError> {
         let schema = self.index.schema();
+        let field = schema.get_field(&id_field)?;
+        if !is_fast_id_field(&schema, field) {
+            return Err(TantivyIndexError::QueryError(format!(
+                "ID field {} must be a fast text, u64 or i64 field",
+                id_field
+            )));
+        }
+
         let query_dsl: TantivyQueryDsl = serde_json::from_str(&query_json)?;
         let query = query_dsl.to_query(&self.index, &schema)?;
 
         let limit: usize = top_doc_limit.try_into()?;
         let offset: usize = top_doc_offset.try_into()?;
+        let cursor = cursor.as_deref().map(SearchCursor::decode).transpose()?;
 
-        let searcher = self.reader.searcher();
-        let (doc_count, top_docs) = searcher.search(
+        let searcher = self.cursor_searcher(cursor.as_ref())?;
+        let (doc_count, top_docs) = search_top_docs(
+            &searcher,
+            &schema,
             &query,
-            &(Count, TopDocs::with_limit(limit).and_offset(offset)),
+            limit + 1,
+            offset,
+            &sort,
+            cursor.as_ref(),
         )?;
 
-        let mut docs = Vec::with_capacity(top_docs.len());
-        for (score, doc_address) in top_docs {
-            let retrieved_doc: TantivyDocument = searcher.doc(doc_address)?;
-            let doc_fields = doc_to_fields(&schema, retrieved_doc)?;
-            docs.push(TantivySearchResult {
-                score,
-                doc: doc_fields,
+        let (top_docs, next_cursor) = take_page(top_docs, limit, &searcher, &sort)?;
+        if next_cursor.is_some() {
+            self.pin_searcher(&searcher)?;
+        }
+
+        let mut hits = Vec::with_capacity(top_docs.len());
+        for ranked in top_docs {
+            let id = fast_id_value(&schema, &searcher, ranked.address, field)?
+                .ok_or_else(|| TantivyIndexError::MissingIdField(id_field.clone()))?;
+            hits.push(TantivyIdHit {
+                id,
+                score: ranked.score,
+                sort_values: ranked.sort_values,
             });
         }
 
-        Ok(TantivySearchResults {
+        Ok(TantivyIdSearchResults {
             count: doc_count as u64,
-            docs,
+            hits,
+            next_cursor,
         })
     }
 
+    #[uniffi::method(default(sort = [], snippet_fields = [], cursor = None, fields = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn search_dsl(
+        &self,
+        query_json: String,
+        top_doc_limit: u32,
+        top_doc_offset: u32,
+        sort: Vec<TantivySortField>,
+        snippet_fields: Vec<TantivySnippetField>,
+        cursor: Option<String>,
+        fields: Option<TantivyFieldProjection>,
+    ) -> Result<TantivySearchResults, TantivyIndexError> {
+        let schema = self.index.schema();
+        let query_dsl: TantivyQueryDsl = serde_json::from_str(&query_json)?;
+        let query = query_dsl.to_query(&self.index, &schema)?;
+
+        self.search_page(
+            &schema,
+            &query,
+            top_doc_limit,
+            top_doc_offset,
+            &sort,
+            &snippet_fields,
+            cursor.as_deref(),
+            fields.as_ref(),
+        )
+    }
+
+    /// Counts the direct children of each facet in `root_paths` across the documents
+    /// matching the query DSL, sorted by descending count.
+    #[uniffi::method]
+    fn facet_counts(
+        &self,
+        query_json: String,
+        field: String,
+        root_paths: Vec<String>,
+        top_k: Option<u32>,
+    ) -> Result<Vec<TantivyFacetCounts>, TantivyIndexError> {
+        let schema = self.index.schema();
+        let facet_field = schema.get_field(&field)?;
+        if !matches!(
+            schema.get_field_entry(facet_field).field_type(),
+            FieldType::Facet(_)
+        ) {
+            return Err(TantivyIndexError::QueryError(format!(
+                "Field {} is not a facet field",
+                field
+            )));
+        }
+
+        let query_dsl: TantivyQueryDsl = serde_json::from_str(&query_json)?;
+        let query = query_dsl.to_query(&self.index, &schema)?;
+
+        let mut roots = Vec::with_capacity(root_paths.len().max(1));
+        for path in &root_paths {
+            roots.push(Facet::from_text(path)?);
+        }
+        if roots.is_empty() {
+            roots.push(Facet::root());
+        }
+
+        let mut collector = FacetCollector::for_field(&field);
+        for (idx, root) in roots.iter().enumerate() {
+            // FacetCollector panics on overlapping roots, reject them up front.
+            if roots[..idx]
+                .iter()
+                .any(|other| other.is_prefix_of(root) || root.is_prefix_of(other))
+            {
+                return Err(TantivyIndexError::QueryError(format!(
+                    "Facet root {} overlaps with another requested root",
+                    root
+                )));
+            }
+            collector.add_facet(root.clone());
+        }
+
+        let searcher = self.reader.searcher();
+        let counts = searcher.search(&query, &collector)?;
+
+        let mut results = Vec::with_capacity(roots.len());
+        for root in roots {
+            let mut children = match top_k {
+                Some(k) => counts
+                    .top_k(root.clone(), k.try_into()?)
+                    .into_iter()
+                    .map(|(facet, count)| TantivyFacetCount {
+                        path: facet.to_path_string(),
+                        count,
+                    })
+                    .collect::<Vec<_>>(),
+                None => counts
+                    .get(root.clone())
+                    .map(|(facet, count)| TantivyFacetCount {
+                        path: facet.to_path_string(),
+                        count,
+                    })
+                    .collect::<Vec<_>>(),
+            };
+            children.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.path.cmp(&b.path)));
+            results.push(TantivyFacetCounts {
+                root: root.to_path_string(),
+                children,
+            });
+        }
+
+        Ok(results)
+    }
+
     #[uniffi::method]
     fn search(&self, query: TantivySearchQuery) -> Result<TantivySearchResults, TantivyIndexError> {
         let schema = self.index.schema();
-        let query_parser = build_query_parser(
+        let parsed_query = parse_query_string(
             &self.index,
             &schema,
+            &query.query_str,
             &query.default_fields,
             &query.fuzzy_fields,
+            query.strict,
         )?;
 
-        let parsed_query = query_parser.parse_query_lenient(&query.query_str).0;
+        self.search_page(
+            &schema,
+            &parsed_query,
+            query.top_doc_limit,
+            query.top_doc_offset,
+            &query.sort,
+            &query.snippet_fields,
+            query.cursor.as_deref(),
+            query.fields.as_ref(),
+        )
+    }
+}
 
-        let limit: usize = query.top_doc_limit.try_into()?;
-        let offset: usize = query.top_doc_offset.try_into()?;
+/// Registers the `unicode` tokenizer and the n-gram tokenizers and analyzers the
+/// schema refers to.
+fn register_tokenizers(
+    index: &Index,
+    index_path: &Path,
+    analyzers: &[TantivyAnalyzerDefinition],
+    save_analyzers: bool,
+) -> Result<(), TantivyIndexError> {
+    let tokenizer = TextAnalyzer::builder(UnicodeTokenizer::default())
+        .filter(LowerCaser)
+        .filter(AsciiFoldingFilter)
+        .build();
+
+    register_analyzer(index, "unicode", tokenizer);
+    register_ngram_tokenizers(index)?;
+    register_analyzers(index, index_path, analyzers, save_analyzers)
+}
 
-        let searcher = self.reader.searcher();
-        let (doc_count, top_docs) = searcher.search(
-            &parsed_query,
-            &(Count, TopDocs::with_limit(limit).and_offset(offset)),
+impl TantivyIndex {
+    fn from_index(index: Index, writer: Option<IndexWriter>) -> Result<Self, TantivyIndexError> {
+        let reader = index.reader()?;
+        Ok(TantivyIndex {
+            index,
+            writer: Mutex::new(writer),
+            reader,
+            pinned_searchers: Mutex::new(VecDeque::new()),
+        })
+    }
+
+    fn lock_writer(&self) -> Result<WriterGuard<'_>, TantivyIndexError> {
+        let writer = match self.writer.lock() {
+            Ok(wtr) => wtr,
+            Err(_) => return Err(TantivyIndexError::WriterAcquisitionError),
+        };
+        if writer.is_none() {
+            return Err(TantivyIndexError::ReadOnlyIndex);
+        }
+        Ok(WriterGuard(writer))
+    }
+
+    fn lock_pinned_searchers(
+        &self,
+    ) -> Result<MutexGuard<'_, VecDeque<Searcher>>, TantivyIndexError> {
+        self.pinned_searchers
+            .lock()
+            .map_err(|_| TantivyIndexError::SearcherAcquisitionError)
+    }
+
+    /// Returns the searcher a cursor was created with, or the latest one.
+    fn cursor_searcher(
+        &self,
+        cursor: Option<&SearchCursor>,
+    ) -> Result<Searcher, TantivyIndexError> {
+        let Some(cursor) = cursor else {
+            return Ok(self.reader.searcher());
+        };
+        self.lock_pinned_searchers()?
+            .iter()
+            .find(|searcher| searcher.generation().generation_id() == cursor.generation)
+            .cloned()
+            .ok_or_else(|| TantivyIndexError::QueryError("Search cursor has expired".to_string()))
+    }
+
+    /// Keeps the searcher of a page that returned a next cursor, so the following
+    /// pages read the same generation.
+    fn pin_searcher(&self, searcher: &Searcher) -> Result<(), TantivyIndexError> {
+        let mut pinned = self.lock_pinned_searchers()?;
+        let generation_id = searcher.generation().generation_id();
+        if !pinned
+            .iter()
+            .any(|pinned| pinned.generation().generation_id() == generation_id)
+        {
+            if pinned.len() == MAX_PINNED_SEARCHERS {
+                pinned.pop_front();
+            }
+            pinned.push_back(searcher.clone());
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn search_page(
+        &self,
+        schema: &Schema,
+        query: &dyn tantivy::query::Query,
+        top_doc_limit: u32,
+        top_doc_offset: u32,
+        sort: &[TantivySortField],
+        snippet_fields: &[TantivySnippetField],
+        cursor: Option<&str>,
+        fields: Option<&TantivyFieldProjection>,
+    ) -> Result<TantivySearchResults, TantivyIndexError> {
+        let limit: usize = top_doc_limit.try_into()?;
+        let offset: usize = top_doc_offset.try_into()?;
+        let cursor = cursor.map(SearchCursor::decode).transpose()?;
+
+        let searcher = self.cursor_searcher(cursor.as_ref())?;
+        let (doc_count, top_docs) = search_top_docs(
+            &searcher,
+            schema,
+            query,
+            limit + 1,
+            offset,
+            sort,
+            cursor.as_ref(),
         )?;
+        let snippet_builder = SnippetBuilder::new(&searcher, schema, query, snippet_fields)?;
+        let projector = FieldProjector::new(schema, fields)?;
 
-        let mut top_doc_items: Vec<TantivySearchResult> = Vec::new();
+        let (top_docs, next_cursor) = take_page(top_docs, limit, &searcher, sort)?;
+        if next_cursor.is_some() {
+            self.pin_searcher(&searcher)?;
+        }
 
-        for (score, doc_address) in top_docs {
-            let retrieved_doc: TantivyDocument = searcher.doc(doc_address)?;
-            let doc_fields = doc_to_fields(&schema, retrieved_doc)?;
-            top_doc_items.push(TantivySearchResult {
+        let mut docs = Vec::with_capacity(top_docs.len());
+        for ranked in top_docs {
+            let mut snippets = Vec::new();
+            let mut retrieved_doc = None;
+            if !snippet_fields.is_empty() {
+                let doc: TantivyDocument = searcher.doc(ranked.address)?;
+                snippets = snippet_builder.snippets(&doc);
+                retrieved_doc = Some(doc);
+            }
+            let doc_fields = projector.project(schema, &searcher, ranked.address, retrieved_doc)?;
+            docs.push(TantivySearchResult {
+                score: ranked.score,
                 doc: doc_fields,
-                score,
+                sort_values: ranked.sort_values,
+                snippets,
             });
         }
 
         Ok(TantivySearchResults {
             count: doc_count as u64,
-            docs: top_doc_items,
+            docs,
+            next_cursor,
         })
     }
+
+    fn stage_delete_query(
+        &self,
+        query: Box<dyn tantivy::query::Query>,
+    ) -> Result<u64, TantivyIndexError> {
+        let searcher = self.reader.searcher();
+        let matched = searcher.search(&query, &Count)?;
+
+        let writer = self.lock_writer()?;
+        writer.delete_query(query)?;
+
+        Ok(matched as u64)
+    }
 }
 
 uniffi::setup_scaffolding!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) fn receipt_schema() -> TantivySchemaBuilder {
+        let builder = TantivySchemaBuilder::new();
+        builder.add_text_field(
+            "id".to_string(),
+            TextFieldOptions {
+                tokenizer: TantivyTokenizer::Raw,
+                record: TantivyIndexRecordOption::Basic,
+                stored: true,
+                fast: true,
+                fieldnorms: false,
+            },
+        );
+        builder.add_text_field("merchant".to_string(), TextFieldOptions::default());
+        builder.add_f64_field(
+            "total".to_string(),
+            NumericFieldOptions {
+                fast: true,
+                ..NumericFieldOptions::default()
+            },
+        );
+        builder.add_date_field(
+            "purchased".to_string(),
+            DateFieldOptions {
+                fast: true,
+                precision: TantivyDatePrecision::Microseconds,
+                ..DateFieldOptions::default()
+            },
+        );
+        builder.add_facet_field("tags".to_string(), FacetFieldOptions::default());
+        builder
+    }
+
+    pub(crate) fn temp_index_path(name: &str) -> String {
+        let path =
+            std::env::temp_dir().join(format!("tantivy_swift_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        path.to_string_lossy().to_string()
+    }
+
+    pub(crate) fn make_index_with(name: &str, builder: &TantivySchemaBuilder) -> TantivyIndex {
+        TantivyIndex::new_with_schema(temp_index_path(name), builder).unwrap()
+    }
+
+    pub(crate) fn make_index(name: &str) -> TantivyIndex {
+        make_index_with(name, &receipt_schema())
+    }
+
+    pub(crate) fn receipt(id: &str, merchant: &str, total: f64) -> TantivyDocumentFields {
+        TantivyDocumentFields {
+            fields: vec![
+                DocumentField {
+                    name: "id".to_string(),
+                    value: FieldValue::Text(id.to_string()),
+                },
+                DocumentField {
+                    name: "merchant".to_string(),
+                    value: FieldValue::Text(merchant.to_string()),
+                },
+                DocumentField {
+                    name: "total".to_string(),
+                    value: FieldValue::F64(total),
+                },
+            ],
+        }
+    }
+
+    pub(crate) fn id_field(id: &str) -> DocumentField {
+        DocumentField {
+            name: "id".to_string(),
+            value: FieldValue::Text(id.to_string()),
+        }
+    }
+
+    pub(crate) fn merchant_of(doc: &TantivyDocumentFields) -> Option<&str> {
+        doc.fields
+            .iter()
+            .find_map(|field| match (&field.name[..], &field.value) {
+                ("merchant", FieldValue::Text(text)) => Some(text.as_str()),
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn upsert_replaces_existing_document_in_one_commit() {
+        let index = make_index("upsert");
+        index
+            .index_docs(vec![
+                receipt("r1", "Costco", 10.0),
+                receipt("r2", "Target", 20.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        index
+            .upsert_doc("id".to_string(), receipt("r1", "Sam's Club", 12.5))
+            .unwrap();
+        assert_eq!(index.docs_count(), 2);
+        index.commit().unwrap();
+
+        assert_eq!(index.docs_count(), 2);
+        let doc = index.get_doc(id_field("r1"), None).unwrap();
+        assert_eq!(merchant_of(&doc), Some("Sam's Club"));
+    }
+
+    #[test]
+    fn batched_deletes_are_staged_until_commit() {
+        let index = make_index("delete_docs");
+        index
+            .index_docs(vec![
+                receipt("r1", "Costco", 10.0),
+                receipt("r2", "Target", 20.0),
+                receipt("r3", "Costco Gas", 30.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let matched = index
+            .delete_docs(vec![id_field("r1"), id_field("missing")])
+            .unwrap();
+        assert_eq!(matched, 1);
+        assert_eq!(index.docs_count(), 3);
+        index.commit().unwrap();
+        assert_eq!(index.docs_count(), 2);
+
+        let query = r#"{"type":"range","field":"total","lower":{"type":"f64","value":15.0},"upper":null,"include_lower":true,"include_upper":false}"#;
+        let matched = index.delete_by_query(query.to_string()).unwrap();
+        assert_eq!(matched, 2);
+        assert_eq!(index.docs_count(), 2);
+        index.commit().unwrap();
+        assert_eq!(index.docs_count(), 0);
+    }
+
+    fn tagged_receipt(id: &str, tags: &[&str]) -> TantivyDocumentFields {
+        let mut doc = receipt(id, "Costco", 1.0);
+        for tag in tags {
+            doc.fields.push(DocumentField {
+                name: "tags".to_string(),
+                value: FieldValue::Facet(tag.to_string()),
+            });
+        }
+        doc
+    }
+
+    #[test]
+    fn facet_counts_children_of_each_root() {
+        let index = make_index("facet_counts");
+        index
+            .index_docs(vec![
+                tagged_receipt("r1", &["/receipt/tags/groceries", "/store/costco"]),
+                tagged_receipt("r2", &["/receipt/tags/groceries", "/store/target"]),
+                tagged_receipt("r3", &["/receipt/tags/home", "/store/costco"]),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let counts = index
+            .facet_counts(
+                r#"{"type":"all"}"#.to_string(),
+                "tags".to_string(),
+                vec!["/receipt/tags".to_string(), "/store".to_string()],
+                Some(1),
+            )
+            .unwrap();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[0].root, "/receipt/tags");
+        assert_eq!(counts[0].children.len(), 1);
+        assert_eq!(counts[0].children[0].path, "/receipt/tags/groceries");
+        assert_eq!(counts[0].children[0].count, 2);
+
+        let all = index
+            .facet_counts(
+                r#"{"type":"all"}"#.to_string(),
+                "tags".to_string(),
+                Vec::new(),
+                None,
+            )
+            .unwrap();
+        let top_level = all[0]
+            .children
+            .iter()
+            .map(|child| (child.path.as_str(), child.count))
+            .collect::<Vec<_>>();
+        assert_eq!(top_level, vec![("/receipt", 3), ("/store", 3)]);
+
+        assert!(
+            index
+                .facet_counts(
+                    r#"{"type":"all"}"#.to_string(),
+                    "tags".to_string(),
+                    vec!["/store".to_string(), "/store/costco".to_string()],
+                    None,
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn upsert_docs_inserts_new_and_requires_id_field() {
+        let index = make_index("upsert_docs");
+        index
+            .upsert_docs(
+                "id".to_string(),
+                vec![
+                    receipt("r1", "Costco", 10.0),
+                    receipt("r1", "Walmart", 11.0),
+                ],
+            )
+            .unwrap();
+        index.commit().unwrap();
+        assert_eq!(index.docs_count(), 1);
+        let doc = index.get_doc(id_field("r1"), None).unwrap();
+        assert_eq!(merchant_of(&doc), Some("Walmart"));
+
+        let missing = TantivyDocumentFields { fields: Vec::new() };
+        assert!(matches!(
+            index.upsert_doc("id".to_string(), missing),
+            Err(TantivyIndexError::MissingIdField(_))
+        ));
+    }
+
+    #[test]
+    fn search_ids_dsl_reads_text_ids_from_fast_columns() {
+        let index = make_index("search_ids_dsl");
+        index
+            .index_docs(vec![
+                receipt("r1", "Costco", 10.0),
+                receipt("r2", "Costco Gas", 20.0),
+                receipt("r3", "Target", 30.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let query = r#"{"type":"term","term":{"name":"merchant","value":{"type":"text","value":"costco"}}}"#;
+        let sort = vec![TantivySortField {
+            field: "total".to_string(),
+            order: crate::sort::TantivySortOrder::Desc,
+        }];
+        let page = index
+            .search_ids_dsl(
+                query.to_string(),
+                "id".to_string(),
+                1,
+                0,
+                sort.clone(),
+                None,
+            )
+            .unwrap();
+        assert_eq!(page.count, 2);
+        assert!(matches!(&page.hits[0].id, FieldValue::Text(id) if id == "r2"));
+
+        let next = index
+            .search_ids_dsl(
+                query.to_string(),
+                "id".to_string(),
+                1,
+                0,
+                sort,
+                page.next_cursor,
+            )
+            .unwrap();
+        assert!(matches!(&next.hits[0].id, FieldValue::Text(id) if id == "r1"));
+        // The last page is full, but nothing follows it.
+        assert!(next.next_cursor.is_none());
+
+        assert!(
+            index
+                .search_ids_dsl(
+                    query.to_string(),
+                    "merchant".to_string(),
+                    10,
+                    0,
+                    vec![],
+                    None
+                )
+                .is_err()
+        );
+
+        // Every hit needs an ID, or pages would come back shorter than the count.
+        index
+            .index_doc(TantivyDocumentFields {
+                fields: vec![DocumentField {
+                    name: "merchant".to_string(),
+                    value: FieldValue::Text("Costco".to_string()),
+                }],
+            })
+            .unwrap();
+        index.commit().unwrap();
+        assert!(matches!(
+            index.search_ids_dsl(query.to_string(), "id".to_string(), 10, 0, vec![], None),
+            Err(TantivyIndexError::MissingIdField(_))
+        ));
+    }
+
+    #[test]
+    fn search_doc_ids_starts_after_the_cursor() {
+        let builder = receipt_schema();
+        builder.add_u64_field(
+            "number".to_string(),
+            NumericFieldOptions {
+                fast: true,
+                ..NumericFieldOptions::default()
+            },
+        );
+        let index = make_index_with("search_doc_ids", &builder);
+        let docs = (1..=3u64)
+            .map(|number| {
+                let mut doc = receipt(&format!("r{number}"), "Costco", number as f64);
+                doc.fields.push(DocumentField {
+                    name: "number".to_string(),
+                    value: FieldValue::U64(number),
+                });
+                doc
+            })
+            .collect();
+        index.index_docs(docs).unwrap();
+        index.commit().unwrap();
+
+        let query = |cursor, fields| TantivySearchQuery {
+            query_str: "costco".to_string(),
+            default_fields: vec!["merchant".to_string()],
+            fuzzy_fields: vec![],
+            top_doc_limit: 1,
+            top_doc_offset: 0,
+            sort: vec![TantivySortField {
+                field: "total".to_string(),
+                order: crate::sort::TantivySortOrder::Desc,
+            }],
+            snippet_fields: vec![],
+            cursor,
+            strict: false,
+            fields,
+        };
+        let first = index.search(query(None, None)).unwrap();
+        let hits = index
+            .search_doc_ids(query(first.next_cursor, None), "number".to_string())
+            .unwrap();
+        assert_eq!(
+            hits.iter().map(|hit| hit.doc_id).collect::<Vec<_>>(),
+            vec![2]
+        );
+
+        assert!(matches!(
+            index.search_doc_ids(
+                query(None, Some(TantivyFieldProjection::All)),
+                "number".to_string()
+            ),
+            Err(TantivyIndexError::QueryError(_))
+        ));
+    }
+
+    #[test]
+    fn index_options_size_the_writer_or_leave_it_out() {
+        let path = temp_index_path("index_options");
+
+        let tiny = TantivyIndexOptions {
+            writer_heap_bytes: 1_000_000,
+            ..TantivyIndexOptions::default()
+        };
+        assert!(TantivyIndex::new_with_options(path.clone(), &receipt_schema(), tiny).is_err());
+        let no_threads = TantivyIndexOptions {
+            num_threads: Some(0),
+            ..TantivyIndexOptions::default()
+        };
+        assert!(matches!(
+            TantivyIndex::new_with_options(path.clone(), &receipt_schema(), no_threads),
+            Err(TantivyIndexError::InvalidIndexOptions(_))
+        ));
+
+        let single_thread = TantivyIndexOptions {
+            writer_heap_bytes: 20_000_000,
+            num_threads: Some(1),
+            ..TantivyIndexOptions::default()
+        };
+        let index =
+            TantivyIndex::new_with_options(path.clone(), &receipt_schema(), single_thread).unwrap();
+        index
+            .index_docs(vec![receipt("r1", "costco", 10.0)])
+            .unwrap();
+        index.commit().unwrap();
+
+        // No writer, so no directory lock: it opens next to the writable index.
+        let read_only = TantivyIndex::new_with_options(
+            path.clone(),
+            &receipt_schema(),
+            TantivyIndexOptions {
+                writable: false,
+                ..TantivyIndexOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(read_only.docs_count(), 1);
+        assert!(matches!(
+            read_only.index_doc(receipt("r2", "target", 20.0)),
+            Err(TantivyIndexError::ReadOnlyIndex)
+        ));
+        assert!(matches!(
+            read_only.commit(),
+            Err(TantivyIndexError::ReadOnlyIndex)
+        ));
+
+        assert!(matches!(
+            TantivyIndex::open(
+                path.clone(),
+                TantivyIndexOptions {
+                    num_threads: Some(0),
+                    ..TantivyIndexOptions::default()
+                }
+            ),
+            Err(TantivyIndexError::InvalidIndexOptions(_))
+        ));
+        let reopened = TantivyIndex::open(
+            path,
+            TantivyIndexOptions {
+                writable: false,
+                ..TantivyIndexOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(matches!(
+            reopened.commit(),
+            Err(TantivyIndexError::ReadOnlyIndex)
+        ));
+    }
+
+    #[test]
+    fn open_read_only_uses_the_schema_on_disk() {
+        let path = temp_index_path("read_only");
+        assert!(TantivyIndex::open_read_only(path.clone()).is_err());
+
+        let index = TantivyIndex::new_with_schema(path.clone(), &receipt_schema()).unwrap();
+        index
+            .index_docs(vec![receipt("r1", "Café Costco", 10.0)])
+            .unwrap();
+        index.commit().unwrap();
+
+        // Opens while the writer above holds the directory lock.
+        let read_only = TantivyIndex::open_read_only(path).unwrap();
+        let query = r#"{"type":"match","field":"merchant","query":"cafe"}"#;
+        let results = read_only
+            .search_dsl(query.to_string(), 10, 0, vec![], vec![], None, None)
+            .unwrap();
+        assert_eq!(results.count, 1);
+        assert!(matches!(
+            read_only.upsert_doc("id".to_string(), receipt("r1", "costco", 12.0)),
+            Err(TantivyIndexError::ReadOnlyIndex)
+        ));
+        assert!(matches!(
+            read_only.clear_index(),
+            Err(TantivyIndexError::ReadOnlyIndex)
+        ));
+    }
+}
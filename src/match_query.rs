@@ -0,0 +1,337 @@
+use serde::{Deserialize, Serialize};
+use tantivy::Index;
+use tantivy::Term;
+use tantivy::query::{
+    BooleanQuery, EmptyQuery, FuzzyTermQuery, PhrasePrefixQuery, PhraseQuery, Query, TermQuery,
+};
+use tantivy::schema::{Field, FieldType, IndexRecordOption, Schema};
+
+use crate::TantivyIndexError;
+
+/// How the terms of a match query combine.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TantivyMatchOperator {
+    #[default]
+    Or,
+    And,
+}
+
+struct AnalyzedText {
+    field: Field,
+    record_option: IndexRecordOption,
+    /// Terms with their token positions.
+    terms: Vec<(usize, Term)>,
+}
+
+/// Runs `texts` through the analyzer the field was indexed with, positioning their
+/// tokens one after the other as if they were a single text.
+fn analyze<'a>(
+    index: &Index,
+    schema: &Schema,
+    field_name: &str,
+    texts: impl IntoIterator<Item = &'a str>,
+) -> Result<AnalyzedText, TantivyIndexError> {
+    let field = schema.get_field(field_name)?;
+    let indexing = match schema.get_field_entry(field).field_type() {
+        FieldType::Str(options) => options.get_indexing_options(),
+        _ => None,
+    };
+    let Some(indexing) = indexing else {
+        return Err(TantivyIndexError::QueryError(format!(
+            "Analyzed query requires an indexed text field: {field_name}"
+        )));
+    };
+
+    let mut analyzer = index.tokenizer_for_field(field)?;
+    let mut terms = Vec::new();
+    let mut offset = 0;
+    for text in texts {
+        // Tokenizers may carry positions over from the previous text.
+        let mut first_position = None;
+        let mut next_offset = offset;
+        analyzer.token_stream(text).process(&mut |token| {
+            let position =
+                offset + (token.position - *first_position.get_or_insert(token.position));
+            terms.push((position, Term::from_field_text(field, &token.text)));
+            next_offset = position + 1;
+        });
+        offset = next_offset;
+    }
+    Ok(AnalyzedText {
+        field,
+        record_option: indexing.index_option(),
+        terms,
+    })
+}
+
+/// Matches documents containing the analyzed terms of `text`, any of them with
+/// `Or` (at least `minimum_should_match` if given) or all of them with `And`, which
+/// rejects `minimum_should_match`.
+pub(crate) fn match_query(
+    index: &Index,
+    schema: &Schema,
+    field: &str,
+    text: &str,
+    operator: TantivyMatchOperator,
+    minimum_should_match: Option<u32>,
+) -> Result<Box<dyn Query>, TantivyIndexError> {
+    if operator == TantivyMatchOperator::And && minimum_should_match.is_some() {
+        return Err(TantivyIndexError::QueryError(
+            "minimum_should_match only applies to the or operator".to_string(),
+        ));
+    }
+    let analyzed = analyze(index, schema, field, [text])?;
+    let mut term_queries = analyzed
+        .terms
+        .into_iter()
+        .map(|(_, term)| -> Box<dyn Query> {
+            Box::new(TermQuery::new(term, analyzed.record_option))
+        })
+        .collect::<Vec<_>>();
+
+    match (term_queries.len(), operator) {
+        (0, _) => Ok(Box::new(EmptyQuery)),
+        (1, _) if minimum_should_match.unwrap_or(1) <= 1 => Ok(term_queries.remove(0)),
+        (_, TantivyMatchOperator::And) => Ok(Box::new(BooleanQuery::intersection(term_queries))),
+        (_, TantivyMatchOperator::Or) => {
+            let minimum_should_match = minimum_should_match.unwrap_or(1).max(1).try_into()?;
+            Ok(Box::new(BooleanQuery::union_with_minimum_required_clauses(
+                term_queries,
+                minimum_should_match,
+            )))
+        }
+    }
+}
+
+/// Matches the analyzed terms of `text` in order, `slop` positions apart at most.
+pub(crate) fn match_phrase_query(
+    index: &Index,
+    schema: &Schema,
+    field: &str,
+    text: &str,
+    slop: Option<u32>,
+) -> Result<Box<dyn Query>, TantivyIndexError> {
+    phrase_of(schema, analyze(index, schema, field, [text])?, slop)
+}
+
+/// Matches the analyzed `terms` in order, `slop` positions apart at most.
+pub(crate) fn phrase_query(
+    index: &Index,
+    schema: &Schema,
+    field: &str,
+    terms: &[String],
+    slop: Option<u32>,
+) -> Result<Box<dyn Query>, TantivyIndexError> {
+    let analyzed = analyze(index, schema, field, terms.iter().map(String::as_str))?;
+    phrase_of(schema, analyzed, slop)
+}
+
+fn phrase_of(
+    schema: &Schema,
+    mut analyzed: AnalyzedText,
+    slop: Option<u32>,
+) -> Result<Box<dyn Query>, TantivyIndexError> {
+    match analyzed.terms.len() {
+        0 => return Ok(Box::new(EmptyQuery)),
+        1 => {
+            let (_, term) = analyzed.terms.remove(0);
+            return Ok(Box::new(TermQuery::new(term, analyzed.record_option)));
+        }
+        _ => {}
+    }
+    if !analyzed.record_option.has_positions() {
+        return Err(TantivyIndexError::QueryError(format!(
+            "Match phrase query requires a field indexed with positions: {}",
+            schema.get_field_name(analyzed.field)
+        )));
+    }
+    Ok(Box::new(PhraseQuery::new_with_offset_and_slop(
+        analyzed.terms,
+        slop.unwrap_or(0),
+    )))
+}
+
+/// Matches the analyzed `terms` in order, the last token as a prefix expanded to
+/// `max_expansions` terms at most.
+pub(crate) fn phrase_prefix_query(
+    index: &Index,
+    schema: &Schema,
+    field: &str,
+    terms: &[String],
+    max_expansions: Option<u32>,
+) -> Result<Box<dyn Query>, TantivyIndexError> {
+    let analyzed = analyze(index, schema, field, terms.iter().map(String::as_str))?;
+    if analyzed.terms.is_empty() {
+        return Ok(Box::new(EmptyQuery));
+    }
+    let mut query = PhrasePrefixQuery::new_with_offset(analyzed.terms);
+    if let Some(max_expansions) = max_expansions {
+        query.set_max_expansions(max_expansions);
+    }
+    Ok(Box::new(query))
+}
+
+/// Matches terms within `distance` edits of the analyzed `term`, which has to
+/// analyze to a single token.
+pub(crate) fn fuzzy_query(
+    index: &Index,
+    schema: &Schema,
+    field: &str,
+    term: &str,
+    distance: u8,
+    transpose_cost_one: bool,
+) -> Result<Box<dyn Query>, TantivyIndexError> {
+    let mut analyzed = analyze(index, schema, field, [term])?;
+    match analyzed.terms.len() {
+        0 => Ok(Box::new(EmptyQuery)),
+        1 => {
+            let (_, term) = analyzed.terms.remove(0);
+            Ok(Box::new(FuzzyTermQuery::new(
+                term,
+                distance,
+                transpose_cost_one,
+            )))
+        }
+        _ => Err(TantivyIndexError::QueryError(format!(
+            "Fuzzy query term analyzes to more than one token: {term}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{make_index, receipt};
+    use crate::{TantivyIndex, TantivyQueryDsl};
+
+    use super::*;
+
+    fn count(index: &TantivyIndex, query: TantivyQueryDsl) -> u64 {
+        index
+            .search_dsl(
+                serde_json::to_string(&query).unwrap(),
+                10,
+                0,
+                vec![],
+                vec![],
+                None,
+                None,
+            )
+            .unwrap()
+            .count
+    }
+
+    fn matching(query: &str, operator: TantivyMatchOperator, msm: Option<u32>) -> TantivyQueryDsl {
+        TantivyQueryDsl::Match {
+            field: "merchant".to_string(),
+            query: query.to_string(),
+            operator,
+            minimum_should_match: msm,
+        }
+    }
+
+    fn phrase(query: &str, slop: Option<u32>) -> TantivyQueryDsl {
+        TantivyQueryDsl::MatchPhrase {
+            field: "merchant".to_string(),
+            query: query.to_string(),
+            slop,
+        }
+    }
+
+    #[test]
+    fn match_queries_analyze_input_like_the_field() {
+        let index = make_index("match_query");
+        index
+            .index_docs(vec![
+                receipt("r1", "Sam's Club Wholesale", 10.0),
+                receipt("r2", "Club Café", 20.0),
+                receipt("r3", "Trader Joe's co-op", 30.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        // Terms given one by one are analyzed too, so they find what the analyzer
+        // lowercased and merged.
+        let terms = TantivyQueryDsl::Phrase {
+            field: "merchant".to_string(),
+            terms: vec!["Sam's".to_string(), "Club".to_string()],
+            slop: None,
+        };
+        assert_eq!(count(&index, terms), 1);
+        let prefix = TantivyQueryDsl::PhrasePrefix {
+            field: "merchant".to_string(),
+            terms: vec!["TRADER".to_string(), "Jo".to_string()],
+            max_expansions: None,
+        };
+        assert_eq!(count(&index, prefix), 1);
+        let fuzzy = |term: &str| TantivyQueryDsl::Fuzzy {
+            field: "merchant".to_string(),
+            term: term.to_string(),
+            distance: 1,
+            transpose_cost_one: true,
+        };
+        assert_eq!(count(&index, fuzzy("CAFÉS")), 1);
+        assert!(
+            index
+                .search_dsl(
+                    serde_json::to_string(&fuzzy("club cafe")).unwrap(),
+                    10,
+                    0,
+                    vec![],
+                    vec![],
+                    None,
+                    None
+                )
+                .is_err()
+        );
+
+        assert_eq!(count(&index, phrase("SAM'S club", None)), 1);
+        assert_eq!(count(&index, phrase("club sams", None)), 0);
+        assert_eq!(count(&index, phrase("sams wholesale", Some(1))), 1);
+        assert_eq!(count(&index, phrase("trader joe's co-op", None)), 1);
+
+        assert_eq!(
+            count(
+                &index,
+                matching("sams club", TantivyMatchOperator::Or, None)
+            ),
+            2
+        );
+        assert_eq!(
+            count(
+                &index,
+                matching("sams club", TantivyMatchOperator::And, None)
+            ),
+            1
+        );
+        assert_eq!(
+            count(
+                &index,
+                matching("club cafe sams", TantivyMatchOperator::Or, Some(2))
+            ),
+            2
+        );
+        assert_eq!(
+            count(&index, matching("coop", TantivyMatchOperator::Or, None)),
+            1
+        );
+        assert_eq!(
+            count(&index, matching("  ", TantivyMatchOperator::Or, None)),
+            0
+        );
+        let and_with_minimum = matching("sams club", TantivyMatchOperator::And, Some(1));
+        assert!(
+            index
+                .search_dsl(
+                    serde_json::to_string(&and_with_minimum).unwrap(),
+                    10,
+                    0,
+                    vec![],
+                    vec![],
+                    None,
+                    None
+                )
+                .is_err()
+        );
+    }
+}
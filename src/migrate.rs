@@ -0,0 +1,557 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tantivy::directory::{Directory, INDEX_WRITER_LOCK, MmapDirectory};
+use tantivy::schema::{Field, Schema, Type};
+use tantivy::{Index, IndexSettings, TantivyDocument, TantivyError};
+
+use crate::analyzer::ANALYZERS_FILE;
+use crate::projection::FieldProjector;
+use crate::{
+    FieldValue, TantivyIndex, TantivyIndexError, TantivyIndexOptions, TantivySchemaBuilder,
+    add_field_value, doc_to_fields, register_tokenizers,
+};
+
+/// Documents migrated between progress reports.
+const PROGRESS_INTERVAL: u64 = 1000;
+
+/// Marks a new index that is still being built, so a directory left by an interrupted
+/// migration can be told apart from one the library did not create.
+const BUILDING_MARKER: &str = ".building";
+
+/// How a field of the old schema carries over to the new one. Fields without a
+/// mapping keep their name.
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum TantivyFieldMapping {
+    /// Moves the values of `from` to the field `to` of the new schema.
+    Rename { from: String, to: String },
+    /// Leaves the values of `field` out of the new index.
+    Drop { field: String },
+    /// Sets `field` of the new schema on documents that have no value for it.
+    Default { field: String, value: FieldValue },
+}
+
+#[uniffi::export(with_foreign)]
+pub trait TantivyMigrationProgress: Send + Sync {
+    /// Called every thousand documents and once all `total` are migrated.
+    fn on_progress(&self, migrated: u64, total: u64);
+}
+
+fn value_type(value: &FieldValue) -> Type {
+    match value {
+        FieldValue::Text(_) => Type::Str,
+        FieldValue::U64(_) => Type::U64,
+        FieldValue::I64(_) => Type::I64,
+        FieldValue::F64(_) => Type::F64,
+        FieldValue::Bool(_) => Type::Bool,
+        FieldValue::Date(_) => Type::Date,
+        FieldValue::Bytes(_) => Type::Bytes,
+        FieldValue::Facet(_) => Type::Facet,
+        FieldValue::Json(_) => Type::Json,
+        FieldValue::IpAddr(_) => Type::IpAddr,
+    }
+}
+
+struct MigrationPlan {
+    /// Field of the new schema for each old field, `None` when it is dropped.
+    targets: HashMap<String, Option<Field>>,
+    defaults: Vec<(Field, FieldValue)>,
+}
+
+impl MigrationPlan {
+    /// Checks that every old field is dropped or lands in its own field of the same type.
+    fn new(
+        old_schema: &Schema,
+        new_schema: &Schema,
+        mappings: &[TantivyFieldMapping],
+    ) -> Result<Self, TantivyIndexError> {
+        let mut renamed = HashMap::new();
+        let mut dropped = HashSet::new();
+        let mut defaults = Vec::new();
+        for mapping in mappings {
+            match mapping {
+                TantivyFieldMapping::Rename { from, to } => {
+                    old_schema.get_field(from)?;
+                    renamed.insert(from.as_str(), to.as_str());
+                }
+                TantivyFieldMapping::Drop { field } => {
+                    old_schema.get_field(field)?;
+                    dropped.insert(field.as_str());
+                }
+                TantivyFieldMapping::Default { field, value } => {
+                    let target = new_schema.get_field(field)?;
+                    let expected = new_schema.get_field_entry(target).field_type().value_type();
+                    if value_type(value) != expected {
+                        return Err(TantivyIndexError::MigrationError(format!(
+                            "Default value of {field} is not a {expected:?} value"
+                        )));
+                    }
+                    defaults.push((target, value.clone()));
+                }
+            }
+        }
+
+        let mut targets = HashMap::new();
+        let mut sources = HashMap::new();
+        for (_, field_entry) in old_schema.fields() {
+            let name = field_entry.name();
+            if dropped.contains(name) {
+                targets.insert(name.to_string(), None);
+                continue;
+            }
+            if !field_entry.is_stored() {
+                return Err(TantivyIndexError::MigrationError(format!(
+                    "Field {name} is not stored, drop it to migrate without its values"
+                )));
+            }
+            let new_name = renamed.get(name).copied().unwrap_or(name);
+            let Ok(target) = new_schema.get_field(new_name) else {
+                return Err(TantivyIndexError::MigrationError(format!(
+                    "Field {new_name} is not in the new schema, rename or drop {name}"
+                )));
+            };
+            let from_type = field_entry.field_type().value_type();
+            let to_type = new_schema.get_field_entry(target).field_type().value_type();
+            if from_type != to_type {
+                return Err(TantivyIndexError::MigrationError(format!(
+                    "Field {name} changes type from {from_type:?} to {to_type:?}"
+                )));
+            }
+            if let Some(other) = sources.insert(target, name) {
+                return Err(TantivyIndexError::MigrationError(format!(
+                    "Fields {other} and {name} both migrate into {new_name}"
+                )));
+            }
+            targets.insert(name.to_string(), Some(target));
+        }
+        Ok(MigrationPlan { targets, defaults })
+    }
+
+    fn migrate_doc(
+        &self,
+        old_schema: &Schema,
+        doc: TantivyDocument,
+    ) -> Result<TantivyDocument, TantivyIndexError> {
+        let mut migrated = TantivyDocument::default();
+        for field in doc_to_fields(old_schema, doc, &FieldProjector::All)?.fields {
+            if let Some(Some(target)) = self.targets.get(&field.name) {
+                add_field_value(&mut migrated, *target, &field.value)?;
+            }
+        }
+        for (field, value) in &self.defaults {
+            if migrated.get_first(*field).is_none() {
+                add_field_value(&mut migrated, *field, value)?;
+            }
+        }
+        Ok(migrated)
+    }
+}
+
+/// `path` with `suffix` appended to its last component.
+fn sibling_path(path: &Path, suffix: &str) -> Result<PathBuf, TantivyIndexError> {
+    let Some(name) = path.file_name() else {
+        return Err(TantivyIndexError::MigrationError(format!(
+            "No index directory name in {}",
+            path.display()
+        )));
+    };
+    let mut name = name.to_os_string();
+    name.push(suffix);
+    Ok(path.with_file_name(name))
+}
+
+#[cfg(any(target_vendor = "apple", all(target_os = "linux", target_env = "gnu")))]
+fn exchange_dirs(a: &Path, b: &Path) -> std::io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let a = CString::new(a.as_os_str().as_bytes())?;
+    let b = CString::new(b.as_os_str().as_bytes())?;
+    // SAFETY: both paths are NUL-terminated and outlive the call.
+    #[cfg(target_vendor = "apple")]
+    let result = unsafe { libc::renamex_np(a.as_ptr(), b.as_ptr(), libc::RENAME_SWAP) };
+    // SAFETY: as above, relative paths resolve against the working directory.
+    #[cfg(not(target_vendor = "apple"))]
+    let result = unsafe {
+        libc::renameat2(
+            libc::AT_FDCWD,
+            a.as_ptr(),
+            libc::AT_FDCWD,
+            b.as_ptr(),
+            libc::RENAME_EXCHANGE,
+        )
+    };
+    match result {
+        0 => Ok(()),
+        _ => Err(std::io::Error::last_os_error()),
+    }
+}
+
+#[cfg(not(any(target_vendor = "apple", all(target_os = "linux", target_env = "gnu"))))]
+fn exchange_dirs(_: &Path, _: &Path) -> std::io::Result<()> {
+    Err(std::io::ErrorKind::Unsupported.into())
+}
+
+/// Swaps two directories in one step, so a crash leaves either the old or the new
+/// index at `a`.
+fn swap_dirs(a: &Path, b: &Path) -> Result<(), TantivyIndexError> {
+    match exchange_dirs(a, b) {
+        Ok(()) => Ok(()),
+        Err(err)
+            if err.kind() == std::io::ErrorKind::Unsupported
+                || matches!(
+                    err.raw_os_error(),
+                    Some(libc::EINVAL | libc::ENOSYS | libc::ENOTSUP)
+                ) =>
+        {
+            Err(TantivyIndexError::MigrationError(format!(
+                "Cannot swap directories in {} atomically on this platform or file system",
+                a.parent().unwrap_or(a).display()
+            )))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Swaps two empty directories inside `dir`, so a platform or file system that
+/// cannot swap fails the migration before anything is reindexed.
+fn check_swap_support(dir: &Path) -> Result<(), TantivyIndexError> {
+    let a = dir.join("swap-probe-a");
+    let b = dir.join("swap-probe-b");
+    std::fs::create_dir(&a)?;
+    std::fs::create_dir(&b)?;
+    let swapped = swap_dirs(&a, &b);
+    std::fs::remove_dir(&a)?;
+    std::fs::remove_dir(&b)?;
+    swapped
+}
+
+/// Builds the new index at `new_path` from the stored documents of `old_index`.
+fn build_migrated_index(
+    old_index: &Index,
+    old_path: &Path,
+    new_path: &Path,
+    schema_builder: &TantivySchemaBuilder,
+    plan: &MigrationPlan,
+    options: &TantivyIndexOptions,
+    progress: Option<&dyn TantivyMigrationProgress>,
+) -> Result<(), TantivyIndexError> {
+    let new_schema = schema_builder.take_and_build().ok_or_else(|| {
+        TantivyIndexError::SchemaBuilderError("Schema already built or empty".to_string())
+    })?;
+    std::fs::create_dir_all(new_path)?;
+    std::fs::write(new_path.join(BUILDING_MARKER), [])?;
+    check_swap_support(new_path)?;
+    let index = Index::create(
+        MmapDirectory::open(new_path)?,
+        new_schema,
+        IndexSettings::default(),
+    )?;
+
+    // The analyzers saved with the old index carry over, new ones are added to them.
+    let saved_analyzers = old_path.join(ANALYZERS_FILE);
+    if saved_analyzers.exists() {
+        std::fs::copy(&saved_analyzers, new_path.join(ANALYZERS_FILE))?;
+    }
+    register_tokenizers(&index, new_path, &schema_builder.take_analyzers(), true)?;
+
+    let mut writer = options.writer(&index)?;
+    let old_schema = old_index.schema();
+    let searcher = old_index.reader()?.searcher();
+    let total = searcher.num_docs();
+    let mut migrated = 0;
+    for segment_reader in searcher.segment_readers() {
+        let store_reader = segment_reader.get_store_reader(1)?;
+        for doc in store_reader.iter::<TantivyDocument>(segment_reader.alive_bitset()) {
+            writer.add_document(plan.migrate_doc(&old_schema, doc?)?)?;
+            migrated += 1;
+            if migrated % PROGRESS_INTERVAL == 0
+                && let Some(progress) = progress
+            {
+                progress.on_progress(migrated, total);
+            }
+        }
+    }
+    writer.commit()?;
+    writer.wait_merging_threads()?;
+    if let Some(progress) = progress {
+        progress.on_progress(migrated, total);
+    }
+    std::fs::remove_file(new_path.join(BUILDING_MARKER))?;
+    Ok(())
+}
+
+#[uniffi::export]
+impl TantivyIndex {
+    /// Reindexes the index at `path` into the schema of `schema_builder` and opens it.
+    ///
+    /// The stored documents are copied through `field_mappings` into a new index
+    /// next to the old one, which replaces the old directory once complete. The old
+    /// index is left as it was when anything fails. Handles with a writer must be
+    /// dropped first, and read-only handles reopened afterwards.
+    ///
+    /// The swap uses `renamex_np` on Apple platforms and `renameat2` on Linux with
+    /// glibc. Other platforms, and file systems without an atomic swap, fail with
+    /// `MigrationError` before any document is reindexed.
+    ///
+    /// A `<path>.migrating` directory left by an interrupted build is replaced, any
+    /// other directory there fails the migration with `MigrationError`.
+    ///
+    /// `options` size the writer that builds the new index and are used to open it.
+    #[uniffi::constructor]
+    pub fn migrate(
+        path: String,
+        schema_builder: &TantivySchemaBuilder,
+        field_mappings: Vec<TantivyFieldMapping>,
+        options: TantivyIndexOptions,
+        progress: Option<Arc<dyn TantivyMigrationProgress>>,
+    ) -> Result<Self, TantivyIndexError> {
+        let index_path = Path::new(&path);
+        let new_path = sibling_path(index_path, ".migrating")?;
+        let old_index = Index::open(MmapDirectory::open(index_path)?)?;
+        // Keeps writers out until the new index is swapped in.
+        let lock = old_index
+            .directory()
+            .acquire_lock(&INDEX_WRITER_LOCK)
+            .map_err(TantivyError::from)?;
+        let new_schema = schema_builder.peek_schema().ok_or_else(|| {
+            TantivyIndexError::SchemaBuilderError("Schema already built or empty".to_string())
+        })?;
+        let plan = MigrationPlan::new(&old_index.schema(), &new_schema, &field_mappings)?;
+
+        if new_path.exists() {
+            if !new_path.join(BUILDING_MARKER).exists() {
+                return Err(TantivyIndexError::MigrationError(format!(
+                    "{} already exists and was not left by an interrupted migration",
+                    new_path.display()
+                )));
+            }
+            std::fs::remove_dir_all(&new_path)?;
+        }
+        let swapped = build_migrated_index(
+            &old_index,
+            index_path,
+            &new_path,
+            schema_builder,
+            &plan,
+            &options,
+            progress.as_deref(),
+        )
+        .and_then(|()| {
+            drop(old_index);
+            swap_dirs(index_path, &new_path)
+        });
+        drop(lock);
+        // Holds the new index when building or swapping failed, the old one otherwise.
+        let _ = std::fs::remove_dir_all(&new_path);
+        swapped?;
+        Self::open(path, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use crate::tests::{id_field, receipt, receipt_schema, temp_index_path};
+    use crate::{DocumentField, TantivyQueryDsl, TextFieldOptions};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordedProgress(Mutex<Vec<(u64, u64)>>);
+
+    impl TantivyMigrationProgress for RecordedProgress {
+        fn on_progress(&self, migrated: u64, total: u64) {
+            self.0.lock().unwrap().push((migrated, total));
+        }
+    }
+
+    /// The receipt schema with `merchant` renamed to `store`, `tags` removed and
+    /// a `currency` added.
+    fn stores_schema() -> TantivySchemaBuilder {
+        let builder = TantivySchemaBuilder::new();
+        let receipts = receipt_schema().peek_schema().unwrap();
+        for name in ["id", "merchant", "total", "purchased"] {
+            let field_entry = receipts.get_field_entry(receipts.get_field(name).unwrap());
+            if name == "merchant" {
+                builder.add_text_field("store".to_string(), TextFieldOptions::default());
+            } else {
+                builder.add_field_entries(vec![field_entry.clone()]);
+            }
+        }
+        builder.add_text_field("currency".to_string(), TextFieldOptions::default());
+        builder
+    }
+
+    #[test]
+    fn migrate_reindexes_stored_docs_into_the_new_schema() {
+        let path = temp_index_path("migrate");
+        let index = TantivyIndex::new_with_schema(path.clone(), &receipt_schema()).unwrap();
+        index
+            .index_docs(vec![
+                receipt("r1", "Costco", 10.0),
+                receipt("r2", "Target", 20.0),
+                receipt("r3", "Trader Joe's", 30.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+        index.delete_doc(id_field("r3")).unwrap();
+        index.commit().unwrap();
+
+        let rename_and_default = || {
+            vec![
+                TantivyFieldMapping::Rename {
+                    from: "merchant".to_string(),
+                    to: "store".to_string(),
+                },
+                TantivyFieldMapping::Default {
+                    field: "currency".to_string(),
+                    value: FieldValue::Text("USD".to_string()),
+                },
+            ]
+        };
+        // The open writer holds the lock.
+        assert!(
+            TantivyIndex::migrate(
+                path.clone(),
+                &stores_schema(),
+                vec![],
+                TantivyIndexOptions::default(),
+                None,
+            )
+            .is_err()
+        );
+        drop(index);
+
+        // `tags` is neither in the new schema nor dropped.
+        let err = TantivyIndex::migrate(
+            path.clone(),
+            &stores_schema(),
+            rename_and_default(),
+            TantivyIndexOptions::default(),
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(err, TantivyIndexError::MigrationError(_)));
+        let wrong_default = vec![TantivyFieldMapping::Default {
+            field: "currency".to_string(),
+            value: FieldValue::U64(840),
+        }];
+        assert!(
+            TantivyIndex::migrate(
+                path.clone(),
+                &stores_schema(),
+                wrong_default,
+                TantivyIndexOptions::default(),
+                None,
+            )
+            .is_err()
+        );
+        // Two old fields may not land in the same new one, renamed or not.
+        for renames in [
+            vec![("id", "store"), ("merchant", "store")],
+            vec![("merchant", "id")],
+        ] {
+            let mut mappings = renames
+                .into_iter()
+                .map(|(from, to)| TantivyFieldMapping::Rename {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                })
+                .collect::<Vec<_>>();
+            mappings.push(TantivyFieldMapping::Drop {
+                field: "tags".to_string(),
+            });
+            let Err(TantivyIndexError::MigrationError(message)) = TantivyIndex::migrate(
+                path.clone(),
+                &stores_schema(),
+                mappings,
+                TantivyIndexOptions::default(),
+                None,
+            ) else {
+                panic!("expected a migration error");
+            };
+            assert!(message.contains("both migrate into"), "{message}");
+        }
+        assert_eq!(
+            TantivyIndex::open(path.clone(), TantivyIndexOptions::default())
+                .unwrap()
+                .docs_count(),
+            2
+        );
+
+        // Only a directory left by an interrupted build is cleared out of the way.
+        let leftover = PathBuf::from(format!("{path}.migrating"));
+        std::fs::create_dir_all(&leftover).unwrap();
+        std::fs::write(leftover.join("notes.txt"), "keep me").unwrap();
+        let mut mappings = rename_and_default();
+        mappings.push(TantivyFieldMapping::Drop {
+            field: "tags".to_string(),
+        });
+        assert!(matches!(
+            TantivyIndex::migrate(
+                path.clone(),
+                &stores_schema(),
+                mappings,
+                TantivyIndexOptions::default(),
+                None,
+            ),
+            Err(TantivyIndexError::MigrationError(_))
+        ));
+        assert!(leftover.join("notes.txt").exists());
+        std::fs::write(leftover.join(BUILDING_MARKER), []).unwrap();
+
+        let progress = Arc::new(RecordedProgress::default());
+        let mut mappings = rename_and_default();
+        mappings.push(TantivyFieldMapping::Drop {
+            field: "tags".to_string(),
+        });
+        let migrated = TantivyIndex::migrate(
+            path.clone(),
+            &stores_schema(),
+            mappings,
+            TantivyIndexOptions {
+                writer_heap_bytes: 15_000_000,
+                num_threads: Some(1),
+                ..TantivyIndexOptions::default()
+            },
+            Some(progress.clone()),
+        )
+        .unwrap();
+        assert_eq!(*progress.0.lock().unwrap(), vec![(2, 2)]);
+        assert!(!Path::new(&format!("{path}.migrating")).exists());
+
+        assert_eq!(migrated.docs_count(), 2);
+        let doc = migrated.get_doc(id_field("r1"), None).unwrap();
+        let names = doc
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["id", "store", "total", "currency"]);
+        let store = DocumentField {
+            name: "store".to_string(),
+            value: FieldValue::Text("target".to_string()),
+        };
+        let query = TantivyQueryDsl::Term { term: store };
+        assert_eq!(
+            migrated
+                .search_dsl(
+                    serde_json::to_string(&query).unwrap(),
+                    10,
+                    0,
+                    vec![],
+                    vec![],
+                    None,
+                    None
+                )
+                .unwrap()
+                .count,
+            1
+        );
+        migrated.index_doc(receipt("r4", "Costco", 5.0)).unwrap();
+    }
+}
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use tantivy::schema::{Field, FieldType, Schema};
+use tantivy::{DocAddress, Searcher, TantivyDocument};
+
+use crate::{DocumentField, FieldValue, TantivyDocumentFields, TantivyIndexError, doc_to_fields};
+
+/// Which stored fields to return with each document.
+#[derive(Debug, Clone, Serialize, Deserialize, uniffi::Enum)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TantivyFieldProjection {
+    All,
+    /// Only these stored fields; an empty list returns no stored fields at all.
+    Include {
+        fields: Vec<String>,
+    },
+    Exclude {
+        fields: Vec<String>,
+    },
+    /// Only the ID field, read from its fast column when it has one.
+    IdOnly {
+        id_field: String,
+    },
+}
+
+pub(crate) enum FieldProjector {
+    All,
+    Include(Vec<Field>),
+    Exclude(Vec<Field>),
+    IdOnly { field: Field, fast: bool },
+}
+
+fn resolve_fields(schema: &Schema, names: &[String]) -> Result<Vec<Field>, TantivyIndexError> {
+    let mut fields = Vec::with_capacity(names.len());
+    for name in names {
+        fields.push(schema.get_field(name)?);
+    }
+    Ok(fields)
+}
+
+impl FieldProjector {
+    pub(crate) fn new(
+        schema: &Schema,
+        projection: Option<&TantivyFieldProjection>,
+    ) -> Result<Self, TantivyIndexError> {
+        Ok(match projection {
+            None | Some(TantivyFieldProjection::All) => FieldProjector::All,
+            Some(TantivyFieldProjection::Include { fields }) => {
+                FieldProjector::Include(resolve_fields(schema, fields)?)
+            }
+            Some(TantivyFieldProjection::Exclude { fields }) => {
+                FieldProjector::Exclude(resolve_fields(schema, fields)?)
+            }
+            Some(TantivyFieldProjection::IdOnly { id_field }) => {
+                let field = schema.get_field(id_field)?;
+                let fast = is_fast_id_field(schema, field);
+                FieldProjector::IdOnly { field, fast }
+            }
+        })
+    }
+
+    /// Whether projecting needs the document from the doc store.
+    pub(crate) fn needs_stored_doc(&self) -> bool {
+        match self {
+            FieldProjector::Include(fields) => !fields.is_empty(),
+            FieldProjector::IdOnly { fast, .. } => !fast,
+            FieldProjector::All | FieldProjector::Exclude(_) => true,
+        }
+    }
+
+    pub(crate) fn keeps(&self, field: Field) -> bool {
+        match self {
+            FieldProjector::All => true,
+            FieldProjector::Include(fields) => fields.contains(&field),
+            FieldProjector::Exclude(fields) => !fields.contains(&field),
+            FieldProjector::IdOnly {
+                field: id_field, ..
+            } => *id_field == field,
+        }
+    }
+
+    /// Projects the document at `address`, loading it only when `doc` is not given
+    /// and the projection needs stored fields.
+    pub(crate) fn project(
+        &self,
+        schema: &Schema,
+        searcher: &Searcher,
+        address: DocAddress,
+        doc: Option<TantivyDocument>,
+    ) -> Result<TantivyDocumentFields, TantivyIndexError> {
+        if let FieldProjector::IdOnly { field, fast: true } = self {
+            let fields = fast_id_value(schema, searcher, address, *field)?
+                .map(|value| DocumentField {
+                    name: schema.get_field_name(*field).to_string(),
+                    value,
+                })
+                .into_iter()
+                .collect();
+            return Ok(TantivyDocumentFields { fields });
+        }
+        if !self.needs_stored_doc() {
+            return Ok(TantivyDocumentFields { fields: Vec::new() });
+        }
+        let doc = match doc {
+            Some(doc) => doc,
+            None => searcher.doc(address)?,
+        };
+        doc_to_fields(schema, doc, self)
+    }
+}
+
+/// Whether the ID of a document can be read from the fast column of `field`.
+pub(crate) fn is_fast_id_field(schema: &Schema, field: Field) -> bool {
+    let field_entry = schema.get_field_entry(field);
+    field_entry.is_fast()
+        && matches!(
+            field_entry.field_type(),
+            FieldType::Str(_) | FieldType::U64(_) | FieldType::I64(_)
+        )
+}
+
+/// Reads the first value of a text, u64 or i64 fast field.
+pub(crate) fn fast_id_value(
+    schema: &Schema,
+    searcher: &Searcher,
+    address: DocAddress,
+    field: Field,
+) -> Result<Option<FieldValue>, TantivyIndexError> {
+    let name = schema.get_field_name(field);
+    let fast_fields = searcher.segment_reader(address.segment_ord).fast_fields();
+    let value = match schema.get_field_entry(field).field_type() {
+        FieldType::Str(_) => match fast_fields.str(name)? {
+            Some(column) => match column.term_ords(address.doc_id).next() {
+                Some(ord) => {
+                    let mut text = String::new();
+                    column.ord_to_str(ord, &mut text)?;
+                    Some(FieldValue::Text(text))
+                }
+                None => None,
+            },
+            None => None,
+        },
+        FieldType::U64(_) => fast_fields
+            .u64(name)?
+            .first(address.doc_id)
+            .map(FieldValue::U64),
+        FieldType::I64(_) => fast_fields
+            .i64(name)?
+            .first(address.doc_id)
+            .map(FieldValue::I64),
+        _ => None,
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{id_field, make_index, receipt};
+
+    use super::*;
+
+    fn field_names(doc: &TantivyDocumentFields) -> Vec<&str> {
+        doc.fields.iter().map(|field| field.name.as_str()).collect()
+    }
+
+    #[test]
+    fn projections_limit_returned_fields() {
+        let index = make_index("projection");
+        index
+            .index_docs(vec![receipt("r1", "costco", 10.0)])
+            .unwrap();
+        index.commit().unwrap();
+
+        let search = |fields| {
+            index
+                .search_dsl(
+                    r#"{"type":"all"}"#.to_string(),
+                    10,
+                    0,
+                    vec![],
+                    vec![],
+                    None,
+                    Some(fields),
+                )
+                .unwrap()
+                .docs
+                .remove(0)
+        };
+
+        let all = search(TantivyFieldProjection::All);
+        assert_eq!(field_names(&all.doc), vec!["id", "merchant", "total"]);
+
+        let include = search(TantivyFieldProjection::Include {
+            fields: vec!["merchant".to_string()],
+        });
+        assert_eq!(field_names(&include.doc), vec!["merchant"]);
+
+        let exclude = search(TantivyFieldProjection::Exclude {
+            fields: vec!["merchant".to_string()],
+        });
+        assert_eq!(field_names(&exclude.doc), vec!["id", "total"]);
+
+        let nothing = search(TantivyFieldProjection::Include { fields: vec![] });
+        assert!(nothing.doc.fields.is_empty());
+        assert!(nothing.score > 0.0);
+
+        let id_only = search(TantivyFieldProjection::IdOnly {
+            id_field: "id".to_string(),
+        });
+        assert_eq!(field_names(&id_only.doc), vec!["id"]);
+        assert!(matches!(&id_only.doc.fields[0].value, FieldValue::Text(id) if id == "r1"));
+
+        let fetched = index
+            .get_doc(
+                id_field("r1"),
+                Some(TantivyFieldProjection::Include {
+                    fields: vec!["total".to_string()],
+                }),
+            )
+            .unwrap();
+        assert_eq!(field_names(&fetched), vec!["total"]);
+
+        assert!(
+            index
+                .get_docs_by_ids(
+                    vec![id_field("r1")],
+                    Some(TantivyFieldProjection::Exclude {
+                        fields: vec!["missing".to_string()],
+                    }),
+                )
+                .is_err()
+        );
+    }
+}
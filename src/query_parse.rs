@@ -0,0 +1,273 @@
+use tantivy::Index;
+use tantivy::query::{Query, QueryParserError};
+use tantivy::query_grammar;
+use tantivy::schema::Schema;
+
+use crate::{TantivyFuzzyField, TantivyIndex, TantivyIndexError, build_query_parser};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum TantivyQueryErrorKind {
+    Syntax,
+    UnknownField,
+    FieldNotIndexed,
+    /// Phrase queries need a field indexed with positions.
+    MissingPositions,
+    /// A value could not be parsed for the field type, e.g. `count:abc`.
+    InvalidValue,
+    Unsupported,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyQueryParseError {
+    pub kind: TantivyQueryErrorKind,
+    pub message: String,
+    /// Byte offset in the query string, only known for syntax errors.
+    pub position: Option<u64>,
+    pub field: Option<String>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivyQueryValidation {
+    pub errors: Vec<TantivyQueryParseError>,
+    /// Debug representation of what the lenient parser makes of the query.
+    pub lenient_query: String,
+}
+
+impl TantivyQueryParseError {
+    fn unknown_field(field: &str, usage: &str) -> Self {
+        TantivyQueryParseError {
+            kind: TantivyQueryErrorKind::UnknownField,
+            message: format!("Unknown {usage} field: {field}"),
+            position: None,
+            field: Some(field.to_string()),
+        }
+    }
+}
+
+impl From<QueryParserError> for TantivyQueryParseError {
+    fn from(error: QueryParserError) -> Self {
+        let message = error.to_string();
+        let (kind, field) = match error {
+            QueryParserError::SyntaxError(_) => (TantivyQueryErrorKind::Syntax, None),
+            QueryParserError::FieldDoesNotExist(field) => {
+                (TantivyQueryErrorKind::UnknownField, Some(field))
+            }
+            QueryParserError::FieldNotIndexed(field) => {
+                (TantivyQueryErrorKind::FieldNotIndexed, Some(field))
+            }
+            QueryParserError::FieldDoesNotHavePositionsIndexed(field) => {
+                (TantivyQueryErrorKind::MissingPositions, Some(field))
+            }
+            QueryParserError::UnknownTokenizer { field, .. } => {
+                (TantivyQueryErrorKind::Unsupported, Some(field))
+            }
+            QueryParserError::ExpectedInt(_)
+            | QueryParserError::ExpectedBase64(_)
+            | QueryParserError::ExpectedFloat(_)
+            | QueryParserError::ExpectedBool(_)
+            | QueryParserError::DateFormatError(_)
+            | QueryParserError::FacetFormatError(_)
+            | QueryParserError::IpFormatError(_) => (TantivyQueryErrorKind::InvalidValue, None),
+            _ => (TantivyQueryErrorKind::Unsupported, None),
+        };
+        TantivyQueryParseError {
+            kind,
+            message,
+            position: None,
+            field,
+        }
+    }
+}
+
+/// Parses a query string, collecting every issue the lenient parser works around.
+fn parse_query_string_lenient(
+    index: &Index,
+    schema: &Schema,
+    query_str: &str,
+    default_fields: &[String],
+    fuzzy_fields: &[TantivyFuzzyField],
+) -> Result<(Box<dyn Query>, Vec<TantivyQueryParseError>), TantivyIndexError> {
+    let mut errors = default_fields
+        .iter()
+        .filter(|field_name| schema.get_field(field_name).is_err())
+        .map(|field_name| TantivyQueryParseError::unknown_field(field_name, "default"))
+        .collect::<Vec<_>>();
+
+    let query_parser = build_query_parser(index, schema, default_fields, fuzzy_fields)?;
+
+    let (user_input_ast, syntax_errors) = query_grammar::parse_query_lenient(query_str);
+    errors.extend(
+        syntax_errors
+            .into_iter()
+            .map(|error| TantivyQueryParseError {
+                kind: TantivyQueryErrorKind::Syntax,
+                message: error.message,
+                position: Some(error.pos as u64),
+                field: None,
+            }),
+    );
+
+    let (query, parser_errors) =
+        query_parser.build_query_from_user_input_ast_lenient(user_input_ast);
+    errors.extend(parser_errors.into_iter().map(TantivyQueryParseError::from));
+
+    Ok((query, errors))
+}
+
+/// Parses a query string leniently, or fails with all of its issues in strict mode.
+pub(crate) fn parse_query_string(
+    index: &Index,
+    schema: &Schema,
+    query_str: &str,
+    default_fields: &[String],
+    fuzzy_fields: &[TantivyFuzzyField],
+    strict: bool,
+) -> Result<Box<dyn Query>, TantivyIndexError> {
+    let (query, errors) =
+        parse_query_string_lenient(index, schema, query_str, default_fields, fuzzy_fields)?;
+    if strict && !errors.is_empty() {
+        let messages = errors
+            .iter()
+            .map(|error| match error.position {
+                Some(position) => format!("{} at position {}", error.message, position),
+                None => error.message.clone(),
+            })
+            .collect::<Vec<_>>();
+        return Err(TantivyIndexError::QueryParseError(messages.join("; ")));
+    }
+    Ok(query)
+}
+
+#[uniffi::export]
+impl TantivyIndex {
+    /// Reports the syntax errors, unknown fields and invalid values of a query string
+    /// that `search` would otherwise skip over.
+    #[uniffi::method]
+    fn validate_query(
+        &self,
+        query_str: String,
+        default_fields: Vec<String>,
+        fuzzy_fields: Vec<TantivyFuzzyField>,
+    ) -> Result<TantivyQueryValidation, TantivyIndexError> {
+        let schema = self.index.schema();
+
+        let (known_fuzzy_fields, unknown_fuzzy_fields): (Vec<_>, Vec<_>) = fuzzy_fields
+            .into_iter()
+            .partition(|fuzzy_field| schema.get_field(&fuzzy_field.field_name).is_ok());
+
+        let (query, mut errors) = parse_query_string_lenient(
+            &self.index,
+            &schema,
+            &query_str,
+            &default_fields,
+            &known_fuzzy_fields,
+        )?;
+        errors.extend(unknown_fuzzy_fields.iter().map(|fuzzy_field| {
+            TantivyQueryParseError::unknown_field(&fuzzy_field.field_name, "fuzzy")
+        }));
+
+        Ok(TantivyQueryValidation {
+            errors,
+            lenient_query: format!("{query:?}"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::make_index;
+    use crate::{TantivyQueryDsl, TantivySearchQuery};
+
+    use super::*;
+
+    #[test]
+    fn validate_query_reports_structured_errors() {
+        let index = make_index("validate_query");
+
+        let validation = index
+            .validate_query(
+                "merchant:(costco OR unknown:target".to_string(),
+                vec!["merchant".to_string(), "missing".to_string()],
+                vec![TantivyFuzzyField {
+                    field_name: "nope".to_string(),
+                    prefix: false,
+                    distance: 1,
+                    transpose_cost_one: false,
+                }],
+            )
+            .unwrap();
+        let kinds = validation
+            .errors
+            .iter()
+            .map(|error| (error.kind, error.field.as_deref()))
+            .collect::<Vec<_>>();
+        assert!(kinds.contains(&(TantivyQueryErrorKind::UnknownField, Some("missing"))));
+        assert!(kinds.contains(&(TantivyQueryErrorKind::UnknownField, Some("unknown"))));
+        assert!(kinds.contains(&(TantivyQueryErrorKind::UnknownField, Some("nope"))));
+        assert!(
+            validation.errors.iter().any(
+                |error| error.kind == TantivyQueryErrorKind::Syntax && error.position.is_some()
+            )
+        );
+
+        let valid = index
+            .validate_query(
+                "merchant:costco AND total:[1 TO 10]".to_string(),
+                vec!["merchant".to_string()],
+                vec![],
+            )
+            .unwrap();
+        assert!(valid.errors.is_empty());
+
+        let invalid_value = index
+            .validate_query("total:abc".to_string(), vec![], vec![])
+            .unwrap();
+        assert_eq!(
+            invalid_value.errors[0].kind,
+            TantivyQueryErrorKind::InvalidValue
+        );
+    }
+
+    #[test]
+    fn strict_mode_rejects_what_lenient_mode_skips() {
+        let index = make_index("strict_query");
+        let search = |strict| {
+            index.search(TantivySearchQuery {
+                query_str: "merchant:(costco".to_string(),
+                default_fields: vec!["merchant".to_string()],
+                fuzzy_fields: vec![],
+                top_doc_limit: 10,
+                top_doc_offset: 0,
+                sort: vec![],
+                snippet_fields: vec![],
+                cursor: None,
+                strict,
+                fields: None,
+            })
+        };
+        assert!(search(false).is_ok());
+        assert!(matches!(
+            search(true),
+            Err(TantivyIndexError::QueryParseError(_))
+        ));
+
+        let dsl = TantivyQueryDsl::QueryString {
+            query: "costco".to_string(),
+            default_fields: vec!["missing".to_string()],
+            fuzzy_fields: vec![],
+            strict: true,
+        };
+        assert!(matches!(
+            index.search_dsl(
+                serde_json::to_string(&dsl).unwrap(),
+                10,
+                0,
+                vec![],
+                vec![],
+                None,
+                None
+            ),
+            Err(TantivyIndexError::QueryParseError(_))
+        ));
+    }
+}
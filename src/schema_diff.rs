@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use serde_json::Value;
+use tantivy::directory::MmapDirectory;
+use tantivy::schema::{FieldEntry, Schema};
+use tantivy::{Index, TantivyError};
+
+use crate::{TantivyIndexError, TantivySchemaBuilder};
+
+/// One option of a field that differs between the index on disk and the schema
+/// builder, e.g. `options.indexing.tokenizer`. `None` where the option is not set.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TantivyFieldChange {
+    pub field: String,
+    pub option: String,
+    pub on_disk: Option<String>,
+    pub requested: Option<String>,
+}
+
+/// How a schema builder differs from the schema of an existing index.
+#[derive(Debug, Clone, Default, PartialEq, uniffi::Record)]
+pub struct TantivySchemaDiff {
+    /// Fields only in the schema builder.
+    pub added: Vec<String>,
+    /// Fields only in the index on disk.
+    pub removed: Vec<String>,
+    /// Fields in both with different options, or in a different order (`position`).
+    pub changed: Vec<TantivyFieldChange>,
+}
+
+impl TantivySchemaDiff {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for TantivySchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.added.is_empty() {
+            parts.push(format!("added {}", self.added.join(", ")));
+        }
+        if !self.removed.is_empty() {
+            parts.push(format!("removed {}", self.removed.join(", ")));
+        }
+        for change in &self.changed {
+            parts.push(format!(
+                "changed {} {} from {} to {}",
+                change.field,
+                change.option,
+                change.on_disk.as_deref().unwrap_or("unset"),
+                change.requested.as_deref().unwrap_or("unset"),
+            ));
+        }
+        write!(f, "{}", parts.join("; "))
+    }
+}
+
+/// Flattens nested options into dotted paths, e.g. `options.indexing.record`.
+fn flatten(prefix: String, value: &Value, options: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                flatten(format!("{prefix}.{key}"), value, options);
+            }
+        }
+        Value::String(text) => {
+            options.insert(prefix, text.clone());
+        }
+        value => {
+            options.insert(prefix, value.to_string());
+        }
+    }
+}
+
+fn field_options(field_entry: &FieldEntry) -> Result<BTreeMap<String, String>, TantivyIndexError> {
+    let mut options = BTreeMap::new();
+    if let Value::Object(object) = serde_json::to_value(field_entry)? {
+        for (key, value) in &object {
+            if key != "name" {
+                flatten(key.clone(), value, &mut options);
+            }
+        }
+    }
+    Ok(options)
+}
+
+pub(crate) fn diff_schemas(
+    on_disk: &Schema,
+    requested: &Schema,
+) -> Result<TantivySchemaDiff, TantivyIndexError> {
+    let mut diff = TantivySchemaDiff::default();
+    for (field, field_entry) in requested.fields() {
+        let Ok(disk_field) = on_disk.get_field(field_entry.name()) else {
+            diff.added.push(field_entry.name().to_string());
+            continue;
+        };
+        if disk_field != field {
+            diff.changed.push(TantivyFieldChange {
+                field: field_entry.name().to_string(),
+                option: "position".to_string(),
+                on_disk: Some(disk_field.field_id().to_string()),
+                requested: Some(field.field_id().to_string()),
+            });
+        }
+
+        let disk_options = field_options(on_disk.get_field_entry(disk_field))?;
+        let requested_options = field_options(field_entry)?;
+        let mut names = disk_options
+            .keys()
+            .chain(requested_options.keys())
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        for name in names {
+            let (disk_value, requested_value) =
+                (disk_options.get(name), requested_options.get(name));
+            if disk_value != requested_value {
+                diff.changed.push(TantivyFieldChange {
+                    field: field_entry.name().to_string(),
+                    option: name.clone(),
+                    on_disk: disk_value.cloned(),
+                    requested: requested_value.cloned(),
+                });
+            }
+        }
+    }
+    for (_, field_entry) in on_disk.fields() {
+        if requested.get_field(field_entry.name()).is_err() {
+            diff.removed.push(field_entry.name().to_string());
+        }
+    }
+    Ok(diff)
+}
+
+#[uniffi::export]
+impl TantivySchemaBuilder {
+    /// Compares the schema being built with the index at `path`, empty when there is
+    /// no index there yet. The builder can still be used afterwards.
+    #[uniffi::method]
+    pub fn diff_with_index(&self, path: String) -> Result<TantivySchemaDiff, TantivyIndexError> {
+        let requested = self.peek_schema().ok_or_else(|| {
+            TantivyIndexError::SchemaBuilderError("Schema already built or empty".to_string())
+        })?;
+        let directory = match MmapDirectory::open(Path::new(&path)) {
+            Ok(directory) => directory,
+            Err(_) => return Ok(TantivySchemaDiff::default()),
+        };
+        if !Index::exists(&directory).map_err(TantivyError::from)? {
+            return Ok(TantivySchemaDiff::default());
+        }
+        diff_schemas(&Index::open(directory)?.schema(), &requested)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{receipt, receipt_schema, temp_index_path};
+    use crate::{
+        NumericFieldOptions, TantivyIndex, TantivyIndexError, TantivyIndexOptions,
+        TantivySchemaBuilder, TantivyTokenizer, TextFieldOptions,
+    };
+
+    #[test]
+    fn schema_drift_is_reported_as_a_diff() {
+        let path = temp_index_path("schema_diff");
+        let index = TantivyIndex::new_with_schema(path.clone(), &receipt_schema()).unwrap();
+        index
+            .index_docs(vec![receipt("r1", "costco", 10.0)])
+            .unwrap();
+        index.commit().unwrap();
+        drop(index);
+
+        assert!(
+            receipt_schema()
+                .diff_with_index(path.clone())
+                .unwrap()
+                .is_empty()
+        );
+
+        let drifted = TantivySchemaBuilder::new();
+        drifted.add_text_field(
+            "id".to_string(),
+            TextFieldOptions {
+                tokenizer: TantivyTokenizer::Raw,
+                fast: true,
+                ..TextFieldOptions::default()
+            },
+        );
+        drifted.add_text_field(
+            "merchant".to_string(),
+            TextFieldOptions {
+                tokenizer: TantivyTokenizer::Raw,
+                ..TextFieldOptions::default()
+            },
+        );
+        drifted.add_u64_field("quantity".to_string(), NumericFieldOptions::default());
+
+        let diff = drifted.diff_with_index(path.clone()).unwrap();
+        assert_eq!(diff.added, vec!["quantity"]);
+        assert_eq!(diff.removed, vec!["total", "purchased", "tags"]);
+        let changed = diff
+            .changed
+            .iter()
+            .map(|change| {
+                (
+                    change.field.as_str(),
+                    change.option.as_str(),
+                    change.on_disk.as_deref(),
+                    change.requested.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert!(changed.contains(&(
+            "merchant",
+            "options.indexing.tokenizer",
+            Some("unicode"),
+            Some("raw")
+        )));
+        assert!(changed.contains(&(
+            "id",
+            "options.indexing.record",
+            Some("basic"),
+            Some("position")
+        )));
+
+        // The builder survives the check, opening still fails with the same diff.
+        let Err(TantivyIndexError::SchemaMismatch(message)) =
+            TantivyIndex::new_with_schema(path.clone(), &drifted)
+        else {
+            panic!("expected a schema mismatch");
+        };
+        assert!(message.contains("added quantity"));
+        assert!(
+            message.contains("changed merchant options.indexing.tokenizer from unicode to raw")
+        );
+
+        let reopened = TantivyIndex::open(path, TantivyIndexOptions::default()).unwrap();
+        assert_eq!(reopened.docs_count(), 1);
+        reopened.index_doc(receipt("r2", "target", 20.0)).unwrap();
+        reopened.commit().unwrap();
+        assert_eq!(reopened.docs_count(), 2);
+    }
+}
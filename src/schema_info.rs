@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+
+use tantivy::schema::{FieldEntry, FieldType, Schema};
+
+use crate::{
+    TantivyDatePrecision, TantivyIndex, TantivyIndexError, TantivyIndexRecordOption,
+    TantivySchemaBuilder, TantivyTokenizer,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum TantivyFieldType {
+    Text,
+    U64,
+    I64,
+    F64,
+    Bool,
+    Date,
+    Facet,
+    Bytes,
+    Json,
+    IpAddr,
+}
+
+/// A field of a schema, with the options of the `add_*_field` method that adds it.
+/// Options that do not apply to the field type are `None`.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TantivyFieldDescriptor {
+    pub name: String,
+    pub field_type: TantivyFieldType,
+    pub indexed: bool,
+    pub stored: bool,
+    pub fast: bool,
+    pub fieldnorms: bool,
+    /// Tokenizer of indexed text and JSON fields.
+    pub tokenizer: Option<TantivyTokenizer>,
+    pub record: Option<TantivyIndexRecordOption>,
+    /// Tokenizer of fast text and JSON fields, `None` keeps the text as is.
+    pub fast_tokenizer: Option<TantivyTokenizer>,
+    pub precision: Option<TantivyDatePrecision>,
+    pub expand_dots: Option<bool>,
+}
+
+impl From<&FieldEntry> for TantivyFieldDescriptor {
+    fn from(field_entry: &FieldEntry) -> Self {
+        let mut descriptor = TantivyFieldDescriptor {
+            name: field_entry.name().to_string(),
+            field_type: TantivyFieldType::Text,
+            indexed: field_entry.is_indexed(),
+            stored: field_entry.is_stored(),
+            fast: field_entry.is_fast(),
+            fieldnorms: field_entry.has_fieldnorms(),
+            tokenizer: None,
+            record: None,
+            fast_tokenizer: None,
+            precision: None,
+            expand_dots: None,
+        };
+        let mut indexing = None;
+        let mut fast_tokenizer = None;
+        descriptor.field_type = match field_entry.field_type() {
+            FieldType::Str(options) => {
+                indexing = options.get_indexing_options();
+                fast_tokenizer = options.get_fast_field_tokenizer_name();
+                TantivyFieldType::Text
+            }
+            FieldType::U64(_) => TantivyFieldType::U64,
+            FieldType::I64(_) => TantivyFieldType::I64,
+            FieldType::F64(_) => TantivyFieldType::F64,
+            FieldType::Bool(_) => TantivyFieldType::Bool,
+            FieldType::Date(options) => {
+                descriptor.precision = Some(options.get_precision().into());
+                TantivyFieldType::Date
+            }
+            FieldType::Facet(_) => TantivyFieldType::Facet,
+            FieldType::Bytes(_) => TantivyFieldType::Bytes,
+            FieldType::JsonObject(options) => {
+                indexing = options.get_text_indexing_options();
+                fast_tokenizer = options.get_fast_field_tokenizer_name();
+                descriptor.expand_dots = Some(options.is_expand_dots_enabled());
+                TantivyFieldType::Json
+            }
+            FieldType::IpAddr(_) => TantivyFieldType::IpAddr,
+        };
+        if let Some(indexing) = indexing {
+            descriptor.tokenizer = Some(TantivyTokenizer::from_name(indexing.tokenizer()));
+            descriptor.record = Some(indexing.index_option().into());
+        }
+        descriptor.fast_tokenizer = fast_tokenizer.map(TantivyTokenizer::from_name);
+        descriptor
+    }
+}
+
+pub(crate) fn describe_schema(schema: &Schema) -> Vec<TantivyFieldDescriptor> {
+    schema
+        .fields()
+        .map(|(_, field_entry)| field_entry.into())
+        .collect()
+}
+
+#[uniffi::export]
+impl TantivyIndex {
+    /// The fields of the index, in schema order.
+    #[uniffi::method]
+    fn schema(&self) -> Vec<TantivyFieldDescriptor> {
+        describe_schema(&self.index.schema())
+    }
+}
+
+#[uniffi::export]
+impl TantivySchemaBuilder {
+    /// A builder holding the fields of a schema serialized by tantivy, e.g. the
+    /// `schema.json` written by `schema-gen` or the `schema` of an index's meta.json.
+    #[uniffi::constructor]
+    pub fn from_json(json: String) -> Result<Self, TantivyIndexError> {
+        let field_entries: Vec<FieldEntry> = serde_json::from_str(&json)?;
+        let mut names = HashSet::new();
+        for field_entry in &field_entries {
+            if !names.insert(field_entry.name()) {
+                return Err(TantivyIndexError::SchemaBuilderError(format!(
+                    "Duplicate field name: {}",
+                    field_entry.name()
+                )));
+            }
+        }
+        let builder = TantivySchemaBuilder::new();
+        builder.add_field_entries(field_entries);
+        Ok(builder)
+    }
+
+    /// The fields added so far, serialized the way tantivy stores its schema.
+    /// Analyzers are not included.
+    #[uniffi::method]
+    pub fn to_json(&self) -> Result<String, TantivyIndexError> {
+        let schema = self.peek_schema().ok_or_else(|| {
+            TantivyIndexError::SchemaBuilderError("Schema already built or empty".to_string())
+        })?;
+        Ok(serde_json::to_string_pretty(&schema)?)
+    }
+
+    /// The fields added so far.
+    #[uniffi::method]
+    pub fn fields(&self) -> Vec<TantivyFieldDescriptor> {
+        self.peek_schema()
+            .map(|schema| describe_schema(&schema))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{make_index, receipt_schema};
+    use crate::{
+        DateFieldOptions, JsonFieldOptions, NumericFieldOptions, TantivySchemaBuilder,
+        TextFieldOptions,
+    };
+
+    use super::*;
+
+    #[test]
+    fn schema_describes_fields_and_round_trips_through_json() {
+        let index = make_index("schema_info");
+        let fields = index.schema();
+        let names = fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["id", "merchant", "total", "purchased", "tags"]);
+
+        let id = &fields[0];
+        assert_eq!(id.field_type, TantivyFieldType::Text);
+        assert!(id.indexed && id.stored && id.fast && !id.fieldnorms);
+        assert_eq!(id.tokenizer, Some(TantivyTokenizer::Raw));
+        assert_eq!(id.record, Some(TantivyIndexRecordOption::Basic));
+        assert_eq!(fields[1].tokenizer, Some(TantivyTokenizer::Unicode));
+        assert_eq!(fields[2].field_type, TantivyFieldType::F64);
+        assert_eq!(fields[2].tokenizer, None);
+        assert_eq!(
+            fields[3].precision,
+            Some(TantivyDatePrecision::Microseconds)
+        );
+        assert_eq!(fields[4].field_type, TantivyFieldType::Facet);
+
+        let json = receipt_schema().to_json().unwrap();
+        let from_json = TantivySchemaBuilder::from_json(json).unwrap();
+        assert_eq!(from_json.fields(), fields);
+
+        let builder = TantivySchemaBuilder::new();
+        builder.add_text_field(
+            "title".to_string(),
+            TextFieldOptions {
+                tokenizer: TantivyTokenizer::Ngram {
+                    min_gram: 2,
+                    max_gram: 5,
+                    prefix_only: true,
+                },
+                ..TextFieldOptions::default()
+            },
+        );
+        builder.add_json_field(
+            "metadata".to_string(),
+            JsonFieldOptions {
+                fast: true,
+                fast_tokenizer: Some(TantivyTokenizer::Raw),
+                expand_dots: true,
+                ..JsonFieldOptions::default()
+            },
+        );
+        builder.add_date_field("seen".to_string(), DateFieldOptions::default());
+        builder.add_ip_addr_field("ip".to_string(), NumericFieldOptions::default());
+        let fields = builder.fields();
+        assert_eq!(
+            fields[0].tokenizer,
+            Some(TantivyTokenizer::Ngram {
+                min_gram: 2,
+                max_gram: 5,
+                prefix_only: true,
+            })
+        );
+        assert_eq!(fields[1].field_type, TantivyFieldType::Json);
+        assert_eq!(fields[1].fast_tokenizer, Some(TantivyTokenizer::Raw));
+        assert_eq!(fields[1].expand_dots, Some(true));
+        assert_eq!(fields[3].field_type, TantivyFieldType::IpAddr);
+
+        let schema_gen = r#"[
+            {"name":"receiptId","type":"text","options":{"indexing":{"record":"basic","fieldnorms":true,"tokenizer":"raw"},"stored":true,"fast":false}},
+            {"name":"convertedTotal","type":"f64","options":{"indexed":false,"fieldnorms":false,"fast":true,"stored":true}}
+        ]"#;
+        let generated = TantivySchemaBuilder::from_json(schema_gen.to_string()).unwrap();
+        let fields = generated.fields();
+        assert_eq!(fields[0].name, "receiptId");
+        assert_eq!(fields[1].field_type, TantivyFieldType::F64);
+        assert!(fields[1].fast && !fields[1].indexed);
+        assert!(TantivySchemaBuilder::from_json("{}".to_string()).is_err());
+        let duplicate = r#"[
+            {"name":"id","type":"u64","options":{"indexed":true,"fieldnorms":false,"fast":false,"stored":true}},
+            {"name":"id","type":"u64","options":{"indexed":true,"fieldnorms":false,"fast":false,"stored":true}}
+        ]"#;
+        assert!(matches!(
+            TantivySchemaBuilder::from_json(duplicate.to_string()),
+            Err(TantivyIndexError::SchemaBuilderError(_))
+        ));
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+use tantivy::query::Query;
+use tantivy::schema::{Field, FieldType, Schema};
+use tantivy::snippet::{SnippetGenerator, collapse_overlapped_ranges};
+use tantivy::tokenizer::{TextAnalyzer, Token, TokenStream, Tokenizer};
+use tantivy::{Score, Searcher, TantivyDocument, Term};
+
+use crate::TantivyIndexError;
+
+const DEFAULT_MAX_NUM_CHARS: u32 = 150;
+
+/// A text field to build a highlighted snippet for.
+#[derive(Debug, Clone, Serialize, Deserialize, uniffi::Record)]
+pub struct TantivySnippetField {
+    pub field: String,
+    /// Maximum fragment length, defaults to 150.
+    #[serde(default)]
+    #[uniffi(default = None)]
+    pub max_num_chars: Option<u32>,
+}
+
+/// A highlighted byte range, relative to the snippet fragment.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct TantivyHighlightRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TantivySnippet {
+    pub field: String,
+    pub fragment: String,
+    /// The fragment with HTML escaped and highlighted terms wrapped in `<b>` tags.
+    pub html: String,
+    /// Sorted, non-overlapping byte ranges of the highlighted terms in `fragment`.
+    pub highlighted: Vec<TantivyHighlightRange>,
+}
+
+/// Re-emits the tokens of the field analyzer ordered by start offset.
+///
+/// The unicode tokenizer emits a dash compound after its parts, e.g. `co`, `op`,
+/// `coop` for "co-op". The snippet generator expects offsets to never go backwards
+/// and would underflow when a fragment starts in the middle of a compound.
+#[derive(Clone)]
+struct OffsetOrderedTokenizer {
+    analyzer: TextAnalyzer,
+    tokens: VecDeque<Token>,
+    token: Token,
+}
+
+struct OffsetOrderedTokenStream<'a> {
+    tokens: &'a mut VecDeque<Token>,
+    token: &'a mut Token,
+}
+
+impl Tokenizer for OffsetOrderedTokenizer {
+    type TokenStream<'a> = OffsetOrderedTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let mut tokens = Vec::new();
+        self.analyzer
+            .token_stream(text)
+            .process(&mut |token| tokens.push(token.clone()));
+        // A compound goes before its parts so the fragment it lands in spans all of them.
+        tokens.sort_by_key(|token| (token.offset_from, std::cmp::Reverse(token.offset_to)));
+
+        self.tokens.clear();
+        self.tokens.extend(tokens);
+        OffsetOrderedTokenStream {
+            tokens: &mut self.tokens,
+            token: &mut self.token,
+        }
+    }
+}
+
+impl TokenStream for OffsetOrderedTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        match self.tokens.pop_front() {
+            Some(token) => {
+                *self.token = token;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn token(&self) -> &Token {
+        self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.token
+    }
+}
+
+fn escape_html(text: &str, out: &mut String) {
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#x27;"),
+            _ => out.push(ch),
+        }
+    }
+}
+
+fn highlight_html(fragment: &str, highlighted: &[Range<usize>]) -> String {
+    let mut html = String::with_capacity(fragment.len());
+    let mut start_from = 0;
+    for range in highlighted {
+        escape_html(&fragment[start_from..range.start], &mut html);
+        html.push_str("<b>");
+        escape_html(&fragment[range.clone()], &mut html);
+        html.push_str("</b>");
+        start_from = range.end;
+    }
+    escape_html(&fragment[start_from..], &mut html);
+    html
+}
+
+/// Builds snippets for the requested fields of every hit of a single search.
+pub(crate) struct SnippetBuilder {
+    generators: Vec<(String, SnippetGenerator)>,
+}
+
+impl SnippetBuilder {
+    pub(crate) fn new(
+        searcher: &Searcher,
+        schema: &Schema,
+        query: &dyn Query,
+        snippet_fields: &[TantivySnippetField],
+    ) -> Result<Self, TantivyIndexError> {
+        let mut generators = Vec::with_capacity(snippet_fields.len());
+        for snippet_field in snippet_fields {
+            let field = schema.get_field(&snippet_field.field)?;
+            let field_entry = schema.get_field_entry(field);
+            if !matches!(field_entry.field_type(), FieldType::Str(_))
+                || !field_entry.is_indexed()
+                || !field_entry.is_stored()
+            {
+                return Err(TantivyIndexError::QueryError(format!(
+                    "Snippet field {} must be an indexed and stored text field",
+                    snippet_field.field
+                )));
+            }
+
+            let tokenizer = OffsetOrderedTokenizer {
+                analyzer: searcher.index().tokenizer_for_field(field)?,
+                tokens: VecDeque::new(),
+                token: Token::default(),
+            };
+            let max_num_chars = snippet_field.max_num_chars.unwrap_or(DEFAULT_MAX_NUM_CHARS);
+            generators.push((
+                snippet_field.field.clone(),
+                SnippetGenerator::new(
+                    highlight_terms(searcher, query, field)?,
+                    TextAnalyzer::from(tokenizer),
+                    field,
+                    max_num_chars.try_into()?,
+                ),
+            ));
+        }
+        Ok(SnippetBuilder { generators })
+    }
+
+    /// Returns the snippets of the fields that contain a query term.
+    pub(crate) fn snippets(&self, doc: &TantivyDocument) -> Vec<TantivySnippet> {
+        let mut snippets = Vec::new();
+        for (field, generator) in &self.generators {
+            let snippet = generator.snippet_from_doc(doc);
+            let fragment = snippet.fragment();
+            let highlighted = collapse_overlapped_ranges(snippet.highlighted())
+                .into_iter()
+                .map(|range| range.start..range.end.min(fragment.len()))
+                .filter(|range| range.start < range.end)
+                .collect::<Vec<_>>();
+            if highlighted.is_empty() {
+                continue;
+            }
+            snippets.push(TantivySnippet {
+                field: field.clone(),
+                fragment: fragment.to_string(),
+                html: highlight_html(fragment, &highlighted),
+                highlighted: highlighted
+                    .iter()
+                    .map(|range| TantivyHighlightRange {
+                        start: range.start as u64,
+                        end: range.end as u64,
+                    })
+                    .collect(),
+            });
+        }
+        snippets
+    }
+}
+
+/// Same term weighting as `SnippetGenerator::create`: rarer terms make better fragments.
+fn highlight_terms(
+    searcher: &Searcher,
+    query: &dyn Query,
+    field: Field,
+) -> Result<BTreeMap<String, Score>, TantivyIndexError> {
+    let mut terms: BTreeSet<Term> = BTreeSet::new();
+    query.query_terms(&mut |term, _| {
+        if term.field() == field {
+            terms.insert(term.clone());
+        }
+    });
+
+    let mut terms_text = BTreeMap::new();
+    for term in terms {
+        let Some(text) = term.value().as_str().map(str::to_string) else {
+            continue;
+        };
+        let doc_freq = searcher.doc_freq(&term)?;
+        if doc_freq > 0 {
+            terms_text.insert(text, 1.0 / (1.0 + doc_freq as Score));
+        }
+    }
+    Ok(terms_text)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{make_index, receipt};
+
+    use super::*;
+
+    fn merchant_snippet(max_num_chars: Option<u32>) -> Vec<TantivySnippetField> {
+        vec![TantivySnippetField {
+            field: "merchant".to_string(),
+            max_num_chars,
+        }]
+    }
+
+    fn merchant_term(text: &str) -> String {
+        format!(
+            r#"{{"type":"term","term":{{"name":"merchant","value":{{"type":"text","value":"{text}"}}}}}}"#
+        )
+    }
+
+    #[test]
+    fn snippets_highlight_dash_compounds() {
+        let index = make_index("snippets");
+        index
+            .index_docs(vec![
+                receipt("r1", "Trader <Joe's> co-op market", 10.0),
+                receipt("r2", "costco", 20.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let results = index
+            .search_dsl(
+                merchant_term("coop"),
+                10,
+                0,
+                vec![],
+                merchant_snippet(None),
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(results.docs.len(), 1);
+        let snippet = &results.docs[0].snippets[0];
+        assert_eq!(snippet.field, "merchant");
+        assert_eq!(snippet.fragment, "Trader <Joe's> co-op market");
+        assert_eq!(
+            snippet.highlighted,
+            vec![TantivyHighlightRange { start: 15, end: 20 }]
+        );
+        assert_eq!(
+            snippet.html,
+            "Trader &lt;Joe&#x27;s&gt; <b>co-op</b> market"
+        );
+
+        // The fragment boundary falls between the parts of the compound.
+        let results = index
+            .search_dsl(
+                merchant_term("coop"),
+                10,
+                0,
+                vec![],
+                merchant_snippet(Some(9)),
+                None,
+                None,
+            )
+            .unwrap();
+        let snippet = &results.docs[0].snippets[0];
+        assert_eq!(snippet.fragment, "co-op");
+        assert_eq!(
+            snippet.highlighted,
+            vec![TantivyHighlightRange { start: 0, end: 5 }]
+        );
+
+        let results = index
+            .search_dsl(
+                merchant_term("costco"),
+                10,
+                0,
+                vec![],
+                merchant_snippet(None),
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(results.docs[0].snippets[0].html, "<b>costco</b>");
+    }
+}
@@ -0,0 +1,755 @@
+use std::cmp::{Ordering, Reverse};
+use std::io;
+use std::ops::Bound;
+
+use serde::{Deserialize, Serialize};
+use tantivy::collector::{Collector, Count, SegmentCollector, TopDocs, TopNComputer};
+use tantivy::columnar::{Column, ColumnType, MonotonicallyMappableToU64, StrColumn};
+use tantivy::query::Query;
+use tantivy::schema::{FieldType, Schema};
+use tantivy::{DocAddress, DocId, Score, Searcher, SegmentOrdinal, SegmentReader};
+
+use crate::{FieldValue, TantivyIndexError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "snake_case")]
+pub enum TantivySortOrder {
+    Asc,
+    Desc,
+}
+
+/// One key of a multi-key sort. Keys are applied in order and the relevance score
+/// always breaks the remaining ties.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct TantivySortField {
+    pub field: String,
+    pub order: TantivySortOrder,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub(crate) enum SortValue {
+    Numeric(u64),
+    Str(String),
+}
+
+/// A sort key part where the greater value always ranks first.
+///
+/// `Missing` is the smallest variant, so documents without a value rank last
+/// regardless of the requested order.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub(crate) enum SortKeyPart {
+    Missing,
+    Asc(Reverse<SortValue>),
+    Desc(SortValue),
+}
+
+impl SortKeyPart {
+    fn new(value: Option<SortValue>, order: TantivySortOrder) -> Self {
+        match (value, order) {
+            (None, _) => SortKeyPart::Missing,
+            (Some(value), TantivySortOrder::Asc) => SortKeyPart::Asc(Reverse(value)),
+            (Some(value), TantivySortOrder::Desc) => SortKeyPart::Desc(value),
+        }
+    }
+
+    fn value(&self) -> Option<&SortValue> {
+        match self {
+            SortKeyPart::Missing => None,
+            SortKeyPart::Asc(Reverse(value)) | SortKeyPart::Desc(value) => Some(value),
+        }
+    }
+
+    fn try_map(
+        self,
+        map: impl FnOnce(SortValue) -> io::Result<SortValue>,
+    ) -> io::Result<SortKeyPart> {
+        Ok(match self {
+            SortKeyPart::Missing => SortKeyPart::Missing,
+            SortKeyPart::Asc(Reverse(value)) => SortKeyPart::Asc(Reverse(map(value)?)),
+            SortKeyPart::Desc(value) => SortKeyPart::Desc(map(value)?),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub(crate) struct SortKey {
+    pub(crate) parts: Vec<SortKeyPart>,
+    pub(crate) score: Score,
+}
+
+/// Position of a hit in the total order of a search: sort key first, then ascending
+/// doc address so that ties are broken the same way on every page.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+struct RankPosition {
+    key: SortKey,
+    address: Reverse<(SegmentOrdinal, DocId)>,
+}
+
+/// Ranks every hit after the cursor above the hits before it, so the top docs
+/// only fall back to already returned hits once the remaining ones run out.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+struct PagedKey {
+    after_cursor: bool,
+    position: RankPosition,
+}
+
+/// The last hit of a page, handed out as an opaque string to fetch the next one.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct SearchCursor {
+    /// Searcher generation the hit addresses belong to.
+    pub(crate) generation: u64,
+    sort: Vec<TantivySortField>,
+    position: RankPosition,
+}
+
+impl SearchCursor {
+    pub(crate) fn encode(&self) -> Result<String, TantivyIndexError> {
+        let json = serde_json::to_vec(self)?;
+        Ok(json.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+
+    pub(crate) fn decode(cursor: &str) -> Result<Self, TantivyIndexError> {
+        let invalid = || TantivyIndexError::QueryError("Invalid search cursor".to_string());
+        if !cursor.len().is_multiple_of(2) {
+            return Err(invalid());
+        }
+        let json = (0..cursor.len())
+            .step_by(2)
+            .map(|idx| {
+                cursor
+                    .get(idx..idx + 2)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(invalid)?;
+        serde_json::from_slice(&json).map_err(|_| invalid())
+    }
+}
+
+enum SortColumn {
+    Numeric(Column<u64>),
+    /// Values are compared by term ordinal, which orders them like their text
+    /// within a segment. Ordinals are stored as `2 * ord + 1` so that text missing
+    /// from the segment, such as a cursor's, can sort between its neighbours.
+    Str(StrColumn),
+    Missing,
+}
+
+impl SortColumn {
+    fn value(&self, doc: DocId) -> Option<SortValue> {
+        match self {
+            SortColumn::Numeric(column) => column.first(doc).map(SortValue::Numeric),
+            SortColumn::Str(column) => {
+                let ord = column.term_ords(doc).next()?;
+                Some(SortValue::Numeric(ord * 2 + 1))
+            }
+            SortColumn::Missing => None,
+        }
+    }
+
+    /// Translates a value of a cursor into the segment's ordinal space.
+    fn segment_value(&self, value: SortValue) -> io::Result<SortValue> {
+        let (SortColumn::Str(column), SortValue::Str(text)) = (self, &value) else {
+            return Ok(value);
+        };
+        let dictionary = column.dictionary();
+        let (lower, _) =
+            dictionary.term_bounds_to_ord(Bound::Excluded(text.as_str()), Bound::Unbounded)?;
+        let key = match lower {
+            Bound::Excluded(ord) => ord * 2 + 1,
+            Bound::Included(next_ord) => next_ord.min(dictionary.num_terms() as u64) * 2,
+            Bound::Unbounded => 0,
+        };
+        Ok(SortValue::Numeric(key))
+    }
+
+    /// Turns an ordinal of the segment back into its text.
+    fn resolve_value(&self, value: SortValue) -> io::Result<SortValue> {
+        let (SortColumn::Str(column), SortValue::Numeric(key)) = (self, &value) else {
+            return Ok(value);
+        };
+        let mut text = String::new();
+        column.ord_to_str(key / 2, &mut text)?;
+        Ok(SortValue::Str(text))
+    }
+}
+
+const NUMERIC_COLUMN_TYPES: [ColumnType; 5] = [
+    ColumnType::U64,
+    ColumnType::I64,
+    ColumnType::F64,
+    ColumnType::Bool,
+    ColumnType::DateTime,
+];
+
+/// Collects the top hits by sort key, resolving text values only for the hits each
+/// segment keeps.
+struct SortedTopDocs {
+    sort: Vec<TantivySortField>,
+    str_fields: Vec<bool>,
+    limit: usize,
+    offset: usize,
+    after: Option<RankPosition>,
+}
+
+struct SortedSegmentCollector {
+    columns: Vec<(SortColumn, TantivySortOrder)>,
+    segment_ord: SegmentOrdinal,
+    /// The cursor position with its text values translated to this segment.
+    after: Option<RankPosition>,
+    top_n: TopNComputer<PagedKey, DocId>,
+}
+
+impl Collector for SortedTopDocs {
+    type Fruit = Vec<(PagedKey, DocAddress)>;
+    type Child = SortedSegmentCollector;
+
+    fn for_segment(
+        &self,
+        segment_ord: SegmentOrdinal,
+        segment_reader: &SegmentReader,
+    ) -> tantivy::Result<Self::Child> {
+        let fast_fields = segment_reader.fast_fields();
+        let mut columns = Vec::with_capacity(self.sort.len());
+        for (sort_field, is_str) in self.sort.iter().zip(&self.str_fields) {
+            let column = if *is_str {
+                fast_fields
+                    .str(&sort_field.field)?
+                    .map_or(SortColumn::Missing, SortColumn::Str)
+            } else {
+                fast_fields
+                    .u64_lenient_for_type(Some(&NUMERIC_COLUMN_TYPES), &sort_field.field)?
+                    .map_or(SortColumn::Missing, |(column, _)| {
+                        SortColumn::Numeric(column)
+                    })
+            };
+            columns.push((column, sort_field.order));
+        }
+        let after = match &self.after {
+            Some(after) => {
+                let mut after = after.clone();
+                for (part, (column, _)) in after.key.parts.iter_mut().zip(&columns) {
+                    *part = part.clone().try_map(|value| column.segment_value(value))?;
+                }
+                Some(after)
+            }
+            None => None,
+        };
+        Ok(SortedSegmentCollector {
+            columns,
+            segment_ord,
+            after,
+            top_n: TopNComputer::new(self.limit + self.offset),
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        true
+    }
+
+    fn merge_fruits(
+        &self,
+        segment_fruits: Vec<tantivy::Result<Vec<(PagedKey, DocAddress)>>>,
+    ) -> tantivy::Result<Self::Fruit> {
+        let mut hits = Vec::new();
+        for segment_hits in segment_fruits {
+            hits.extend(segment_hits?);
+        }
+        hits.sort_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        Ok(hits
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit)
+            .collect())
+    }
+}
+
+impl SegmentCollector for SortedSegmentCollector {
+    type Fruit = tantivy::Result<Vec<(PagedKey, DocAddress)>>;
+
+    fn collect(&mut self, doc: DocId, score: Score) {
+        let parts = self
+            .columns
+            .iter()
+            .map(|(column, order)| SortKeyPart::new(column.value(doc), *order))
+            .collect();
+        let position = RankPosition {
+            key: SortKey { parts, score },
+            address: Reverse((self.segment_ord, doc)),
+        };
+        let key = PagedKey {
+            after_cursor: self.after.as_ref().is_none_or(|after| position < *after),
+            position,
+        };
+        self.top_n.push(key, doc);
+    }
+
+    fn harvest(self) -> Self::Fruit {
+        let mut hits = Vec::new();
+        for hit in self.top_n.into_vec() {
+            let mut key = hit.feature;
+            for (part, (column, _)) in key.position.key.parts.iter_mut().zip(&self.columns) {
+                *part = part.clone().try_map(|value| column.resolve_value(value))?;
+            }
+            hits.push((key, DocAddress::new(self.segment_ord, hit.doc)));
+        }
+        Ok(hits)
+    }
+}
+
+pub(crate) struct RankedDoc {
+    pub(crate) score: Score,
+    pub(crate) sort_values: Vec<Option<FieldValue>>,
+    pub(crate) address: DocAddress,
+    position: RankPosition,
+}
+
+impl RankedDoc {
+    /// Cursor pointing right after this hit, for the searcher and sort it came from.
+    pub(crate) fn cursor(
+        &self,
+        searcher: &Searcher,
+        sort: &[TantivySortField],
+    ) -> Result<String, TantivyIndexError> {
+        SearchCursor {
+            generation: searcher.generation().generation_id(),
+            sort: sort.to_vec(),
+            position: self.position.clone(),
+        }
+        .encode()
+    }
+}
+
+/// Cuts hits fetched with one extra down to `limit`, with a cursor to the next page
+/// only when the extra hit shows there is one.
+pub(crate) fn take_page(
+    mut ranked: Vec<RankedDoc>,
+    limit: usize,
+    searcher: &Searcher,
+    sort: &[TantivySortField],
+) -> Result<(Vec<RankedDoc>, Option<String>), TantivyIndexError> {
+    if ranked.len() <= limit {
+        return Ok((ranked, None));
+    }
+    ranked.truncate(limit);
+    let next_cursor = match ranked.last() {
+        Some(last) => Some(last.cursor(searcher, sort)?),
+        None => None,
+    };
+    Ok((ranked, next_cursor))
+}
+
+fn validate_sort_fields(
+    schema: &Schema,
+    sort: &[TantivySortField],
+) -> Result<Vec<bool>, TantivyIndexError> {
+    let mut str_fields = Vec::with_capacity(sort.len());
+    for sort_field in sort {
+        let field = schema.get_field(&sort_field.field)?;
+        let field_entry = schema.get_field_entry(field);
+        if !field_entry.is_fast() {
+            return Err(TantivyIndexError::QueryError(format!(
+                "Sort field {} is not a fast field",
+                sort_field.field
+            )));
+        }
+        match field_entry.field_type() {
+            FieldType::Str(_) => str_fields.push(true),
+            FieldType::U64(_)
+            | FieldType::I64(_)
+            | FieldType::F64(_)
+            | FieldType::Bool(_)
+            | FieldType::Date(_) => str_fields.push(false),
+            _ => {
+                return Err(TantivyIndexError::QueryError(format!(
+                    "Sort field {} must be a text, numeric, bool or date field",
+                    sort_field.field
+                )));
+            }
+        }
+    }
+    Ok(str_fields)
+}
+
+pub(crate) fn sort_value_to_field_value(
+    schema: &Schema,
+    field_name: &str,
+    value: &SortValue,
+) -> Option<FieldValue> {
+    let field = schema.get_field(field_name).ok()?;
+    match (schema.get_field_entry(field).field_type(), value) {
+        (FieldType::Str(_), SortValue::Str(text)) => Some(FieldValue::Text(text.clone())),
+        (FieldType::U64(_), SortValue::Numeric(raw)) => Some(FieldValue::U64(*raw)),
+        (FieldType::I64(_), SortValue::Numeric(raw)) => Some(FieldValue::I64(i64::from_u64(*raw))),
+        (FieldType::F64(_), SortValue::Numeric(raw)) => Some(FieldValue::F64(f64::from_u64(*raw))),
+        (FieldType::Bool(_), SortValue::Numeric(raw)) => {
+            Some(FieldValue::Bool(bool::from_u64(*raw)))
+        }
+        (FieldType::Date(_), SortValue::Numeric(raw)) => Some(FieldValue::Date(
+            tantivy::DateTime::from_u64(*raw).into_timestamp_micros(),
+        )),
+        _ => None,
+    }
+}
+
+/// Collects the top documents for `query`, ranked by score or by the sort keys.
+///
+/// With a cursor, only the hits ranked after it are returned. The cursor must come
+/// from the same `searcher`.
+pub(crate) fn search_top_docs(
+    searcher: &Searcher,
+    schema: &Schema,
+    query: &dyn Query,
+    limit: usize,
+    offset: usize,
+    sort: &[TantivySortField],
+    after: Option<&SearchCursor>,
+) -> Result<(usize, Vec<RankedDoc>), TantivyIndexError> {
+    if sort.is_empty() && after.is_none() {
+        let top_docs = TopDocs::with_limit(limit).and_offset(offset);
+        let (doc_count, top_docs) = searcher.search(query, &(Count, top_docs))?;
+        let ranked = top_docs
+            .into_iter()
+            .map(|(score, address)| RankedDoc {
+                score,
+                sort_values: Vec::new(),
+                address,
+                position: RankPosition {
+                    key: SortKey {
+                        parts: Vec::new(),
+                        score,
+                    },
+                    address: Reverse((address.segment_ord, address.doc_id)),
+                },
+            })
+            .collect();
+        return Ok((doc_count, ranked));
+    }
+
+    if let Some(after) = after
+        && (after.generation != searcher.generation().generation_id() || after.sort != sort)
+    {
+        return Err(TantivyIndexError::QueryError(
+            "Search cursor does not match this search".to_string(),
+        ));
+    }
+
+    let str_fields = validate_sort_fields(schema, sort)?;
+    let collector = SortedTopDocs {
+        sort: sort.to_vec(),
+        str_fields,
+        limit,
+        offset,
+        after: after.map(|after| after.position.clone()),
+    };
+    let (doc_count, top_docs) = searcher.search(query, &(Count, collector))?;
+
+    let ranked = top_docs
+        .into_iter()
+        .filter(|(key, _)| key.after_cursor)
+        .map(|(key, address)| RankedDoc {
+            score: key.position.key.score,
+            sort_values: sort
+                .iter()
+                .zip(&key.position.key.parts)
+                .map(|(sort_field, part)| {
+                    part.value().and_then(|value| {
+                        sort_value_to_field_value(schema, &sort_field.field, value)
+                    })
+                })
+                .collect(),
+            address,
+            position: key.position,
+        })
+        .collect();
+
+    Ok((doc_count, ranked))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{make_index, merchant_of, receipt};
+    use crate::{DocumentField, TantivyDocumentFields};
+
+    use super::*;
+
+    fn sort_by(field: &str, order: TantivySortOrder) -> Vec<TantivySortField> {
+        vec![TantivySortField {
+            field: field.to_string(),
+            order,
+        }]
+    }
+
+    #[test]
+    fn search_dsl_sorts_by_fast_fields() {
+        let index = make_index("sort_fast_fields");
+        index
+            .index_docs(vec![
+                receipt("r1", "costco", 30.0),
+                receipt("r2", "target", 10.0),
+                receipt("r3", "walmart", 20.0),
+                TantivyDocumentFields {
+                    fields: vec![DocumentField {
+                        name: "id".to_string(),
+                        value: FieldValue::Text("r4".to_string()),
+                    }],
+                },
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let all = r#"{"type":"all"}"#.to_string();
+        let totals = |order| {
+            index
+                .search_dsl(
+                    all.clone(),
+                    10,
+                    0,
+                    sort_by("total", order),
+                    vec![],
+                    None,
+                    None,
+                )
+                .unwrap()
+                .docs
+                .into_iter()
+                .map(|hit| match hit.sort_values[0] {
+                    Some(FieldValue::F64(total)) => Some(total),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            totals(TantivySortOrder::Desc),
+            vec![Some(30.0), Some(20.0), Some(10.0), None]
+        );
+        assert_eq!(
+            totals(TantivySortOrder::Asc),
+            vec![Some(10.0), Some(20.0), Some(30.0), None]
+        );
+
+        let page = index
+            .search_dsl(
+                all.clone(),
+                2,
+                1,
+                sort_by("id", TantivySortOrder::Desc),
+                vec![],
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(page.count, 4);
+        let ids = page
+            .docs
+            .iter()
+            .map(|hit| hit.sort_values[0].clone())
+            .collect::<Vec<_>>();
+        assert!(matches!(&ids[0], Some(FieldValue::Text(id)) if id == "r3"));
+        assert!(matches!(&ids[1], Some(FieldValue::Text(id)) if id == "r2"));
+
+        assert!(
+            index
+                .search_dsl(
+                    all,
+                    10,
+                    0,
+                    sort_by("merchant", TantivySortOrder::Asc),
+                    vec![],
+                    None,
+                    None
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn sort_values_round_trip_dates() {
+        let index = make_index("sort_dates");
+        let dated = |id: &str, micros: i64| {
+            let mut doc = receipt(id, "costco", 1.0);
+            doc.fields.push(DocumentField {
+                name: "purchased".to_string(),
+                value: FieldValue::Date(micros),
+            });
+            doc
+        };
+        index
+            .index_docs(vec![
+                dated("r1", 1_700_000_000_123_456),
+                dated("r2", -86_400_000_000),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let results = index
+            .search_dsl(
+                r#"{"type":"all"}"#.to_string(),
+                10,
+                0,
+                sort_by("purchased", TantivySortOrder::Asc),
+                vec![],
+                None,
+                None,
+            )
+            .unwrap();
+        let dates = results
+            .docs
+            .iter()
+            .map(|hit| match hit.sort_values[0] {
+                Some(FieldValue::Date(micros)) => micros,
+                _ => panic!("expected a date sort value"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(dates, vec![-86_400_000_000, 1_700_000_000_123_456]);
+    }
+
+    #[test]
+    fn text_sort_cursors_span_segments() {
+        let index = make_index("sort_text_segments");
+        for ids in [["r5", "r1", "r3"], ["r4", "r2", "r6"]] {
+            index
+                .index_docs(ids.iter().map(|id| receipt(id, "costco", 1.0)).collect())
+                .unwrap();
+            index.commit().unwrap();
+        }
+        index.reader.reload().unwrap();
+
+        let mut ids = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = index
+                .search_dsl(
+                    r#"{"type":"all"}"#.to_string(),
+                    2,
+                    0,
+                    sort_by("id", TantivySortOrder::Asc),
+                    vec![],
+                    cursor,
+                    None,
+                )
+                .unwrap();
+            ids.extend(page.docs.into_iter().map(|hit| match &hit.sort_values[0] {
+                Some(FieldValue::Text(id)) => id.clone(),
+                _ => panic!("expected a text sort value"),
+            }));
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        assert_eq!(ids, vec!["r1", "r2", "r3", "r4", "r5", "r6"]);
+    }
+
+    #[test]
+    fn cursor_pages_are_stable_across_commits() {
+        let index = make_index("sort_cursor");
+        index
+            .index_docs(vec![
+                receipt("r1", "costco", 30.0),
+                receipt("r2", "target", 10.0),
+                receipt("r3", "walmart", 20.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+        index
+            .index_docs(vec![
+                receipt("r4", "costco", 20.0),
+                receipt("r5", "target", 10.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let all = r#"{"type":"all"}"#.to_string();
+        // A search that fits on one page has no cursor to keep its searcher for.
+        let single_page = index
+            .search_dsl(all.clone(), 10, 0, vec![], vec![], None, None)
+            .unwrap();
+        assert!(single_page.next_cursor.is_none());
+        assert!(index.pinned_searchers.lock().unwrap().is_empty());
+
+        let id_of = |doc: &TantivyDocumentFields| {
+            doc.fields
+                .iter()
+                .find_map(|field| match (&field.name[..], &field.value) {
+                    ("id", FieldValue::Text(id)) => Some(id.clone()),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        let paginate = |sort: Vec<TantivySortField>, late_id: &str| {
+            let mut hits = Vec::new();
+            let mut cursor = None;
+            loop {
+                let page = index
+                    .search_dsl(all.clone(), 2, 0, sort.clone(), vec![], cursor, None)
+                    .unwrap();
+                hits.extend(page.docs);
+                if hits.len() == 2 {
+                    // Not visible to the pages of a search that already started.
+                    index.index_doc(receipt(late_id, "aldi", 40.0)).unwrap();
+                    index.commit().unwrap();
+                    index.reader.reload().unwrap();
+                }
+                match page.next_cursor {
+                    Some(next) => cursor = Some(next),
+                    None => return hits,
+                }
+            }
+        };
+
+        let sorted = paginate(sort_by("total", TantivySortOrder::Desc), "late1");
+        assert_eq!(
+            sorted
+                .iter()
+                .map(|hit| merchant_of(&hit.doc).unwrap())
+                .collect::<Vec<_>>(),
+            vec!["costco", "walmart", "costco", "target", "target"]
+        );
+
+        let mut unsorted = paginate(Vec::new(), "late2")
+            .iter()
+            .map(|hit| id_of(&hit.doc))
+            .collect::<Vec<_>>();
+        unsorted.sort();
+        assert_eq!(unsorted, vec!["late1", "r1", "r2", "r3", "r4", "r5"]);
+
+        let expired = SearchCursor {
+            generation: u64::MAX,
+            sort: Vec::new(),
+            position: RankPosition {
+                key: SortKey {
+                    parts: Vec::new(),
+                    score: 1.0,
+                },
+                address: Reverse((0, 0)),
+            },
+        };
+        assert!(
+            index
+                .search_dsl(
+                    all.clone(),
+                    2,
+                    0,
+                    vec![],
+                    vec![],
+                    Some(expired.encode().unwrap()),
+                    None
+                )
+                .is_err()
+        );
+        assert!(
+            index
+                .search_dsl(
+                    all,
+                    2,
+                    0,
+                    vec![],
+                    vec![],
+                    Some("not a cursor".to_string()),
+                    None
+                )
+                .is_err()
+        );
+    }
+}
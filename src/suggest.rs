@@ -0,0 +1,418 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use levenshtein_automata::{DFA, Distance, LevenshteinAutomatonBuilder, SINK_STATE};
+use tantivy::schema::{Field, FieldType, Schema};
+use tantivy::{Index, Searcher};
+use tantivy_fst::Automaton;
+
+use crate::{TantivyIndex, TantivyIndexError};
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TantivyTermSuggestion {
+    pub term: String,
+    /// Documents containing the term, summed over segments. Deleted documents
+    /// still count until their segment is merged.
+    pub doc_freq: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TantivySpellToken {
+    /// The token as analyzed, e.g. lowercased.
+    pub token: String,
+    /// Whether the token itself is an indexed term.
+    pub found: bool,
+    /// Other indexed terms within the edit distance, most frequent first.
+    pub alternatives: Vec<TantivyTermSuggestion>,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TantivySpellSuggestion {
+    /// The analyzed tokens joined by spaces, each token that is not indexed
+    /// replaced by its most frequent alternative.
+    pub corrected: String,
+    pub tokens: Vec<TantivySpellToken>,
+}
+
+/// Alternatives returned per misspelled token.
+const MAX_SPELL_ALTERNATIVES: usize = 5;
+
+/// Matches every term starting with the given bytes.
+pub(crate) struct PrefixAutomaton<'a>(pub(crate) &'a [u8]);
+
+impl Automaton for PrefixAutomaton<'_> {
+    /// Bytes of the prefix matched so far, `None` once a byte differs.
+    type State = Option<usize>;
+
+    fn start(&self) -> Self::State {
+        Some(0)
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        matches!(state, Some(matched) if *matched >= self.0.len())
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        state.is_some()
+    }
+
+    fn will_always_match(&self, state: &Self::State) -> bool {
+        self.is_match(state)
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        let matched = (*state)?;
+        match self.0.get(matched) {
+            None => Some(matched),
+            Some(expected) if *expected == byte => Some(matched + 1),
+            Some(_) => None,
+        }
+    }
+}
+
+/// Matches every term within the edit distance the DFA was built for.
+struct LevenshteinAutomaton(DFA);
+
+impl Automaton for LevenshteinAutomaton {
+    type State = u32;
+
+    fn start(&self) -> Self::State {
+        self.0.initial_state()
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        matches!(self.0.distance(*state), Distance::Exact(_))
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        *state != SINK_STATE
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        self.0.transition(*state, byte)
+    }
+}
+
+/// The field `name`, which must be an indexed text field.
+pub(crate) fn indexed_text_field(schema: &Schema, name: &str) -> Result<Field, TantivyIndexError> {
+    let field = schema.get_field(name)?;
+    match schema.get_field_entry(field).field_type() {
+        FieldType::Str(options) if options.get_indexing_options().is_some() => Ok(field),
+        _ => Err(TantivyIndexError::QueryError(format!(
+            "Field {name} is not an indexed text field"
+        ))),
+    }
+}
+
+/// The tokens the field's analyzer makes of `text`.
+fn analyzed_tokens(
+    index: &Index,
+    field: Field,
+    text: &str,
+) -> Result<Vec<String>, TantivyIndexError> {
+    let mut analyzer = index.tokenizer_for_field(field)?;
+    let mut tokens = Vec::new();
+    analyzer
+        .token_stream(text)
+        .process(&mut |token| tokens.push(token.text.clone()));
+    Ok(tokens)
+}
+
+/// Streams the terms `automaton` accepts from every segment, merging their doc
+/// frequencies, and keeps the `limit` most frequent.
+fn collect_terms<A>(
+    searcher: &Searcher,
+    field: Field,
+    automaton: &A,
+    limit: usize,
+) -> Result<Vec<TantivyTermSuggestion>, TantivyIndexError>
+where
+    A: Automaton,
+    A::State: Clone,
+{
+    let inverted_indexes = searcher
+        .segment_readers()
+        .iter()
+        .map(|segment_reader| segment_reader.inverted_index(field))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut streams = inverted_indexes
+        .iter()
+        .map(|inverted_index| inverted_index.terms().search(automaton).into_stream())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The next term of each segment, the smallest on top, so that equal terms of
+    // different segments come out together.
+    let mut heads = BinaryHeap::with_capacity(streams.len());
+    for (segment, stream) in streams.iter_mut().enumerate() {
+        if stream.advance() {
+            heads.push(Reverse((stream.key().to_vec(), segment)));
+        }
+    }
+    // The most frequent terms so far, the least frequent on top.
+    let mut top = BinaryHeap::new();
+    while let Some(Reverse((key, segment))) = heads.pop() {
+        let mut segments = vec![segment];
+        while heads.peek().is_some_and(|Reverse((next, _))| *next == key) {
+            segments.extend(heads.pop().map(|Reverse((_, segment))| segment));
+        }
+        let mut doc_freq = 0;
+        for segment in segments {
+            let stream = &mut streams[segment];
+            doc_freq += u64::from(stream.value().doc_freq);
+            if stream.advance() {
+                heads.push(Reverse((stream.key().to_vec(), segment)));
+            }
+        }
+
+        // Terms come in order, so a tie never displaces a term already kept.
+        if top.len() == limit
+            && top
+                .peek()
+                .is_none_or(|Reverse((least, _))| doc_freq <= *least)
+        {
+            continue;
+        }
+        let Ok(term) = String::from_utf8(key) else {
+            continue;
+        };
+        top.push(Reverse((doc_freq, Reverse(term))));
+        if top.len() > limit {
+            top.pop();
+        }
+    }
+
+    Ok(top
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((doc_freq, Reverse(term)))| TantivyTermSuggestion { term, doc_freq })
+        .collect())
+}
+
+fn term_exists(searcher: &Searcher, field: Field, term: &str) -> Result<bool, TantivyIndexError> {
+    for segment_reader in searcher.segment_readers() {
+        if segment_reader
+            .inverted_index(field)?
+            .terms()
+            .get(term)?
+            .is_some()
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[uniffi::export]
+impl TantivyIndex {
+    /// Completes the last word of `prefix` from the indexed terms of a text field,
+    /// most frequent first.
+    ///
+    /// The prefix goes through the field's analyzer, so suggestions are terms as
+    /// indexed, e.g. lowercased.
+    #[uniffi::method]
+    fn suggest(
+        &self,
+        field: String,
+        prefix: String,
+        limit: u32,
+    ) -> Result<Vec<TantivyTermSuggestion>, TantivyIndexError> {
+        let schema = self.index.schema();
+        let field = indexed_text_field(&schema, &field)?;
+        let Some(last_token) = analyzed_tokens(&self.index, field, &prefix)?.pop() else {
+            return Ok(Vec::new());
+        };
+
+        let searcher = self.reader.searcher();
+        collect_terms(
+            &searcher,
+            field,
+            &PrefixAutomaton(last_token.as_bytes()),
+            limit.try_into()?,
+        )
+    }
+
+    /// Suggests corrections for each analyzed token of `text` from the indexed terms
+    /// of a text field within `max_distance` edits (0 to 2, a transposition counts
+    /// as one).
+    #[uniffi::method]
+    fn spell_suggest(
+        &self,
+        field: String,
+        text: String,
+        max_distance: u8,
+    ) -> Result<TantivySpellSuggestion, TantivyIndexError> {
+        if max_distance > 2 {
+            return Err(TantivyIndexError::QueryError(format!(
+                "Spelling distance must be at most 2, got {max_distance}"
+            )));
+        }
+        let schema = self.index.schema();
+        let field = indexed_text_field(&schema, &field)?;
+        let automaton_builder = LevenshteinAutomatonBuilder::new(max_distance, true);
+        let searcher = self.reader.searcher();
+
+        let mut tokens = Vec::new();
+        for token in analyzed_tokens(&self.index, field, &text)? {
+            let found = term_exists(&searcher, field, &token)?;
+            let automaton = LevenshteinAutomaton(automaton_builder.build_dfa(&token));
+            let mut alternatives =
+                collect_terms(&searcher, field, &automaton, MAX_SPELL_ALTERNATIVES + 1)?;
+            alternatives.retain(|alternative| alternative.term != token);
+            alternatives.truncate(MAX_SPELL_ALTERNATIVES);
+            tokens.push(TantivySpellToken {
+                token,
+                found,
+                alternatives,
+            });
+        }
+
+        let corrected = tokens
+            .iter()
+            .map(|token| match token.alternatives.first() {
+                Some(alternative) if !token.found => alternative.term.as_str(),
+                _ => token.token.as_str(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        Ok(TantivySpellSuggestion { corrected, tokens })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{make_index, receipt};
+
+    #[test]
+    fn suggest_completes_prefixes_by_doc_freq() {
+        let index = make_index("suggest");
+        index
+            .index_docs(vec![
+                receipt("r1", "Starbucks", 5.0),
+                receipt("r2", "Starbucks Reserve", 7.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+        // A second segment, doc frequencies are merged across both.
+        index
+            .index_docs(vec![
+                receipt("r3", "Star Market", 20.0),
+                receipt("r4", "Starbucks", 4.0),
+                receipt("r5", "Target", 30.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let suggestions = index
+            .suggest("merchant".to_string(), "ST".to_string(), 10)
+            .unwrap()
+            .into_iter()
+            .map(|suggestion| (suggestion.term, suggestion.doc_freq))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            suggestions,
+            vec![("starbucks".to_string(), 3), ("star".to_string(), 1)]
+        );
+
+        let last_word = index
+            .suggest("merchant".to_string(), "starbucks re".to_string(), 10)
+            .unwrap();
+        assert_eq!(last_word.len(), 1);
+        assert_eq!(last_word[0].term, "reserve");
+
+        let most_frequent = index
+            .suggest("merchant".to_string(), "star".to_string(), 1)
+            .unwrap();
+        assert_eq!(most_frequent.len(), 1);
+        assert_eq!(most_frequent[0].term, "starbucks");
+        assert!(
+            index
+                .suggest("merchant".to_string(), "star".to_string(), 0)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            index
+                .suggest("merchant".to_string(), " ".to_string(), 10)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            index
+                .suggest("total".to_string(), "1".to_string(), 10)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn spell_suggest_corrects_unknown_tokens() {
+        let index = make_index("spell_suggest");
+        index
+            .index_docs(vec![
+                receipt("r1", "Starbucks", 5.0),
+                receipt("r2", "Starbucks", 7.0),
+                receipt("r3", "Starducks Arcade", 2.0),
+                receipt("r4", "Target", 30.0),
+                receipt("r5", "Costco", 80.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+
+        let suggestion = index
+            .spell_suggest(
+                "merchant".to_string(),
+                "Starbuks tagret costco".to_string(),
+                2,
+            )
+            .unwrap();
+        assert_eq!(suggestion.corrected, "starbucks target costco");
+        assert!(!suggestion.tokens[0].found);
+        let alternatives = suggestion.tokens[0]
+            .alternatives
+            .iter()
+            .map(|alternative| (alternative.term.as_str(), alternative.doc_freq))
+            .collect::<Vec<_>>();
+        assert_eq!(alternatives, vec![("starbucks", 2), ("starducks", 1)]);
+        assert!(suggestion.tokens[2].found);
+        assert!(suggestion.tokens[2].alternatives.is_empty());
+
+        let unmatched = index
+            .spell_suggest("merchant".to_string(), "walmart".to_string(), 1)
+            .unwrap();
+        assert_eq!(unmatched.corrected, "walmart");
+        assert!(unmatched.tokens[0].alternatives.is_empty());
+
+        assert!(
+            index
+                .spell_suggest("merchant".to_string(), "target".to_string(), 3)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn spell_suggest_keeps_rare_known_tokens() {
+        let index = make_index("spell_suggest_rare");
+        let mut receipts = vec![receipt("cat", "cat", 1.0)];
+        for (idx, merchant) in ["bat", "hat", "mat", "rat", "sat", "cot"]
+            .iter()
+            .enumerate()
+        {
+            receipts.push(receipt(&format!("{merchant}1"), merchant, idx as f64));
+            receipts.push(receipt(&format!("{merchant}2"), merchant, idx as f64));
+        }
+        index.index_docs(receipts).unwrap();
+        index.commit().unwrap();
+
+        let suggestion = index
+            .spell_suggest("merchant".to_string(), "cat".to_string(), 1)
+            .unwrap();
+        assert_eq!(suggestion.corrected, "cat");
+        assert!(suggestion.tokens[0].found);
+        let alternatives = &suggestion.tokens[0].alternatives;
+        assert_eq!(alternatives.len(), 5);
+        assert!(
+            alternatives
+                .iter()
+                .all(|alternative| alternative.term != "cat")
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::BTreeMap;
+
+use tantivy::postings::{Postings, TermInfo};
+use tantivy::schema::{Field, FieldType, IndexRecordOption, Schema};
+use tantivy::{DocSet, InvertedIndexReader, TERMINATED};
+
+use crate::suggest::{PrefixAutomaton, indexed_text_field};
+use crate::{TantivyIndex, TantivyIndexError};
+
+/// Counts of an indexed term. Deleted documents still count until their segment
+/// is merged.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TantivyTermFrequency {
+    pub term: String,
+    pub doc_freq: u64,
+    /// Occurrences over all documents, `None` when the field is indexed without
+    /// frequencies.
+    pub total_term_freq: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TantivyTermPage {
+    pub terms: Vec<TantivyTermFrequency>,
+    /// Pass back as `cursor` to list the following terms, `nil` on the last page.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TantivySegmentTermStats {
+    pub segment_id: String,
+    pub doc_freq: u64,
+    pub total_term_freq: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TantivyTermStats {
+    pub term: String,
+    pub doc_freq: u64,
+    pub total_term_freq: Option<u64>,
+    /// Only the segments containing the term.
+    pub segments: Vec<TantivySegmentTermStats>,
+}
+
+fn has_freqs(schema: &Schema, field: Field) -> bool {
+    match schema.get_field_entry(field).field_type() {
+        FieldType::Str(options) => options
+            .get_indexing_options()
+            .is_some_and(|indexing| indexing.index_option().has_freq()),
+        _ => false,
+    }
+}
+
+/// Sums the term frequencies of the postings of a term, reading them in full.
+fn total_term_freq(
+    inverted_index: &InvertedIndexReader,
+    term_info: &TermInfo,
+    with_freqs: bool,
+) -> Result<Option<u64>, TantivyIndexError> {
+    if !with_freqs {
+        return Ok(None);
+    }
+    let mut postings =
+        inverted_index.read_postings_from_terminfo(term_info, IndexRecordOption::WithFreqs)?;
+    let mut total = 0;
+    while postings.doc() != TERMINATED {
+        total += u64::from(postings.term_freq());
+        postings.advance();
+    }
+    Ok(Some(total))
+}
+
+#[uniffi::export]
+impl TantivyIndex {
+    /// Lists the indexed terms of a text field in lexicographic order, `limit` at a
+    /// time, optionally only those starting with `prefix`.
+    ///
+    /// Terms are listed as indexed, `prefix` is not analyzed.
+    #[uniffi::method(default(prefix = None, cursor = None))]
+    fn list_terms(
+        &self,
+        field: String,
+        limit: u32,
+        prefix: Option<String>,
+        cursor: Option<String>,
+    ) -> Result<TantivyTermPage, TantivyIndexError> {
+        let schema = self.index.schema();
+        let field = indexed_text_field(&schema, &field)?;
+        let with_freqs = has_freqs(&schema, field);
+        let limit = usize::try_from(limit)?;
+        let prefix = prefix.unwrap_or_default();
+        let automaton = PrefixAutomaton(prefix.as_bytes());
+
+        // The first `limit` terms overall are among the first `limit` of each segment,
+        // one more tells whether there is a next page.
+        let mut terms: BTreeMap<String, TantivyTermFrequency> = BTreeMap::new();
+        let searcher = self.reader.searcher();
+        for segment_reader in searcher.segment_readers() {
+            let inverted_index = segment_reader.inverted_index(field)?;
+            let mut stream_builder = inverted_index.terms().search(&automaton);
+            if let Some(cursor) = &cursor {
+                stream_builder = stream_builder.gt(cursor);
+            }
+            let mut stream = stream_builder.into_stream()?;
+            let mut taken = 0;
+            while taken <= limit && stream.advance() {
+                let Ok(term) = std::str::from_utf8(stream.key()) else {
+                    continue;
+                };
+                let term_info = stream.value();
+                let total = total_term_freq(&inverted_index, term_info, with_freqs)?;
+                let entry = terms
+                    .entry(term.to_string())
+                    .or_insert_with(|| TantivyTermFrequency {
+                        term: term.to_string(),
+                        doc_freq: 0,
+                        total_term_freq: with_freqs.then_some(0),
+                    });
+                entry.doc_freq += u64::from(term_info.doc_freq);
+                entry.total_term_freq = entry.total_term_freq.zip(total).map(|(a, b)| a + b);
+                taken += 1;
+            }
+        }
+
+        let has_more = terms.len() > limit;
+        let terms = terms.into_values().take(limit).collect::<Vec<_>>();
+        let next_cursor = if has_more {
+            terms.last().map(|term| term.term.clone())
+        } else {
+            None
+        };
+        Ok(TantivyTermPage { terms, next_cursor })
+    }
+
+    /// Counts of an indexed term of a text field, overall and per segment.
+    ///
+    /// `term` is looked up as indexed, it is not analyzed.
+    #[uniffi::method]
+    fn term_stats(
+        &self,
+        field: String,
+        term: String,
+    ) -> Result<TantivyTermStats, TantivyIndexError> {
+        let schema = self.index.schema();
+        let field = indexed_text_field(&schema, &field)?;
+        let with_freqs = has_freqs(&schema, field);
+
+        let mut segments = Vec::new();
+        let searcher = self.reader.searcher();
+        for segment_reader in searcher.segment_readers() {
+            let inverted_index = segment_reader.inverted_index(field)?;
+            let Some(term_info) = inverted_index.terms().get(term.as_bytes())? else {
+                continue;
+            };
+            segments.push(TantivySegmentTermStats {
+                segment_id: segment_reader.segment_id().uuid_string(),
+                doc_freq: u64::from(term_info.doc_freq),
+                total_term_freq: total_term_freq(&inverted_index, &term_info, with_freqs)?,
+            });
+        }
+
+        Ok(TantivyTermStats {
+            term,
+            doc_freq: segments.iter().map(|segment| segment.doc_freq).sum(),
+            total_term_freq: with_freqs.then(|| {
+                segments
+                    .iter()
+                    .filter_map(|segment| segment.total_term_freq)
+                    .sum()
+            }),
+            segments,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{make_index, receipt};
+
+    #[test]
+    fn list_terms_pages_vocabulary_with_frequencies() {
+        let index = make_index("list_terms");
+        index
+            .index_docs(vec![
+                receipt("r1", "Co-op Food co-op", 5.0),
+                receipt("r2", "Costco", 7.0),
+            ])
+            .unwrap();
+        index.commit().unwrap();
+        index
+            .index_docs(vec![receipt("r3", "Food Lion", 9.0)])
+            .unwrap();
+        index.commit().unwrap();
+
+        let list = |prefix: Option<&str>, cursor: Option<String>, limit| {
+            index
+                .list_terms(
+                    "merchant".to_string(),
+                    limit,
+                    prefix.map(str::to_string),
+                    cursor,
+                )
+                .unwrap()
+        };
+        let terms = |page: &super::TantivyTermPage| {
+            page.terms
+                .iter()
+                .map(|term| term.term.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let first = list(None, None, 3);
+        assert_eq!(terms(&first), vec!["co", "coop", "costco"]);
+        assert_eq!(first.next_cursor.as_deref(), Some("costco"));
+        let second = list(None, first.next_cursor, 2);
+        assert_eq!(terms(&second), vec!["food", "lion"]);
+        let last = list(None, second.next_cursor, 2);
+        assert_eq!(terms(&last), vec!["op"]);
+        assert!(last.next_cursor.is_none());
+
+        let co = list(Some("co"), None, 10);
+        assert_eq!(terms(&co), vec!["co", "coop", "costco"]);
+        assert_eq!(co.terms[1].doc_freq, 1);
+        assert_eq!(co.terms[1].total_term_freq, Some(2));
+        // Only the first segment has these terms.
+        let co_page = list(Some("co"), None, 2);
+        assert_eq!(co_page.next_cursor.as_deref(), Some("coop"));
+        assert_eq!(
+            terms(&list(Some("co"), co_page.next_cursor, 2)),
+            vec!["costco"]
+        );
+
+        let food = index
+            .term_stats("merchant".to_string(), "food".to_string())
+            .unwrap();
+        assert_eq!(food.doc_freq, 2);
+        assert_eq!(food.total_term_freq, Some(2));
+        assert_eq!(food.segments.len(), 2);
+        assert_eq!(
+            index
+                .term_stats("merchant".to_string(), "Food".to_string())
+                .unwrap()
+                .doc_freq,
+            0
+        );
+        assert!(
+            index
+                .list_terms("total".to_string(), 10, None, None)
+                .is_err()
+        );
+    }
+}